@@ -0,0 +1,119 @@
+//! A uniform answer type for solvers returning numbers, text or letters rendered on a grid.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::answer::Answer;
+//!
+//! assert_eq!("42", Answer::from(42u32).to_string());
+//! assert_eq!("abc", Answer::from("abc").to_string());
+//!
+//! let rendered = [
+//!     "#....#",
+//!     "#....#",
+//!     "#....#",
+//!     "#....#",
+//!     "######",
+//!     "#....#",
+//!     "#....#",
+//!     "#....#",
+//!     "#....#",
+//!     "#....#",
+//! ]
+//! .join("\n");
+//! assert_eq!(Answer::Text(String::from("H")), Answer::from_rendered(&rendered));
+//! ```
+
+use std::fmt;
+
+use crate::grid::Grid;
+use crate::ocr;
+
+/// The answer of a puzzle part.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// A number.
+    Integer(i128),
+    /// Text, e.g. a code or letters decoded from a grid.
+    Text(String),
+    /// Pixels (`#` and `.`) that could not be decoded into letters.
+    Grid(String),
+}
+
+impl Answer {
+    /// Create an answer from rendered pixels (`#` for lit ones). The letters are decoded if
+    /// possible, otherwise the pixels are kept as `Answer::Grid`.
+    pub fn from_rendered(rendered: &str) -> Self {
+        match ocr::decode(rendered) {
+            Some(text) => Answer::Text(text),
+            None => Answer::Grid(rendered.to_string()),
+        }
+    }
+
+    /// The answer as it would be submitted, or `None` for an undecoded grid.
+    pub fn submission(&self) -> Option<String> {
+        match self {
+            Answer::Integer(n) => Some(n.to_string()),
+            Answer::Text(s) => Some(s.clone()),
+            Answer::Grid(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Grid(g) => write!(f, "\n{}", g),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Answer::Text(c.to_string())
+    }
+}
+
+/// Lit pixels are decoded into letters if possible.
+impl From<&Grid<bool>> for Answer {
+    fn from(grid: &Grid<bool>) -> Self {
+        let rendered: Vec<String> = grid
+            .rows()
+            .map(|row| row.iter().map(|&lit| if lit { '#' } else { '.' }).collect())
+            .collect();
+        Answer::from_rendered(&rendered.join("\n"))
+    }
+}
+
+impl From<Grid<bool>> for Answer {
+    fn from(grid: Grid<bool>) -> Self {
+        Answer::from(&grid)
+    }
+}
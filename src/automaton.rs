@@ -0,0 +1,413 @@
+//! Cellular automata: sparse automata over sets of active cells, one-dimensional elementary
+//! automata generating row after row, a drifting one-dimensional automaton over pots, and
+//! walkers that turn and modify cells depending on the state of the cell they stand on.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::automaton::{life_rule, step_sparse};
+//! use std::collections::HashSet;
+//!
+//! // A blinker in Conway's Game of Life
+//! let neighbors = |&(x, y): &(i64, i64)| {
+//!     (-1..=1)
+//!         .flat_map(move |dx| (-1..=1).map(move |dy| (x + dx, y + dy)))
+//!         .filter(move |&p| p != (x, y))
+//! };
+//! let blinker: HashSet<(i64, i64)> = vec![(0, 0), (1, 0), (2, 0)].into_iter().collect();
+//!
+//! let next = step_sparse(&blinker, neighbors, life_rule(&[3], &[2, 3]));
+//! assert_eq!(vec![(1, -1), (1, 0), (1, 1)].into_iter().collect::<HashSet<_>>(), next);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+
+use crate::geometry::Point2;
+
+/// Compute the next generation of a sparse automaton.
+///
+/// `neighbors` returns the neighbors of a cell, and `rule` decides whether a cell is active in
+/// the next generation given whether it is currently active and how many of its neighbors are.
+/// Only active cells and their neighbors are considered, so inactive cells without active
+/// neighbors always stay inactive.
+pub fn step_sparse<P, N, I, R>(active: &HashSet<P>, neighbors: N, rule: R) -> HashSet<P>
+where
+    P: Eq + Hash + Clone,
+    N: Fn(&P) -> I,
+    I: IntoIterator<Item = P>,
+    R: Fn(bool, usize) -> bool,
+{
+    let mut counts: HashMap<P, usize> = HashMap::new();
+    for cell in active {
+        counts.entry(cell.clone()).or_insert(0);
+        for neighbor in neighbors(cell) {
+            *counts.entry(neighbor).or_insert(0) += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .filter(|(cell, count)| rule(active.contains(cell), *count))
+        .map(|(cell, _)| cell)
+        .collect()
+}
+
+/// Life-like rule in B/S notation: an inactive cell becomes active with a neighbor count in
+/// `born`, an active cell stays active with a neighbor count in `survive`.
+pub fn life_rule<'a>(born: &'a [usize], survive: &'a [usize]) -> impl Fn(bool, usize) -> bool + 'a {
+    move |active, count| {
+        if active {
+            survive.contains(&count)
+        } else {
+            born.contains(&count)
+        }
+    }
+}
+
+/// Wolfram code of the elementary automaton where a cell becomes active if exactly one of its two
+/// neighbors is active (e.g. the trap tiles in "Like a Rogue").
+pub const RULE_90: u8 = 90;
+
+/// Iterator over the rows of a one-dimensional elementary automaton. Created using
+/// `elementary_rows()`.
+///
+/// Cells beyond both ends of the row are always inactive.
+#[derive(Debug, Clone)]
+pub struct ElementaryRows {
+    row: Vec<bool>,
+    rule: u8,
+}
+
+impl Iterator for ElementaryRows {
+    type Item = Vec<bool>;
+
+    fn next(&mut self) -> Option<Vec<bool>> {
+        let next = (0..self.row.len())
+            .map(|i| {
+                let left = i > 0 && self.row[i - 1];
+                let center = self.row[i];
+                let right = i + 1 < self.row.len() && self.row[i + 1];
+                let pattern = (left as u8) << 2 | (center as u8) << 1 | right as u8;
+                self.rule >> pattern & 1 == 1
+            })
+            .collect();
+
+        Some(std::mem::replace(&mut self.row, next))
+    }
+}
+
+/// Generate the rows of an elementary automaton with the given Wolfram code, starting with (and
+/// including) `first`. Rows are computed lazily, one at a time.
+pub fn elementary_rows(first: Vec<bool>, rule: u8) -> ElementaryRows {
+    ElementaryRows { row: first, rule }
+}
+
+/// Count the cells in the first `n` rows of an elementary automaton that are in state `value`,
+/// without keeping more than two rows in memory.
+pub fn count_in_rows(first: Vec<bool>, rule: u8, n: usize, value: bool) -> usize {
+    elementary_rows(first, rule)
+        .take(n)
+        .map(|row| row.iter().filter(|&&cell| cell == value).count())
+        .sum()
+}
+
+/// Parse a row of cells, where `active` denotes an active cell and any other character an
+/// inactive one.
+pub fn parse_row(s: &str, active: char) -> Vec<bool> {
+    s.trim().chars().map(|c| c == active).collect()
+}
+
+/// One-dimensional automaton over an infinite row of pots with a 5-cell neighborhood
+/// ("Subterranean Sustainability").
+///
+/// Only the range between the leftmost and the rightmost plant is stored, together with the
+/// index of the leftmost plant, so patterns that drift along the row stay cheap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pots {
+    plants: Vec<bool>,
+    offset: i64,
+    rules: [bool; 32],
+    generation: u64,
+}
+
+impl Pots {
+    /// Create new `Pots` with the initial row starting at pot `0`. `rules` is indexed by the
+    /// neighborhood pattern, with the leftmost pot as the most significant bit.
+    ///
+    /// # Panics
+    /// Panics if the rule for an empty neighborhood produces a plant, since the row would fill up
+    /// infinitely.
+    pub fn new(initial: &[bool], rules: [bool; 32]) -> Self {
+        assert!(!rules[0], "empty neighborhoods must stay empty");
+        let mut pots = Self {
+            plants: initial.to_vec(),
+            offset: 0,
+            rules,
+            generation: 0,
+        };
+        pots.trim();
+        pots
+    }
+
+    fn trim(&mut self) {
+        match self.plants.iter().position(|&p| p) {
+            Some(first) => {
+                let last = self.plants.iter().rposition(|&p| p).unwrap();
+                self.plants.truncate(last + 1);
+                self.plants.drain(..first);
+                self.offset += first as i64;
+            }
+            None => self.plants.clear(),
+        }
+    }
+
+    /// Index of the leftmost plant (meaningless if there are no plants).
+    pub fn offset(&self) -> i64 {
+        self.offset
+    }
+
+    /// Number of generations simulated so far.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns `true` if pot `index` contains a plant.
+    pub fn has_plant(&self, index: i64) -> bool {
+        let i = index - self.offset;
+        i >= 0 && self.plants.get(i as usize) == Some(&true)
+    }
+
+    /// Number of plants.
+    pub fn count(&self) -> usize {
+        self.plants.iter().filter(|&&p| p).count()
+    }
+
+    /// Sum of the indices of all pots containing a plant.
+    pub fn sum(&self) -> i64 {
+        self.plants
+            .iter()
+            .enumerate()
+            .filter(|&(_, &p)| p)
+            .map(|(i, _)| self.offset + i as i64)
+            .sum()
+    }
+
+    /// Compute the next generation.
+    pub fn step(&mut self) {
+        let len = self.plants.len() as i64;
+        let mut pattern = 0;
+        let mut next = Vec::with_capacity(self.plants.len() + 4);
+        // Pots -2..len+2 relative to the offset are the only ones that can contain plants.
+        for i in -2..len + 2 {
+            let incoming = i + 2 >= 0 && i + 2 < len && self.plants[(i + 2) as usize];
+            pattern = ((pattern << 1) | usize::from(incoming)) & 0b11111;
+            next.push(self.rules[pattern]);
+        }
+
+        self.plants = next;
+        self.offset -= 2;
+        self.generation += 1;
+        self.trim();
+    }
+
+    /// Simulate `n` generations.
+    pub fn run(&mut self, n: u64) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    /// Simulate until `generations` generations have passed in total and return the sum of the
+    /// plant indices.
+    ///
+    /// Detects when the pattern stops changing and only drifts along the row; from then on, the
+    /// sum is extrapolated instead of simulated. Without a steady state, the generations are
+    /// simulated one by one.
+    pub fn sum_after(&mut self, generations: u64) -> i64 {
+        while self.generation < generations {
+            let (plants, offset) = (self.plants.clone(), self.offset);
+            self.step();
+            if self.plants == plants {
+                let drift = self.offset - offset;
+                let remaining = (generations - self.generation) as i64;
+                let sum = self.sum() + drift * self.count() as i64 * remaining;
+
+                self.offset += drift * remaining;
+                self.generation = generations;
+                return sum;
+            }
+        }
+        self.sum()
+    }
+}
+
+impl FromStr for Pots {
+    type Err = String;
+
+    /// Parses an `initial state: #..#.#..##` line followed by rules like `...## => #`. Rules
+    /// that are not listed produce an empty pot.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim).filter(|l| !l.is_empty());
+        let initial = lines
+            .next()
+            .and_then(|l| l.strip_prefix("initial state:"))
+            .ok_or_else(|| String::from("missing initial state"))?;
+
+        let mut rules = [false; 32];
+        for line in lines {
+            let mut parts = line.splitn(2, "=>").map(str::trim);
+            match (parts.next(), parts.next()) {
+                (Some(pattern), Some(result)) if pattern.len() == 5 && result.len() == 1 => {
+                    let index = pattern
+                        .chars()
+                        .fold(0, |acc, c| (acc << 1) | usize::from(c == '#'));
+                    rules[index] = result == "#";
+                }
+                _ => return Err(format!("invalid rule: {}", line)),
+            }
+        }
+        if rules[0] {
+            return Err(String::from("empty neighborhoods must stay empty"));
+        }
+
+        Ok(Pots::new(&parse_row(initial.trim(), '#'), rules))
+    }
+}
+
+/// How a walker turns before moving forward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    /// Turn 90° counterclockwise.
+    Left,
+    /// Turn 90° clockwise.
+    Right,
+    /// Keep the current direction.
+    Straight,
+    /// Turn around.
+    Reverse,
+}
+
+/// State of a node in the "Sporifica Virus" puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Node {
+    /// Clean (the state of all nodes not yet visited)
+    #[default]
+    Clean,
+    /// Weakened
+    Weakened,
+    /// Infected
+    Infected,
+    /// Flagged
+    Flagged,
+}
+
+/// A walker on an infinite grid (Langton's ant style). On every burst, it looks at the state of
+/// the current cell, turns and updates the cell according to its rule, and moves forward one
+/// step. Cells that have never been modified are in their default state.
+#[derive(Debug, Clone)]
+pub struct Walker<S, R> {
+    cells: HashMap<Point2<i64>, S>,
+    position: Point2<i64>,
+    direction: Point2<i64>,
+    rule: R,
+}
+
+impl<S, R> Walker<S, R>
+where
+    S: Clone + Default + Eq,
+    R: Fn(&S) -> (Turn, S),
+{
+    /// Create new `Walker` at `position` facing in `direction`. `rule` maps the state of the
+    /// current cell to the turn to take and the cell's new state.
+    pub fn new(
+        cells: HashMap<Point2<i64>, S>,
+        position: Point2<i64>,
+        direction: Point2<i64>,
+        rule: R,
+    ) -> Self {
+        Self {
+            cells,
+            position,
+            direction,
+            rule,
+        }
+    }
+
+    /// Current position.
+    pub fn position(&self) -> Point2<i64> {
+        self.position
+    }
+
+    /// Current direction.
+    pub fn direction(&self) -> Point2<i64> {
+        self.direction
+    }
+
+    /// State of a cell.
+    pub fn state(&self, position: Point2<i64>) -> S {
+        self.cells.get(&position).cloned().unwrap_or_default()
+    }
+
+    /// Perform a single burst. Returns the new state of the cell the walker left.
+    pub fn burst(&mut self) -> S {
+        let current = self.cells.entry(self.position).or_default();
+        let (turn, next) = (self.rule)(current);
+        *current = next.clone();
+
+        self.direction = match turn {
+            Turn::Left => self.direction.rotate_left(),
+            Turn::Right => self.direction.rotate_right(),
+            Turn::Straight => self.direction,
+            Turn::Reverse => self.direction * -1,
+        };
+        self.position += self.direction;
+
+        next
+    }
+
+    /// Perform `n` bursts. Returns the number of bursts that changed a cell to `state`.
+    pub fn run(&mut self, n: usize, state: &S) -> usize {
+        (0..n).filter(|_| self.burst() == *state).count()
+    }
+}
+
+/// Rule of the original virus carrier: clean nodes get infected (turning left), infected nodes
+/// get cleaned (turning right).
+pub fn virus_rule(node: &Node) -> (Turn, Node) {
+    match node {
+        Node::Infected => (Turn::Right, Node::Clean),
+        _ => (Turn::Left, Node::Infected),
+    }
+}
+
+/// Rule of the evolved virus carrier: clean → weakened → infected → flagged → clean.
+pub fn evolved_virus_rule(node: &Node) -> (Turn, Node) {
+    match node {
+        Node::Clean => (Turn::Left, Node::Weakened),
+        Node::Weakened => (Turn::Straight, Node::Infected),
+        Node::Infected => (Turn::Right, Node::Flagged),
+        Node::Flagged => (Turn::Reverse, Node::Clean),
+    }
+}
+
+/// Parse a square map of infected (`#`) and clean nodes. The center of the map is at the
+/// origin.
+pub fn parse_nodes(input: &str) -> HashMap<Point2<i64>, Node> {
+    let lines: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
+    let offset_y = lines.len() as i64 / 2;
+
+    let mut cells = HashMap::new();
+    for (y, line) in lines.iter().enumerate() {
+        let offset_x = line.chars().count() as i64 / 2;
+        for (x, c) in line.chars().enumerate() {
+            if c == '#' {
+                cells.insert(
+                    Point2::new(x as i64 - offset_x, y as i64 - offset_y),
+                    Node::Infected,
+                );
+            }
+        }
+    }
+    cells
+}
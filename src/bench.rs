@@ -0,0 +1,87 @@
+//! Adapters for wiring solvers into benchmark harnesses such as criterion.
+//!
+//! Inputs are read (and cached) once, outside the measured closure, so benchmarks only measure
+//! the solver itself. `bench_solvers!` registers one benchmark per solver with anything that has
+//! a criterion-style `bench_function(name, |b| b.iter(routine))` method.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::bench;
+//!
+//! fn part1(numbers: &[u32]) -> u32 {
+//!     numbers.iter().sum()
+//! }
+//!
+//! let numbers = vec![1, 2, 3];
+//! let mut routine = bench::solver(&numbers[..], part1);
+//!
+//! // With criterion: `b.iter(routine)`
+//! assert_eq!(6, routine());
+//! ```
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+/// The content of the file at `path`, read only on the first call for each path.
+///
+/// Cached inputs live until the end of the program.
+///
+/// # Failures
+/// Returns an error if the file cannot be read or contains invalid UTF-8.
+pub fn cached_input<P: AsRef<Path>>(path: P) -> io::Result<&'static str> {
+    static CACHE: OnceLock<Mutex<HashMap<PathBuf, &'static str>>> = OnceLock::new();
+
+    let path = path.as_ref();
+    let mut cache = CACHE
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+
+    if let Some(&input) = cache.get(path) {
+        return Ok(input);
+    }
+
+    let input: &'static str = Box::leak(std::fs::read_to_string(path)?.into_boxed_str());
+    cache.insert(path.to_path_buf(), input);
+    Ok(input)
+}
+
+/// A routine that runs `solve` on the already prepared `input`, suitable for criterion's
+/// `Bencher::iter()`.
+///
+/// Input and result are passed through `black_box` so the compiler cannot optimize the work
+/// away.
+pub fn solver<'a, T, R, F>(input: &'a T, solve: F) -> impl FnMut() -> R + 'a
+where
+    T: ?Sized,
+    F: Fn(&T) -> R + 'a,
+{
+    move || black_box(solve(black_box(input)))
+}
+
+/// Register one benchmark per solver with a criterion-style harness.
+///
+/// The input file is read (see `cached_input()`) and parsed with `parse` once, before any
+/// measurement. Each solver is called with a reference to the parsed input and registered as
+/// `"<name> <solver>"`.
+///
+/// ```ignore
+/// fn day01(c: &mut Criterion) {
+///     aoc_util::bench_solvers!(c, "day01", "inputs/day01.txt", parse, part1, part2);
+/// }
+/// ```
+#[macro_export]
+macro_rules! bench_solvers {
+    ($c:expr, $name:expr, $path:expr, $parse:expr, $($solver:ident),+ $(,)?) => {{
+        let input = $crate::bench::cached_input($path).expect("cannot read benchmark input");
+        let parsed = ($parse)(input);
+        $(
+            $c.bench_function(format!("{} {}", $name, stringify!($solver)), |b| {
+                b.iter($crate::bench::solver(&parsed, |input| $solver(input)))
+            });
+        )+
+    }};
+}
@@ -0,0 +1,200 @@
+//! Masks of the form `XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X` applied to values or addresses.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::bitmask::Mask;
+//!
+//! let mask: Mask = "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".parse().unwrap();
+//! assert_eq!(73, mask.apply(11));
+//!
+//! let mask: Mask = "000000000000000000000000000000X1001X".parse().unwrap();
+//! let addresses: Vec<u64> = mask.addresses(42).collect();
+//! assert_eq!(vec![26, 27, 58, 59], addresses);
+//! ```
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+/// A mask consisting of `0`, `1` and `X` (floating) bits, most significant bit first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Mask {
+    ones: u64,
+    zeros: u64,
+    floating: u64,
+    width: u32,
+}
+
+impl Mask {
+    /// Number of bits covered by the mask.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Bits that are set to `1` in the mask.
+    pub fn ones(&self) -> u64 {
+        self.ones
+    }
+
+    /// Bits that are set to `0` in the mask.
+    pub fn zeros(&self) -> u64 {
+        self.zeros
+    }
+
+    /// Bits that are `X` in the mask.
+    pub fn floating(&self) -> u64 {
+        self.floating
+    }
+
+    /// Apply the mask to a value: `0` and `1` overwrite the corresponding bit, `X` leaves it
+    /// unchanged.
+    pub fn apply(&self, value: u64) -> u64 {
+        (value | self.ones) & !self.zeros
+    }
+
+    /// Apply the mask to an address: `0` leaves the bit unchanged, `1` sets it and `X` takes on
+    /// both values. Returns an iterator over all resulting addresses, in ascending order.
+    pub fn addresses(&self, address: u64) -> FloatingAddresses {
+        FloatingAddresses {
+            base: (address | self.ones) & !self.floating,
+            floating: self.floating,
+            subset: 0,
+            emitted: 0,
+            done: false,
+        }
+    }
+}
+
+impl FromStr for Mask {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.len() > 64 {
+            return Err(format!("mask too long: {}", s));
+        }
+
+        let mut mask = Mask {
+            width: s.len() as u32,
+            ..Mask::default()
+        };
+        for (idx, c) in s.chars().rev().enumerate() {
+            let bit = 1 << idx;
+            match c {
+                '0' => mask.zeros |= bit,
+                '1' => mask.ones |= bit,
+                'X' => mask.floating |= bit,
+                _ => return Err(format!("invalid character in mask: {}", c)),
+            }
+        }
+
+        Ok(mask)
+    }
+}
+
+/// Iterator over all addresses produced by a mask with floating bits. Created using
+/// `Mask::addresses()`.
+#[derive(Debug, Clone)]
+pub struct FloatingAddresses {
+    base: u64,
+    floating: u64,
+    subset: u64,
+    emitted: u128,
+    done: bool,
+}
+
+impl Iterator for FloatingAddresses {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+
+        let address = self.base | self.subset;
+        // Enumerate all subsets of the floating bits in ascending order.
+        self.subset = self.subset.wrapping_sub(self.floating) & self.floating;
+        self.done = self.subset == 0;
+        self.emitted += 1;
+
+        Some(address)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let total = 1u128 << self.floating.count_ones();
+            let remaining = total - self.emitted;
+            let remaining = usize::try_from(remaining).ok();
+            (remaining.unwrap_or(usize::MAX), remaining)
+        }
+    }
+}
+
+/// A line of the initialization program: `mask = ...` or `mem[8] = 11`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// Replace the current mask.
+    SetMask(Mask),
+    /// Write a value to memory: `(address, value)`.
+    Write(u64, u64),
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(2, '=');
+        let (lhs, rhs) = match (parts.next(), parts.next()) {
+            (Some(lhs), Some(rhs)) => (lhs.trim(), rhs.trim()),
+            _ => return Err(format!("invalid instruction: {}", s)),
+        };
+
+        if lhs == "mask" {
+            return Ok(Instruction::SetMask(rhs.parse()?));
+        }
+
+        let address = lhs
+            .strip_prefix("mem[")
+            .and_then(|a| a.strip_suffix(']'))
+            .and_then(|a| a.parse().ok())
+            .ok_or_else(|| format!("invalid instruction: {}", s))?;
+        let value = rhs.parse().map_err(|_| format!("invalid value: {}", rhs))?;
+
+        Ok(Instruction::Write(address, value))
+    }
+}
+
+/// Run the program with the mask applied to values. Returns the sum of all values in memory.
+pub fn run_value_masks(program: &[Instruction]) -> u64 {
+    let mut mask = Mask::default();
+    let mut memory = HashMap::new();
+    for instruction in program {
+        match *instruction {
+            Instruction::SetMask(m) => mask = m,
+            Instruction::Write(address, value) => {
+                memory.insert(address, mask.apply(value));
+            }
+        }
+    }
+    memory.values().sum()
+}
+
+/// Run the program with the mask applied to addresses (floating bits). Returns the sum of all
+/// values in memory.
+pub fn run_address_masks(program: &[Instruction]) -> u64 {
+    let mut mask = Mask::default();
+    let mut memory = HashMap::new();
+    for instruction in program {
+        match *instruction {
+            Instruction::SetMask(m) => mask = m,
+            Instruction::Write(address, value) => {
+                for a in mask.addresses(address) {
+                    memory.insert(a, value);
+                }
+            }
+        }
+    }
+    memory.values().sum()
+}
@@ -0,0 +1,74 @@
+//! Inputs consisting of blocks of lines separated by blank lines.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::blocks::{intersection_counts, split_blocks, union_counts};
+//!
+//! let input = "abc\n\na\nb\nc\n\nab\nac\n";
+//!
+//! assert_eq!(3, split_blocks(input).len());
+//! assert_eq!(vec![3, 3, 3], union_counts(input));
+//! assert_eq!(vec![3, 0, 1], intersection_counts(input));
+//! ```
+
+use std::collections::BTreeSet;
+
+/// Split the input into blocks of lines. Blocks are separated by one or more blank (or
+/// whitespace-only) lines; leading and trailing blank lines are ignored.
+pub fn split_blocks(input: &str) -> Vec<Vec<&str>> {
+    let mut blocks = Vec::new();
+    let mut block = Vec::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            if !block.is_empty() {
+                blocks.push(std::mem::take(&mut block));
+            }
+        } else {
+            block.push(line);
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+
+    blocks
+}
+
+/// Characters that appear in any line of the block (whitespace excluded).
+pub fn union(block: &[&str]) -> BTreeSet<char> {
+    block
+        .iter()
+        .flat_map(|line| line.chars())
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
+
+/// Characters that appear in every line of the block (whitespace excluded).
+pub fn intersection(block: &[&str]) -> BTreeSet<char> {
+    let mut lines = block.iter();
+    let first = match lines.next() {
+        Some(line) => line.chars().filter(|c| !c.is_whitespace()).collect(),
+        None => return BTreeSet::new(),
+    };
+
+    lines.fold(first, |acc: BTreeSet<char>, line| {
+        let chars: BTreeSet<char> = line.chars().collect();
+        acc.intersection(&chars).copied().collect()
+    })
+}
+
+/// For each block, the number of distinct characters that appear in any of its lines ("anyone
+/// answered yes").
+pub fn union_counts(input: &str) -> Vec<usize> {
+    split_blocks(input).iter().map(|b| union(b).len()).collect()
+}
+
+/// For each block, the number of distinct characters that appear in all of its lines
+/// ("everyone answered yes").
+pub fn intersection_counts(input: &str) -> Vec<usize> {
+    split_blocks(input)
+        .iter()
+        .map(|b| intersection(b).len())
+        .collect()
+}
@@ -0,0 +1,137 @@
+//! Chains of two-ported components whose adjacent ports match (e.g. `0/2 - 2/3 - 3/5`).
+//!
+//! All chains starting at a given port are enumerated by a depth-first search that marks used
+//! components in a `Vec<bool>`, so inputs of a few dozen components are fine.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::bridge::{longest_strongest, strongest, Component};
+//!
+//! let components: Vec<Component> = "0/2\n2/2\n2/3\n3/4\n3/5\n0/1\n10/1\n9/10"
+//!     .lines()
+//!     .map(|l| l.parse().unwrap())
+//!     .collect();
+//!
+//! assert_eq!(31, strongest(&components, 0));
+//! assert_eq!(19, longest_strongest(&components, 0));
+//! ```
+
+use std::str::FromStr;
+
+/// A component with two ports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Component {
+    /// First port.
+    pub a: u32,
+    /// Second port.
+    pub b: u32,
+}
+
+impl Component {
+    /// Create new `Component`.
+    pub fn new(a: u32, b: u32) -> Self {
+        Self { a, b }
+    }
+
+    /// Sum of both ports.
+    pub fn strength(&self) -> u32 {
+        self.a + self.b
+    }
+
+    /// The port on the other side if the component is connected at `port`.
+    pub fn other(&self, port: u32) -> Option<u32> {
+        if self.a == port {
+            Some(self.b)
+        } else if self.b == port {
+            Some(self.a)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Component {
+    type Err = String;
+
+    /// Parses components of the form `3/5`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(2, '/');
+        match (parts.next(), parts.next()) {
+            (Some(a), Some(b)) => {
+                let port = |p: &str| {
+                    p.trim()
+                        .parse()
+                        .map_err(|_| format!("invalid port in component: {}", s))
+                };
+                Ok(Component::new(port(a)?, port(b)?))
+            }
+            _ => Err(format!("invalid component: {}", s)),
+        }
+    }
+}
+
+/// Summary of a chain: the number of components and their total strength.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Chain {
+    /// Number of components.
+    pub length: usize,
+    /// Total strength of all components.
+    pub strength: u32,
+}
+
+/// Call `visit` for every chain (including the empty one) starting at port `start`. Chains that
+/// could be extended are visited as well.
+pub fn for_each_chain<F: FnMut(Chain)>(components: &[Component], start: u32, mut visit: F) {
+    let mut used = vec![false; components.len()];
+    extend(components, &mut used, start, Chain::default(), &mut visit);
+}
+
+fn extend<F: FnMut(Chain)>(
+    components: &[Component],
+    used: &mut [bool],
+    port: u32,
+    chain: Chain,
+    visit: &mut F,
+) {
+    visit(chain);
+
+    for (i, component) in components.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+        if let Some(next) = component.other(port) {
+            used[i] = true;
+            let longer = Chain {
+                length: chain.length + 1,
+                strength: chain.strength + component.strength(),
+            };
+            extend(components, used, next, longer, visit);
+            used[i] = false;
+        }
+    }
+}
+
+/// The best chain starting at port `start` according to `key` (larger is better).
+pub fn best_chain<K, F>(components: &[Component], start: u32, key: F) -> Chain
+where
+    K: Ord,
+    F: Fn(&Chain) -> K,
+{
+    let mut best = Chain::default();
+    for_each_chain(components, start, |chain| {
+        if key(&chain) > key(&best) {
+            best = chain;
+        }
+    });
+    best
+}
+
+/// Strength of the strongest chain starting at port `start`.
+pub fn strongest(components: &[Component], start: u32) -> u32 {
+    best_chain(components, start, |c| c.strength).strength
+}
+
+/// Strength of the strongest of the longest chains starting at port `start`.
+pub fn longest_strongest(components: &[Component], start: u32) -> u32 {
+    best_chain(components, start, |c| (c.length, c.strength)).strength
+}
@@ -0,0 +1,70 @@
+//! Binary space partitioning codes such as boarding passes (`FBFBBFFRLR`).
+//!
+//! Each character of the code selects either the lower or the upper half of the remaining range,
+//! which is the same as interpreting the code as a binary number.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::bsp::{find_missing_id, Decoder};
+//!
+//! let decoder = Decoder::boarding_pass();
+//! assert_eq!(Ok(357), decoder.decode("FBFBBFFRLR"));
+//!
+//! assert_eq!(Some(6), find_missing_id(&[3, 7, 4, 5, 8]));
+//! ```
+
+/// Decodes strings over two symbol sets into integers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoder {
+    zeros: Vec<char>,
+    ones: Vec<char>,
+}
+
+impl Decoder {
+    /// Create new `Decoder`. Characters in `zeros` select the lower half (bit `0`), characters in
+    /// `ones` the upper half (bit `1`).
+    pub fn new(zeros: &str, ones: &str) -> Self {
+        Self {
+            zeros: zeros.chars().collect(),
+            ones: ones.chars().collect(),
+        }
+    }
+
+    /// Create new `Decoder` for boarding passes (`F`/`L` are `0`, `B`/`R` are `1`).
+    pub fn boarding_pass() -> Self {
+        Self::new("FL", "BR")
+    }
+
+    /// Decode a string into an integer, most significant bit first.
+    ///
+    /// # Failures
+    /// Returns an error if the string contains a character from neither symbol set or is longer
+    /// than 64 symbols.
+    pub fn decode(&self, s: &str) -> Result<u64, String> {
+        let s = s.trim();
+        if s.chars().count() > 64 {
+            return Err(format!("code too long: {}", s));
+        }
+
+        s.chars().try_fold(0, |acc, c| {
+            if self.zeros.contains(&c) {
+                Ok(acc << 1)
+            } else if self.ones.contains(&c) {
+                Ok((acc << 1) | 1)
+            } else {
+                Err(format!("invalid symbol: {}", c))
+            }
+        })
+    }
+}
+
+/// Find the single missing ID in an otherwise contiguous block of IDs. The IDs don't need to be
+/// sorted. Returns `None` if there is no gap.
+pub fn find_missing_id(ids: &[u64]) -> Option<u64> {
+    let mut sorted = ids.to_vec();
+    sorted.sort_unstable();
+    sorted
+        .windows(2)
+        .find(|w| w[1] == w[0] + 2)
+        .map(|w| w[0] + 1)
+}
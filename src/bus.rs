@@ -0,0 +1,103 @@
+//! Shuttle bus schedules (2020 day 13): buses leave every `id` minutes, starting at time 0.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::bus::Schedule;
+//!
+//! let schedule: Schedule = "939\n7,13,x,x,59,x,31,19".parse().unwrap();
+//!
+//! assert_eq!(Some((59, 944)), schedule.earliest_bus());
+//! assert_eq!(Some(1068781), schedule.earliest_aligned());
+//! ```
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use crate::math::{crt, next_multiple};
+
+/// Notes of the shuttle puzzle: the earliest time to depart and the list of buses, where `None`
+/// marks an out-of-service slot (`x`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schedule {
+    /// Earliest time at which one can depart.
+    pub earliest: u64,
+    /// Bus IDs (equal to their periods) in list order.
+    pub buses: Vec<Option<u64>>,
+}
+
+/// Parse a comma-separated list of bus IDs, with `x` for out-of-service slots.
+///
+/// # Failures
+/// Returns an error for entries that are neither `x` nor a positive number.
+pub fn parse_buses(s: &str) -> Result<Vec<Option<u64>>, String> {
+    s.trim()
+        .split(',')
+        .map(|entry| match entry.trim() {
+            "x" => Ok(None),
+            id => match id.parse() {
+                Ok(id) if id > 0 => Ok(Some(id)),
+                _ => Err(format!("invalid bus: {}", id)),
+            },
+        })
+        .collect()
+}
+
+impl FromStr for Schedule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().filter(|l| !l.trim().is_empty());
+        let (earliest, buses) = match (lines.next(), lines.next(), lines.next()) {
+            (Some(earliest), Some(buses), None) => (earliest, buses),
+            _ => return Err(format!("invalid schedule: {}", s)),
+        };
+
+        Ok(Schedule {
+            earliest: earliest
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid time: {}", earliest))?,
+            buses: parse_buses(buses)?,
+        })
+    }
+}
+
+impl Schedule {
+    /// The first bus departing at or after `earliest`, as `(id, departure)`.
+    pub fn earliest_bus(&self) -> Option<(u64, u64)> {
+        self.buses
+            .iter()
+            .flatten()
+            .map(|&id| (id, next_multiple(self.earliest, id)))
+            .min_by_key(|&(_, departure)| departure)
+    }
+
+    /// The conditions on a time `t` such that every bus departs at `t` plus its position in the
+    /// list, as pairs `(residue, modulus)` meaning `t ≡ residue (mod modulus)`.
+    pub fn congruences(&self) -> Vec<(u64, u64)> {
+        self.buses
+            .iter()
+            .enumerate()
+            .filter_map(|(offset, &id)| {
+                let id = id?;
+                Some(((id - offset as u64 % id) % id, id))
+            })
+            .collect()
+    }
+
+    /// The earliest time `t` such that every bus departs at `t` plus its position in the list.
+    /// Returns `None` if the buses never align (only possible if IDs share factors), or the
+    /// period of the whole schedule doesn't fit into an `i64`.
+    pub fn earliest_aligned(&self) -> Option<u64> {
+        let (residues, moduli): (Vec<i64>, Vec<i64>) = self
+            .congruences()
+            .into_iter()
+            .map(|(residue, modulus)| {
+                Some((i64::try_from(residue).ok()?, i64::try_from(modulus).ok()?))
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        crt(&residues, &moduli).map(|(t, _)| t as u64)
+    }
+}
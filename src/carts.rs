@@ -0,0 +1,169 @@
+//! Carts moving along a network of tracks ("Mine Cart Madness").
+//!
+//! Tracks consist of straight pieces (`-`, `|`), curves (`/`, `\`) and intersections (`+`).
+//! At intersections, each cart turns left, goes straight and turns right, cycling through these
+//! options. Carts move one at a time in reading order; two carts on the same square crash and
+//! are removed.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::carts::Tracks;
+//! use aoc_util::geometry::Point2;
+//!
+//! let tracks: Tracks = "|\nv\n|\n|\n|\n^\n|".parse().unwrap();
+//!
+//! assert_eq!(Some(Point2::new(0, 3)), tracks.first_collision());
+//! ```
+
+use std::str::FromStr;
+
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+/// A single cart.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cart {
+    /// Current position (`x` to the right, `y` downwards).
+    pub pos: Point2<i64>,
+    /// Unit vector of the direction the cart is facing.
+    pub dir: Point2<i64>,
+    /// Number of intersections the cart has passed.
+    pub intersections: usize,
+}
+
+impl Cart {
+    /// Move the cart one step forward and turn it according to the track it arrives on.
+    fn advance(&mut self, track: char) {
+        self.pos += self.dir;
+        let Point2 { x, y } = self.dir;
+        self.dir = match track {
+            '/' => Point2::new(-y, -x),
+            '\\' => Point2::new(y, x),
+            '+' => {
+                let dir = match self.intersections % 3 {
+                    0 => self.dir.rotate_left(),
+                    1 => self.dir,
+                    _ => self.dir.rotate_right(),
+                };
+                self.intersections += 1;
+                dir
+            }
+            _ => self.dir,
+        };
+    }
+}
+
+/// A track network with the carts on it.
+#[derive(Debug, Clone)]
+pub struct Tracks {
+    tracks: Grid<char>,
+    carts: Vec<Cart>,
+}
+
+impl Tracks {
+    /// The remaining carts, in reading order as of the last completed tick.
+    pub fn carts(&self) -> &[Cart] {
+        &self.carts
+    }
+
+    fn track(&self, pos: Point2<i64>) -> char {
+        if pos.x < 0 || pos.y < 0 {
+            return ' ';
+        }
+        self.tracks
+            .get(pos.y as usize, pos.x as usize)
+            .copied()
+            .unwrap_or(' ')
+    }
+
+    /// Move every cart once, in reading order. Carts that crash are removed; returns the
+    /// locations of all crashes in the order they happened.
+    pub fn tick(&mut self) -> Vec<Point2<i64>> {
+        self.carts.sort_by_key(|c| (c.pos.y, c.pos.x));
+
+        let mut crashed = vec![false; self.carts.len()];
+        let mut crashes = Vec::new();
+        for i in 0..self.carts.len() {
+            if crashed[i] {
+                continue;
+            }
+            let track = self.track(self.carts[i].pos + self.carts[i].dir);
+            self.carts[i].advance(track);
+
+            let pos = self.carts[i].pos;
+            if let Some(j) =
+                (0..self.carts.len()).find(|&j| j != i && !crashed[j] && self.carts[j].pos == pos)
+            {
+                crashed[i] = true;
+                crashed[j] = true;
+                crashes.push(pos);
+            }
+        }
+
+        let mut crashed = crashed.into_iter();
+        self.carts.retain(|_| !crashed.next().unwrap());
+        crashes
+    }
+
+    /// Location of the first crash, if carts ever crash.
+    ///
+    /// Returns `None` once fewer than two carts are left without a crash having happened.
+    pub fn first_collision(&self) -> Option<Point2<i64>> {
+        let mut tracks = self.clone();
+        while tracks.carts.len() > 1 {
+            if let Some(&pos) = tracks.tick().first() {
+                return Some(pos);
+            }
+        }
+        None
+    }
+
+    /// Location of the last remaining cart at the end of the first tick after which only one
+    /// cart is left. Returns `None` if no cart survives.
+    pub fn last_cart(&self) -> Option<Point2<i64>> {
+        let mut tracks = self.clone();
+        while tracks.carts.len() > 1 {
+            tracks.tick();
+        }
+        tracks.carts.first().map(|c| c.pos)
+    }
+}
+
+impl FromStr for Tracks {
+    type Err = String;
+
+    /// Parses a track map with carts (`^`, `v`, `<`, `>`) on straight pieces. Lines may be of
+    /// different lengths.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().collect();
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let mut carts = Vec::new();
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                let (dir, track) = match c {
+                    '^' => (Point2::UP, '|'),
+                    'v' => (Point2::DOWN, '|'),
+                    '<' => (Point2::LEFT, '-'),
+                    '>' => (Point2::RIGHT, '-'),
+                    '-' | '|' | '/' | '\\' | '+' | ' ' => {
+                        cells.push(c);
+                        continue;
+                    }
+                    _ => return Err(format!("invalid track character: {}", c)),
+                };
+                carts.push(Cart {
+                    pos: Point2::new(x as i64, y as i64),
+                    dir,
+                    intersections: 0,
+                });
+                cells.push(track);
+            }
+            cells.extend(std::iter::repeat_n(' ', width - line.chars().count()));
+        }
+
+        let tracks = Grid::from_vec(width, cells).ok_or_else(|| String::from("empty track map"))?;
+        Ok(Tracks { tracks, carts })
+    }
+}
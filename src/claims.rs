@@ -0,0 +1,104 @@
+//! Rectangular fabric claims and their overlaps ("No Matter How You Slice It").
+//!
+//! # Examples
+//! ```
+//! use aoc_util::claims::{self, Claim};
+//!
+//! let claims: Vec<Claim> = "#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2"
+//!     .lines()
+//!     .map(|l| l.parse().unwrap())
+//!     .collect();
+//!
+//! assert_eq!(4, claims::overlap_area(&claims));
+//! assert_eq!(Some(3), claims::intact_claim(&claims));
+//! ```
+
+use std::str::FromStr;
+
+/// A claimed rectangle of fabric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Claim {
+    /// ID of the claim.
+    pub id: u32,
+    /// Distance from the left edge of the fabric.
+    pub left: usize,
+    /// Distance from the top edge of the fabric.
+    pub top: usize,
+    /// Width of the rectangle.
+    pub width: usize,
+    /// Height of the rectangle.
+    pub height: usize,
+}
+
+impl Claim {
+    /// Returns `true` if the claim shares at least one square inch with `other`.
+    pub fn overlaps(&self, other: &Claim) -> bool {
+        self.left < other.left + other.width
+            && other.left < self.left + self.width
+            && self.top < other.top + other.height
+            && other.top < self.top + self.height
+    }
+}
+
+impl FromStr for Claim {
+    type Err = String;
+
+    /// Parses `#1 @ 1,3: 4x4`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<usize> = s
+            .split(&['#', '@', ',', ':', 'x', ' '][..])
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse().map_err(|_| format!("invalid claim: {}", s)))
+            .collect::<Result<_, _>>()?;
+
+        match numbers.as_slice() {
+            &[id, left, top, width, height] if s.trim_start().starts_with('#') => Ok(Claim {
+                id: id as u32,
+                left,
+                top,
+                width,
+                height,
+            }),
+            _ => Err(format!("invalid claim: {}", s)),
+        }
+    }
+}
+
+/// Number of claims covering each square inch, row by row, of a fabric just large enough for
+/// all claims. Returns the counts and the width of the fabric.
+fn coverage(claims: &[Claim]) -> (Vec<u32>, usize) {
+    let width = claims.iter().map(|c| c.left + c.width).max().unwrap_or(0);
+    let height = claims.iter().map(|c| c.top + c.height).max().unwrap_or(0);
+
+    let mut counts = vec![0; width * height];
+    for claim in claims {
+        for row in claim.top..claim.top + claim.height {
+            for count in
+                &mut counts[row * width + claim.left..row * width + claim.left + claim.width]
+            {
+                *count += 1;
+            }
+        }
+    }
+    (counts, width)
+}
+
+/// Number of square inches covered by two or more claims.
+pub fn overlap_area(claims: &[Claim]) -> usize {
+    coverage(claims).0.iter().filter(|&&c| c > 1).count()
+}
+
+/// ID of the first claim that doesn't overlap any other claim.
+pub fn intact_claim(claims: &[Claim]) -> Option<u32> {
+    let (counts, width) = coverage(claims);
+    claims
+        .iter()
+        .find(|c| {
+            (c.top..c.top + c.height).all(|row| {
+                counts[row * width + c.left..row * width + c.left + c.width]
+                    .iter()
+                    .all(|&n| n == 1)
+            })
+        })
+        .map(|c| c.id)
+}
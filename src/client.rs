@@ -0,0 +1,157 @@
+//! Politeness layer for requests to adventofcode.com.
+//!
+//! A `Client` doesn't perform HTTP itself; it wraps every request made through it so that
+//! requests are spaced by a minimum interval, retried with exponential backoff on `429` and `5xx`
+//! responses, and always identify the user through a `User-Agent` containing contact
+//! information, as requested by the Advent of Code maintainers.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::client::Client;
+//! use std::time::Duration;
+//!
+//! let mut client = Client::new("me@example.com")
+//!     .unwrap()
+//!     .min_interval(Duration::from_millis(0))
+//!     .initial_backoff(Duration::from_millis(0));
+//!
+//! let mut attempts = 0;
+//! let body = client
+//!     .send(|_user_agent| {
+//!         attempts += 1;
+//!         Ok(if attempts < 3 { (503, "") } else { (200, "input") })
+//!     })
+//!     .unwrap();
+//!
+//! assert_eq!("input", body);
+//! assert_eq!(3, attempts);
+//! ```
+
+use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Default minimum interval between two requests.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Returns `true` for HTTP status codes that are worth retrying (too many requests, server
+/// errors).
+pub fn is_retryable(status: u16) -> bool {
+    status == 429 || (500..600).contains(&status)
+}
+
+/// Rate limiting, backoff and identification shared by all requests of a program.
+#[derive(Debug, Clone)]
+pub struct Client {
+    user_agent: String,
+    min_interval: Duration,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_retries: u32,
+    last_request: Option<Instant>,
+}
+
+impl Client {
+    /// Create new `Client` identifying requests with `contact` (e.g. an email address or
+    /// repository URL).
+    ///
+    /// # Failures
+    /// Returns an error if `contact` is blank.
+    pub fn new(contact: &str) -> Result<Self, String> {
+        let contact = contact.trim();
+        if contact.is_empty() {
+            return Err(String::from("missing contact information for User-Agent"));
+        }
+
+        Ok(Self {
+            user_agent: format!(
+                "{}/{} ({})",
+                env!("CARGO_PKG_NAME"),
+                env!("CARGO_PKG_VERSION"),
+                contact
+            ),
+            min_interval: DEFAULT_MIN_INTERVAL,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_retries: 3,
+            last_request: None,
+        })
+    }
+
+    /// Set the minimum interval between the start of two requests.
+    pub fn min_interval(mut self, interval: Duration) -> Self {
+        self.min_interval = interval;
+        self
+    }
+
+    /// Set the delay before the first retry. It doubles with every further retry.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the upper limit of the delay between retries.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set how often a request is retried after a retryable response.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// The `User-Agent` header value to send with every request.
+    pub fn user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    /// The delay before retry number `retry` (starting at 0).
+    pub fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .checked_mul(1 << retry.min(31))
+            .map_or(self.max_backoff, |delay| delay.min(self.max_backoff))
+    }
+
+    /// Block until the minimum interval since the previous request has passed.
+    pub fn wait(&mut self) {
+        if let Some(last) = self.last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+        self.last_request = Some(Instant::now());
+    }
+
+    /// Perform a request through the client. `request` receives the `User-Agent` to send and
+    /// returns the HTTP status code together with the response.
+    ///
+    /// # Failures
+    /// Returns an error if `request` fails, if the status is not successful (`2xx`), or if it is
+    /// still retryable after all retries.
+    pub fn send<T, F>(&mut self, mut request: F) -> io::Result<T>
+    where
+        F: FnMut(&str) -> io::Result<(u16, T)>,
+    {
+        let mut retry = 0;
+        loop {
+            self.wait();
+            let (status, response) = request(&self.user_agent)?;
+            match status {
+                200..=299 => return Ok(response),
+                s if is_retryable(s) && retry < self.max_retries => {
+                    thread::sleep(self.backoff(retry));
+                    retry += 1;
+                }
+                s => {
+                    return Err(io::Error::other(format!(
+                        "request failed with status {}",
+                        s
+                    )))
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+
+/// Handle of a value in an `Arena<T>`.
+///
+/// Handles are plain indices that are only meaningful for the arena that created them.
+pub struct Id<T> {
+    index: usize,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Id<T> {
+    /// The position of the value in its arena (in order of allocation, starting at 0).
+    pub fn index(self) -> usize {
+        self.index
+    }
+}
+
+// Implemented by hand, since deriving would require `T` to implement the traits as well.
+impl<T> Clone for Id<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Id<T> {}
+
+impl<T> PartialEq for Id<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.index == other.index
+    }
+}
+
+impl<T> Eq for Id<T> {}
+
+impl<T> PartialOrd for Id<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Id<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.index.cmp(&other.index)
+    }
+}
+
+impl<T> Hash for Id<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.index.hash(state);
+    }
+}
+
+impl<T> fmt::Debug for Id<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Id({})", self.index)
+    }
+}
+
+/// A typed arena: values are allocated into a single `Vec` and referred to by `Id<T>` handles,
+/// which makes recursive structures (trees, graphs with cycles) easy to build.
+///
+/// Values are never freed individually.
+///
+/// # Examples
+/// ```
+/// use aoc_util::collections::{Arena, Id};
+///
+/// enum Expr {
+///     Number(i64),
+///     Add(Id<Expr>, Id<Expr>),
+/// }
+///
+/// let mut arena = Arena::new();
+/// let one = arena.alloc(Expr::Number(1));
+/// let two = arena.alloc(Expr::Number(2));
+/// let sum = arena.alloc(Expr::Add(one, two));
+///
+/// fn eval(arena: &Arena<Expr>, id: Id<Expr>) -> i64 {
+///     match arena[id] {
+///         Expr::Number(n) => n,
+///         Expr::Add(a, b) => eval(arena, a) + eval(arena, b),
+///     }
+/// }
+///
+/// assert_eq!(3, eval(&arena, sum));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arena<T> {
+    values: Vec<T>,
+}
+
+impl<T> Default for Arena<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Arena<T> {
+    /// Create new, empty `Arena`.
+    pub fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Create new, empty `Arena` with room for `capacity` values.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Number of allocated values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values have been allocated.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Store `value` in the arena and return its handle.
+    pub fn alloc(&mut self, value: T) -> Id<T> {
+        self.values.push(value);
+        Id {
+            index: self.values.len() - 1,
+            _value: PhantomData,
+        }
+    }
+
+    /// The value with handle `id`, or `None` if `id` belongs to a larger arena.
+    pub fn get(&self, id: Id<T>) -> Option<&T> {
+        self.values.get(id.index)
+    }
+
+    /// Mutable reference to the value with handle `id`, or `None` if `id` belongs to a larger
+    /// arena.
+    pub fn get_mut(&mut self, id: Id<T>) -> Option<&mut T> {
+        self.values.get_mut(id.index)
+    }
+
+    /// All values with their handles, in order of allocation.
+    pub fn iter(&self) -> impl Iterator<Item = (Id<T>, &T)> {
+        self.values.iter().enumerate().map(|(index, value)| {
+            (
+                Id {
+                    index,
+                    _value: PhantomData,
+                },
+                value,
+            )
+        })
+    }
+}
+
+impl<T> Index<Id<T>> for Arena<T> {
+    type Output = T;
+
+    fn index(&self, id: Id<T>) -> &T {
+        &self.values[id.index]
+    }
+}
+
+impl<T> IndexMut<Id<T>> for Arena<T> {
+    fn index_mut(&mut self, id: Id<T>) -> &mut T {
+        &mut self.values[id.index]
+    }
+}
@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+/// A circular doubly linked list with a cursor, backed by a `Vec`.
+///
+/// Insertion and removal at the cursor are `O(1)`; moving the cursor by `n` is `O(n)`. Slots of
+/// removed elements are reused.
+///
+/// # Examples
+/// ```
+/// use aoc_util::collections::CircularList;
+///
+/// let mut list = CircularList::new();
+/// list.insert(0);
+/// list.insert(1);
+/// list.insert(2);
+/// list.move_backward(2);
+///
+/// assert_eq!(Some(&0), list.current());
+/// assert_eq!(Some(0), list.remove());
+/// assert_eq!(vec![1, 2], list.iter().copied().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct CircularList<T> {
+    values: Vec<Option<T>>,
+    next: Vec<usize>,
+    prev: Vec<usize>,
+    free: Vec<usize>,
+    current: Option<usize>,
+    len: usize,
+}
+
+impl<T> Default for CircularList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> CircularList<T> {
+    /// Create new, empty `CircularList`.
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create new, empty `CircularList` with space for `capacity` elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+            next: Vec::with_capacity(capacity),
+            prev: Vec::with_capacity(capacity),
+            free: Vec::new(),
+            current: None,
+            len: 0,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the list contains no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The element at the cursor.
+    pub fn current(&self) -> Option<&T> {
+        self.current.and_then(|i| self.values[i].as_ref())
+    }
+
+    /// Move the cursor `n` elements forward (clockwise).
+    pub fn move_forward(&mut self, n: usize) {
+        if let Some(mut i) = self.current {
+            for _ in 0..n % self.len {
+                i = self.next[i];
+            }
+            self.current = Some(i);
+        }
+    }
+
+    /// Move the cursor `n` elements backward (counterclockwise).
+    pub fn move_backward(&mut self, n: usize) {
+        if let Some(mut i) = self.current {
+            for _ in 0..n % self.len {
+                i = self.prev[i];
+            }
+            self.current = Some(i);
+        }
+    }
+
+    /// Insert an element after the cursor and move the cursor to it.
+    pub fn insert(&mut self, value: T) {
+        let slot = match self.free.pop() {
+            Some(slot) => {
+                self.values[slot] = Some(value);
+                slot
+            }
+            None => {
+                self.values.push(Some(value));
+                self.next.push(0);
+                self.prev.push(0);
+                self.values.len() - 1
+            }
+        };
+
+        match self.current {
+            Some(i) => {
+                let after = self.next[i];
+                self.next[i] = slot;
+                self.prev[slot] = i;
+                self.next[slot] = after;
+                self.prev[after] = slot;
+            }
+            None => {
+                self.next[slot] = slot;
+                self.prev[slot] = slot;
+            }
+        }
+        self.current = Some(slot);
+        self.len += 1;
+    }
+
+    /// Remove the element at the cursor and move the cursor to the following element.
+    pub fn remove(&mut self) -> Option<T> {
+        let i = self.current?;
+        let (before, after) = (self.prev[i], self.next[i]);
+        self.next[before] = after;
+        self.prev[after] = before;
+
+        self.len -= 1;
+        self.current = if self.len == 0 { None } else { Some(after) };
+        self.free.push(i);
+        self.values[i].take()
+    }
+
+    /// Iterate over all elements, starting at the cursor and moving forward.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut i = self.current;
+        (0..self.len).map(move |_| {
+            let idx = i.unwrap();
+            i = Some(self.next[idx]);
+            self.values[idx].as_ref().unwrap()
+        })
+    }
+}
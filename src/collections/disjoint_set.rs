@@ -0,0 +1,114 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A disjoint-set forest (union-find) over the elements `0..len`, with path compression and
+/// union by rank.
+///
+/// # Examples
+/// ```
+/// use aoc_util::collections::DisjointSet;
+///
+/// let mut sets = DisjointSet::new(5);
+/// sets.union(0, 1);
+/// sets.union(3, 4);
+///
+/// assert!(sets.same_set(1, 0));
+/// assert!(!sets.same_set(1, 3));
+/// assert_eq!(3, sets.count_sets());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    /// Create new `DisjointSet` in which every element of `0..len` forms its own set.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            sets: len,
+        }
+    }
+
+    /// Number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// The representative of the set containing `x`.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        let mut node = x;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+
+        root
+    }
+
+    /// Merge the sets containing `a` and `b`. Returns `false` if they already were the same set.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = if self.rank[a] < self.rank[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.sets -= 1;
+
+        true
+    }
+
+    /// Returns `true` if `a` and `b` are in the same set.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is out of bounds.
+    pub fn same_set(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set containing `x`.
+    ///
+    /// # Panics
+    /// Panics if `x` is out of bounds.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Number of disjoint sets.
+    pub fn count_sets(&self) -> usize {
+        self.sets
+    }
+}
@@ -0,0 +1,68 @@
+use core::hash::{BuildHasherDefault, Hasher};
+
+/// Multiplier of the Fx hash (as used by rustc).
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+/// The fast, non-cryptographic hash function used by the Rust compiler ("FxHash").
+///
+/// Much faster than the default SipHash for small keys like integers and coordinates, but not
+/// resistant against collisions crafted on purpose, which is irrelevant for puzzle inputs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FxHasher {
+    hash: u64,
+}
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.hash = (self.hash.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            let mut word = [0; 8];
+            word.copy_from_slice(chunk);
+            self.add(u64::from_le_bytes(word));
+        }
+        for &byte in chunks.remainder() {
+            self.add(u64::from(byte));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.add(u64::from(i));
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Builds `FxHasher`s, for use as the hasher of `HashMap` and `HashSet`.
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
+
+/// A `HashMap` using `FxHasher`. Create it with `FastHashMap::default()`.
+#[cfg(feature = "std")]
+pub type FastHashMap<K, V> = std::collections::HashMap<K, V, FxBuildHasher>;
+
+/// A `HashSet` using `FxHasher`. Create it with `FastHashSet::default()`.
+#[cfg(feature = "std")]
+pub type FastHashSet<T> = std::collections::HashSet<T, FxBuildHasher>;
@@ -0,0 +1,79 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+/// Maps strings to small integer IDs (`0`, `1`, ... in order of first appearance) and back.
+///
+/// Replacing string keys by IDs allows to store per-node data in vectors instead of hash maps.
+///
+/// # Examples
+/// ```
+/// use aoc_util::collections::Interner;
+///
+/// let mut names = Interner::new();
+/// let aa = names.intern("AA");
+/// let bb = names.intern("BB");
+///
+/// assert_eq!(aa, names.intern("AA"));
+/// assert_eq!(Some(bb), names.get("BB"));
+/// assert_eq!(Some("AA"), names.resolve(aa));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Interner {
+    ids: BTreeMap<String, usize>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    /// Create new, empty `Interner`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of interned strings.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns `true` if no strings have been interned.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The ID of `name`, assigning the next free one if it hasn't been interned yet.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        id
+    }
+
+    /// The ID of `name`, if it has been interned.
+    pub fn get(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    /// The string with ID `id`.
+    pub fn resolve(&self, id: usize) -> Option<&str> {
+        self.names.get(id).map(String::as_str)
+    }
+
+    /// All interned strings, ordered by ID.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.names.iter().map(String::as_str).enumerate()
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Interner {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut interner = Interner::new();
+        for name in iter {
+            interner.intern(name);
+        }
+        interner
+    }
+}
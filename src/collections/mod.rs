@@ -0,0 +1,19 @@
+//! Collection types that are not available in the standard library.
+
+mod arena;
+mod circular;
+mod disjoint_set;
+mod fx;
+mod interner;
+mod table;
+mod trie;
+
+pub use self::arena::{Arena, Id};
+pub use self::circular::CircularList;
+pub use self::disjoint_set::DisjointSet;
+#[cfg(feature = "std")]
+pub use self::fx::{FastHashMap, FastHashSet};
+pub use self::fx::{FxBuildHasher, FxHasher};
+pub use self::interner::Interner;
+pub use self::table::{HashTable, HashTableIter};
+pub use self::trie::Trie;
@@ -0,0 +1,266 @@
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::hash::{BuildHasher, Hash};
+use core::iter::FromIterator;
+use core::ops::Index;
+use core::{mem, slice};
+
+use super::fx::FxBuildHasher;
+
+/// Smallest number of slots allocated.
+const MIN_SLOTS: usize = 8;
+
+/// A hash map with linear probing and `FxHasher` that only needs `alloc`, so that hash-based
+/// algorithms also build for `no_std` targets.
+///
+/// Iteration order is unspecified.
+///
+/// # Examples
+/// ```
+/// use aoc_util::collections::HashTable;
+///
+/// let mut distances = HashTable::new();
+/// distances.insert((0, 0), 0);
+/// *distances.get_or_insert_with((1, 0), || 1) += 1;
+///
+/// assert_eq!(Some(&2), distances.get(&(1, 0)));
+/// assert_eq!(Some(0), distances.remove(&(0, 0)));
+/// assert_eq!(1, distances.len());
+/// ```
+#[derive(Debug, Clone)]
+pub struct HashTable<K, V> {
+    slots: Vec<Option<(K, V)>>,
+    len: usize,
+}
+
+impl<K, V> Default for HashTable<K, V> {
+    fn default() -> Self {
+        Self {
+            slots: Vec::new(),
+            len: 0,
+        }
+    }
+}
+
+impl<K, V> HashTable<K, V> {
+    /// Create new, empty `HashTable`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Iterate over all entries.
+    pub fn iter(&self) -> HashTableIter<'_, K, V> {
+        self.into_iter()
+    }
+
+    /// Iterate over all keys.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Iterate over all values.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+
+    /// Consume the table and iterate over all values.
+    pub fn into_values(self) -> impl Iterator<Item = V> {
+        self.into_iter().map(|(_, v)| v)
+    }
+}
+
+impl<K: Eq + Hash, V> HashTable<K, V> {
+    /// Insert a value, returning the previous value of the key.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.find(&key) {
+            Ok(idx) => self.slots[idx]
+                .as_mut()
+                .map(|(_, v)| mem::replace(v, value)),
+            Err(_) => {
+                self.insert_new(key, value);
+                None
+            }
+        }
+    }
+
+    /// The value of `key`, inserting the result of `f` first if there is none.
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, key: K, f: F) -> &mut V {
+        let idx = match self.find(&key) {
+            Ok(idx) => idx,
+            Err(_) => self.insert_new(key, f()),
+        };
+        &mut self.slots[idx].as_mut().unwrap().1
+    }
+
+    /// The value of `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let idx = self.find(key).ok()?;
+        self.slots[idx].as_ref().map(|(_, v)| v)
+    }
+
+    /// The value of `key`, mutably.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let idx = self.find(key).ok()?;
+        self.slots[idx].as_mut().map(|(_, v)| v)
+    }
+
+    /// Returns `true` if there is a value for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.find(key).is_ok()
+    }
+
+    /// Remove the entry of `key` and return its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        let mut hole = self.find(key).ok()?;
+        let (_, value) = self.slots[hole].take().unwrap();
+        self.len -= 1;
+
+        // Shift back following entries that would otherwise not be found from their home slot
+        let mask = self.slots.len() - 1;
+        let mut idx = (hole + 1) & mask;
+        while let Some((k, _)) = &self.slots[idx] {
+            let home = self.home(k);
+            if idx.wrapping_sub(home) & mask >= idx.wrapping_sub(hole) & mask {
+                self.slots[hole] = self.slots[idx].take();
+                hole = idx;
+            }
+            idx = (idx + 1) & mask;
+        }
+
+        Some(value)
+    }
+
+    /// The slot of a hash value, before probing.
+    fn home<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        FxBuildHasher::default().hash_one(key) as usize & (self.slots.len() - 1)
+    }
+
+    /// The slot containing `key`, or else the empty slot where it would be inserted.
+    fn find<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        if self.slots.is_empty() {
+            return Err(0);
+        }
+        let mask = self.slots.len() - 1;
+        let mut idx = self.home(key);
+        loop {
+            match &self.slots[idx] {
+                Some((k, _)) if k.borrow() == key => return Ok(idx),
+                Some(_) => idx = (idx + 1) & mask,
+                None => return Err(idx),
+            }
+        }
+    }
+
+    /// Insert a key that is not in the table yet and return its slot.
+    fn insert_new(&mut self, key: K, value: V) -> usize {
+        // Keep the load factor at most 3/4, so that probe sequences stay short
+        if 4 * (self.len + 1) > 3 * self.slots.len() {
+            self.grow();
+        }
+        let idx = self.find(&key).unwrap_err();
+        self.slots[idx] = Some((key, value));
+        self.len += 1;
+        idx
+    }
+
+    fn grow(&mut self) {
+        let size = (2 * self.slots.len()).max(MIN_SLOTS);
+        let old = mem::replace(&mut self.slots, (0..size).map(|_| None).collect());
+        for (key, value) in old.into_iter().flatten() {
+            let idx = self.find(&key).unwrap_err();
+            self.slots[idx] = Some((key, value));
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq> PartialEq for HashTable<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().all(|(k, v)| other.get(k) == Some(v))
+    }
+}
+
+impl<K: Eq + Hash, V: Eq> Eq for HashTable<K, V> {}
+
+impl<K, Q, V> Index<&Q> for HashTable<K, V>
+where
+    K: Eq + Hash + Borrow<Q>,
+    Q: Eq + Hash + ?Sized,
+{
+    type Output = V;
+
+    /// # Panics
+    /// Panics if there is no value for `key`.
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K: Eq + Hash, V> Extend<(K, V)> for HashTable<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> FromIterator<(K, V)> for HashTable<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        table.extend(iter);
+        table
+    }
+}
+
+/// Iterator over the entries of a `HashTable`.
+pub type HashTableIter<'a, K, V> =
+    core::iter::FilterMap<slice::Iter<'a, Option<(K, V)>>, fn(&Option<(K, V)>) -> Option<(&K, &V)>>;
+
+impl<'a, K, V> IntoIterator for &'a HashTable<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = HashTableIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots
+            .iter()
+            .filter_map(|slot| slot.as_ref().map(|(k, v)| (k, v)))
+    }
+}
+
+impl<K, V> IntoIterator for HashTable<K, V> {
+    type Item = (K, V);
+    type IntoIter = core::iter::Flatten<alloc::vec::IntoIter<Option<(K, V)>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots.into_iter().flatten()
+    }
+}
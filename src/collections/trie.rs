@@ -0,0 +1,165 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: BTreeMap<char, usize>,
+    terminal: bool,
+    count: usize,
+}
+
+/// Prefix tree over strings.
+///
+/// # Examples
+/// ```
+/// use aoc_util::collections::Trie;
+///
+/// let trie: Trie = vec!["r", "wr", "b", "br"].into_iter().collect();
+///
+/// assert!(trie.contains("wr"));
+/// assert_eq!(2, trie.count_with_prefix("b"));
+/// assert_eq!(vec![1, 2], trie.prefix_lengths("brwrr"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Trie {
+    nodes: Vec<Node>,
+    len: usize,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie {
+    /// Create new, empty `Trie`.
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![Node::default()],
+            len: 0,
+        }
+    }
+
+    /// Number of distinct words stored in the trie.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the trie contains no words.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Insert a word. Returns `false` if the word was already present.
+    pub fn insert(&mut self, word: &str) -> bool {
+        if self.contains(word) {
+            return false;
+        }
+
+        let mut current = 0;
+        self.nodes[current].count += 1;
+        for c in word.chars() {
+            current = match self.nodes[current].children.get(&c) {
+                Some(&next) => next,
+                None => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    self.nodes[current].children.insert(c, next);
+                    next
+                }
+            };
+            self.nodes[current].count += 1;
+        }
+        self.nodes[current].terminal = true;
+        self.len += 1;
+
+        true
+    }
+
+    fn find(&self, prefix: &str) -> Option<usize> {
+        prefix
+            .chars()
+            .try_fold(0, |node, c| self.nodes[node].children.get(&c).copied())
+    }
+
+    /// Returns `true` if the trie contains the specified word.
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word)
+            .is_some_and(|node| self.nodes[node].terminal)
+    }
+
+    /// Returns `true` if any word in the trie starts with the specified prefix.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.count_with_prefix(prefix) > 0
+    }
+
+    /// Number of words in the trie that start with the specified prefix.
+    pub fn count_with_prefix(&self, prefix: &str) -> usize {
+        self.find(prefix).map_or(0, |node| self.nodes[node].count)
+    }
+
+    /// All words that start with the specified prefix, in lexicographical order.
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            let mut buffer = prefix.to_string();
+            self.collect_words(node, &mut buffer, &mut words);
+        }
+        words
+    }
+
+    fn collect_words(&self, node: usize, buffer: &mut String, words: &mut Vec<String>) {
+        if self.nodes[node].terminal {
+            words.push(buffer.clone());
+        }
+        for (&c, &child) in &self.nodes[node].children {
+            buffer.push(c);
+            self.collect_words(child, buffer, words);
+            buffer.pop();
+        }
+    }
+
+    /// Lengths (in bytes) of all words in the trie that are a prefix of `text`, in increasing
+    /// order.
+    pub fn prefix_lengths(&self, text: &str) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        let mut node = 0;
+        if self.nodes[node].terminal {
+            lengths.push(0);
+        }
+        for (idx, c) in text.char_indices() {
+            node = match self.nodes[node].children.get(&c) {
+                Some(&next) => next,
+                None => break,
+            };
+            if self.nodes[node].terminal {
+                lengths.push(idx + c.len_utf8());
+            }
+        }
+        lengths
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+impl FromIterator<String> for Trie {
+    fn from_iter<I: IntoIterator<Item = String>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(&word);
+        }
+        trie
+    }
+}
@@ -0,0 +1,205 @@
+//! Cycle detection for deterministic simulations.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::cycles::{find_cycle, state_after};
+//!
+//! // 1, 2, 4, 8, 16, 11, 1, ... (mod 21)
+//! let cycle = find_cycle(1u32, |&x| x * 2 % 21);
+//! assert_eq!((0, 6), (cycle.start, cycle.length));
+//!
+//! // The state after 10^18 steps, without simulating them.
+//! assert_eq!(16, state_after(1u32, 1_000_000_000_000_000_000, |&x| x * 2 % 21));
+//! ```
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Position and length of a cycle in a sequence of states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the first state that is part of the cycle.
+    pub start: usize,
+    /// Number of steps after which the states repeat.
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Number of steps until a state is seen for the second time.
+    pub fn first_repeat(&self) -> usize {
+        self.start + self.length
+    }
+
+    /// The number of steps before `first_repeat` that lead to the same state as `n` steps.
+    pub fn index(&self, n: u64) -> usize {
+        let start = self.start as u64;
+        if n < start {
+            n as usize
+        } else {
+            self.start + ((n - start) % self.length as u64) as usize
+        }
+    }
+}
+
+/// Apply `step` starting from `initial` until a state repeats, remembering all states seen
+/// along the way.
+///
+/// Never returns if the sequence of states doesn't contain a cycle.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    let mut index = 0;
+
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            return Cycle {
+                start,
+                length: index - start,
+            };
+        }
+        let next = step(&state);
+        seen.insert(state, index);
+        state = next;
+        index += 1;
+    }
+}
+
+/// Find the cycle like `find_cycle`, but with Brent's algorithm, which only keeps two states
+/// in memory (at the price of calling `step` about three times as often).
+///
+/// Never returns if the sequence of states doesn't contain a cycle.
+pub fn find_cycle_brent<S, F>(initial: S, mut step: F) -> Cycle
+where
+    S: Eq + Clone,
+    F: FnMut(&S) -> S,
+{
+    // Find the length by moving the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, both meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// The state after applying `step` `n` times to `initial`. Once a state repeats, the remaining
+/// steps are skipped, so `n` can be huge as long as the cycle is reached early.
+pub fn state_after<S, F>(initial: S, n: u64, mut step: F) -> S
+where
+    S: Eq + Hash + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+    let mut index = 0;
+
+    loop {
+        if index as u64 == n {
+            return state;
+        }
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: index - start,
+            };
+            return history.swap_remove(cycle.index(n));
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), index);
+        history.push(state);
+        state = next;
+        index += 1;
+    }
+}
+
+/// The `value` of the state after applying `step` `n` times to `initial`, for simulations whose
+/// state keeps changing (e.g. a growing tower) but whose future only depends on a `key` of it.
+///
+/// Once a key repeats, the value is assumed to grow by the same amount in every further cycle,
+/// and the remaining steps are skipped.
+pub fn extrapolate<S, K, F, KF, VF>(initial: S, n: u64, mut step: F, key: KF, value: VF) -> i64
+where
+    K: Eq + Hash,
+    F: FnMut(&S) -> S,
+    KF: Fn(&S) -> K,
+    VF: Fn(&S) -> i64,
+{
+    let mut seen = HashMap::new();
+    let mut values = Vec::new();
+    let mut state = initial;
+    let mut index = 0;
+
+    loop {
+        let current = value(&state);
+        if index as u64 == n {
+            return current;
+        }
+        if let Some(&start) = seen.get(&key(&state)) {
+            let cycle = Cycle {
+                start,
+                length: index - start,
+            };
+            let growth = current - values[start];
+            let cycles = (n - start as u64) / cycle.length as u64;
+            return values[cycle.index(n)] + cycles as i64 * growth;
+        }
+        seen.insert(key(&state), index);
+        values.push(current);
+        state = step(&state);
+        index += 1;
+    }
+}
+
+/// Perform one redistribution of memory banks: the bank with the most blocks (the first one in
+/// case of a tie) is emptied and its blocks are handed out one at a time to the following banks,
+/// wrapping around.
+pub fn redistribute(banks: &mut [u32]) {
+    let len = banks.len();
+    let (start, blocks) = match banks.iter().enumerate().rev().max_by_key(|&(_, &b)| b) {
+        Some((idx, &blocks)) => (idx, blocks as usize),
+        None => return,
+    };
+
+    banks[start] = 0;
+    for (i, bank) in banks.iter_mut().enumerate() {
+        // Offset of this bank after `start`, in 1..=len
+        let offset = (i + len - start - 1) % len + 1;
+        *bank += (blocks / len + usize::from(offset <= blocks % len)) as u32;
+    }
+}
+
+/// Repeat the memory bank redistribution until a configuration repeats.
+pub fn redistribution_cycle(banks: &[u32]) -> Cycle {
+    find_cycle(banks.to_vec(), |banks| {
+        let mut next = banks.clone();
+        redistribute(&mut next);
+        next
+    })
+}
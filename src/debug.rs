@@ -0,0 +1,191 @@
+//! Printing grids with highlighted points or paths to stderr while debugging.
+//!
+//! `dbg_grid!` and `dbg_path!` print like `dbg!` (with file and line), but only in builds with
+//! debug assertions. Highlights are colored if stderr is a terminal and `NO_COLOR` isn't set;
+//! otherwise highlighted points are drawn as `O` and paths with arrows only.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::debug::render_path;
+//! use aoc_util::grid::Grid;
+//!
+//! let grid = Grid::parse("...\n.#.\n...", |c| c).unwrap();
+//! let path = [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)];
+//!
+//! assert_eq!(">>v\n.#v\n..E", render_path(&grid, &path, false));
+//! ```
+
+use std::collections::HashMap;
+use std::env;
+use std::io::{self, IsTerminal};
+
+use crate::grid::Grid;
+
+/// ANSI escape sequence starting a highlight (bold red).
+const HIGHLIGHT: &str = "\x1b[1;31m";
+
+/// ANSI escape sequence ending a highlight.
+const RESET: &str = "\x1b[0m";
+
+/// Cells that can be drawn as a single character.
+pub trait Glyph {
+    /// The character representing the cell.
+    fn glyph(&self) -> char;
+}
+
+impl Glyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+/// `#` for `true`, `.` for `false`.
+impl Glyph for bool {
+    fn glyph(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// The byte as ASCII character.
+impl Glyph for u8 {
+    fn glyph(&self) -> char {
+        char::from(*self)
+    }
+}
+
+impl<T: Glyph + ?Sized> Glyph for &T {
+    fn glyph(&self) -> char {
+        (**self).glyph()
+    }
+}
+
+/// Returns `true` if highlights should be colored: stderr is a terminal and `NO_COLOR` isn't
+/// set.
+pub fn use_color() -> bool {
+    io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none()
+}
+
+fn render<T: Glyph>(
+    grid: &Grid<T>,
+    overlay: &HashMap<(usize, usize), char>,
+    color: bool,
+) -> String {
+    let mut out = String::new();
+    for (r, row) in grid.rows().enumerate() {
+        if r > 0 {
+            out.push('\n');
+        }
+        for (c, cell) in row.iter().enumerate() {
+            match overlay.get(&(r, c)) {
+                Some(&glyph) if color => {
+                    out.push_str(HIGHLIGHT);
+                    out.push(glyph);
+                    out.push_str(RESET);
+                }
+                Some(&glyph) => out.push(glyph),
+                None => out.push(cell.glyph()),
+            }
+        }
+    }
+    out
+}
+
+/// Draw `grid` with the `highlighted` positions `(row, col)` colored, or drawn as `O` if `color`
+/// is `false`. Positions outside of the grid are ignored.
+pub fn render_grid<T, I>(grid: &Grid<T>, highlighted: I, color: bool) -> String
+where
+    T: Glyph,
+    I: IntoIterator<Item = (usize, usize)>,
+{
+    let overlay = highlighted
+        .into_iter()
+        .filter_map(|(r, c)| {
+            let glyph = if color { grid.get(r, c)?.glyph() } else { 'O' };
+            Some(((r, c), glyph))
+        })
+        .collect();
+    render(grid, &overlay, color)
+}
+
+/// Draw `grid` with `path` overlaid: every position shows an arrow towards the next one, and the
+/// last position is drawn as `E`. Steps that are not to an orthogonal neighbor are drawn as `*`.
+pub fn render_path<T: Glyph>(grid: &Grid<T>, path: &[(usize, usize)], color: bool) -> String {
+    let mut overlay: HashMap<(usize, usize), char> = path
+        .windows(2)
+        .map(|step| {
+            let ((r, c), (nr, nc)) = (step[0], step[1]);
+            let arrow = match (nr as isize - r as isize, nc as isize - c as isize) {
+                (-1, 0) => '^',
+                (1, 0) => 'v',
+                (0, -1) => '<',
+                (0, 1) => '>',
+                _ => '*',
+            };
+            ((r, c), arrow)
+        })
+        .collect();
+    if let Some(&end) = path.last() {
+        overlay.insert(end, 'E');
+    }
+    render(grid, &overlay, color)
+}
+
+/// Print a grid to stderr (in builds with debug assertions), optionally with highlighted
+/// positions `(row, col)`.
+///
+/// # Examples
+/// ```
+/// use aoc_util::dbg_grid;
+/// use aoc_util::grid::Grid;
+///
+/// let grid = Grid::parse("#..\n.#.", |c| c == '#').unwrap();
+/// dbg_grid!(grid);
+/// dbg_grid!(grid, vec![(0, 1), (1, 2)]);
+/// ```
+#[macro_export]
+macro_rules! dbg_grid {
+    ($grid:expr $(,)?) => {
+        $crate::dbg_grid!($grid, ::std::iter::empty::<(usize, usize)>())
+    };
+    ($grid:expr, $highlighted:expr $(,)?) => {
+        if cfg!(debug_assertions) {
+            eprintln!(
+                "[{}:{}] {} =\n{}",
+                file!(),
+                line!(),
+                stringify!($grid),
+                $crate::debug::render_grid(&$grid, $highlighted, $crate::debug::use_color())
+            );
+        }
+    };
+}
+
+/// Print a grid with a path of positions `(row, col)` overlaid to stderr (in builds with debug
+/// assertions), see `render_path()`.
+///
+/// # Examples
+/// ```
+/// use aoc_util::dbg_path;
+/// use aoc_util::grid::Grid;
+///
+/// let grid = Grid::parse("..\n..", |c| c).unwrap();
+/// dbg_path!(grid, [(0, 0), (1, 0), (1, 1)]);
+/// ```
+#[macro_export]
+macro_rules! dbg_path {
+    ($grid:expr, $path:expr $(,)?) => {
+        if cfg!(debug_assertions) {
+            eprintln!(
+                "[{}:{}] {} =\n{}",
+                file!(),
+                line!(),
+                stringify!($grid),
+                $crate::debug::render_path(&$grid, &$path, $crate::debug::use_color())
+            );
+        }
+    };
+}
@@ -0,0 +1,58 @@
+//! Disk defragmentation (2017 day 14): a 128x128 grid of bits built from knot hashes.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::defrag::Disk;
+//!
+//! let disk = Disk::new("flqrgnkx");
+//!
+//! assert_eq!(8108, disk.used_squares());
+//! assert_eq!(1242, disk.regions());
+//! ```
+
+use crate::grid::Grid;
+use crate::hash::knot_hash;
+
+/// Number of rows and columns of the disk.
+pub const SIZE: usize = 128;
+
+/// The disk's usage grid, where `true` marks a used square.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disk {
+    grid: Grid<bool>,
+}
+
+impl Disk {
+    /// Build the disk grid from the key string: row `n` is the knot hash of `key-n`.
+    pub fn new(key: &str) -> Self {
+        let cells = (0..SIZE)
+            .flat_map(|row| {
+                let hash = knot_hash(&format!("{}-{}", key, row));
+                IntoIterator::into_iter(hash)
+                    .flat_map(|byte| (0..8).rev().map(move |bit| byte >> bit & 1 == 1))
+            })
+            .collect();
+
+        Self {
+            grid: Grid::from_vec(SIZE, cells).expect("grid has SIZE * SIZE cells"),
+        }
+    }
+
+    /// The usage grid.
+    pub fn grid(&self) -> &Grid<bool> {
+        &self.grid
+    }
+
+    /// Number of used squares.
+    pub fn used_squares(&self) -> usize {
+        (0..SIZE)
+            .flat_map(|row| (0..SIZE).map(move |col| (row, col)))
+            .filter(|&(row, col)| self.grid.get(row, col) == Some(&true))
+            .count()
+    }
+
+    /// Number of regions of adjacent used squares.
+    pub fn regions(&self) -> usize {
+        self.grid.count_regions(|&used| used)
+    }
+}
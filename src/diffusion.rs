@@ -0,0 +1,171 @@
+//! Simultaneous-proposal movement over sparse point sets.
+//!
+//! Every round, each point looks at its surroundings and proposes a move according to the first
+//! rule whose checked cells are all empty. Points whose proposals collide with another proposal
+//! stay where they are. After each round, the rule order is rotated by one.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::diffusion::{parse_points, Diffusion};
+//!
+//! let points = parse_points(".....\n..##.\n..#..\n.....\n..##.\n.....", '#');
+//! let mut diffusion = Diffusion::unstable_diffusion(points);
+//!
+//! assert_eq!(4, diffusion.run_until_stable());
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+/// Position of a point, given as `(x, y)` with `y` growing downwards.
+pub type Pos = (i64, i64);
+
+/// A movement rule: if none of the `check` offsets is occupied, the point proposes to move by
+/// `step`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    check: Vec<Pos>,
+    step: Pos,
+}
+
+impl Rule {
+    /// Create new `Rule` from the offsets that have to be empty and the resulting step.
+    pub fn new(check: Vec<Pos>, step: Pos) -> Self {
+        Self { check, step }
+    }
+}
+
+/// Parse all positions in a character map that contain the specified character.
+pub fn parse_points(input: &str, occupied: char) -> HashSet<Pos> {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(move |&(_, c)| c == occupied)
+                .map(move |(x, _)| (x as i64, y as i64))
+        })
+        .collect()
+}
+
+/// Proposal-based movement engine.
+#[derive(Debug, Clone)]
+pub struct Diffusion {
+    points: HashSet<Pos>,
+    rules: Vec<Rule>,
+    neighborhood: Vec<Pos>,
+    round: usize,
+}
+
+impl Diffusion {
+    /// Create new `Diffusion` from a set of points and a list of rules.
+    ///
+    /// Points that have no occupied cell in any of the rules' checked cells don't move.
+    pub fn new<I: IntoIterator<Item = Pos>>(points: I, rules: Vec<Rule>) -> Self {
+        let mut neighborhood: Vec<Pos> = rules.iter().flat_map(|r| r.check.clone()).collect();
+        neighborhood.sort_unstable();
+        neighborhood.dedup();
+
+        Self {
+            points: points.into_iter().collect(),
+            rules,
+            neighborhood,
+            round: 0,
+        }
+    }
+
+    /// Create new `Diffusion` using the rules of the "Unstable Diffusion" puzzle (north, south,
+    /// west, east).
+    pub fn unstable_diffusion<I: IntoIterator<Item = Pos>>(points: I) -> Self {
+        let rules = vec![
+            Rule::new(vec![(-1, -1), (0, -1), (1, -1)], (0, -1)),
+            Rule::new(vec![(-1, 1), (0, 1), (1, 1)], (0, 1)),
+            Rule::new(vec![(-1, -1), (-1, 0), (-1, 1)], (-1, 0)),
+            Rule::new(vec![(1, -1), (1, 0), (1, 1)], (1, 0)),
+        ];
+        Self::new(points, rules)
+    }
+
+    /// Current set of points.
+    pub fn points(&self) -> &HashSet<Pos> {
+        &self.points
+    }
+
+    /// Number of rounds that have been simulated so far.
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    /// Simulate a single round. Returns the number of points that moved.
+    pub fn step(&mut self) -> usize {
+        let occupied = |p: Pos, d: &Pos| self.points.contains(&(p.0 + d.0, p.1 + d.1));
+
+        let mut proposals: HashMap<Pos, Vec<Pos>> = HashMap::new();
+        for &p in &self.points {
+            if !self.neighborhood.iter().any(|d| occupied(p, d)) {
+                continue;
+            }
+            if let Some(rule) = self
+                .rules
+                .iter()
+                .find(|r| !r.check.iter().any(|d| occupied(p, d)))
+            {
+                let target = (p.0 + rule.step.0, p.1 + rule.step.1);
+                proposals.entry(target).or_default().push(p);
+            }
+        }
+
+        // All points move at once, so a target may be the source of another move
+        let moves: Vec<(Pos, Pos)> = proposals
+            .into_iter()
+            .filter(|(_, sources)| sources.len() == 1)
+            .map(|(target, sources)| (sources[0], target))
+            .collect();
+        for (source, _) in &moves {
+            self.points.remove(source);
+        }
+        self.points.extend(moves.iter().map(|&(_, target)| target));
+        let moved = moves.len();
+
+        if !self.rules.is_empty() {
+            self.rules.rotate_left(1);
+        }
+        self.round += 1;
+
+        moved
+    }
+
+    /// Simulate `n` rounds.
+    pub fn run(&mut self, n: usize) {
+        for _ in 0..n {
+            self.step();
+        }
+    }
+
+    /// Simulate until no point moves anymore. Returns the number of the first round in which no
+    /// point moved.
+    pub fn run_until_stable(&mut self) -> usize {
+        while self.step() > 0 {}
+        self.round
+    }
+
+    /// Smallest axis-aligned rectangle containing all points, as `(min, max)` (inclusive).
+    pub fn bounding_box(&self) -> Option<(Pos, Pos)> {
+        let mut iter = self.points.iter();
+        let first = *iter.next()?;
+        Some(iter.fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    /// Number of empty cells inside the bounding box.
+    pub fn empty_in_bounding_box(&self) -> usize {
+        match self.bounding_box() {
+            Some((min, max)) => {
+                let area = (max.0 - min.0 + 1) * (max.1 - min.1 + 1);
+                area as usize - self.points.len()
+            }
+            None => 0,
+        }
+    }
+}
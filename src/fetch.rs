@@ -0,0 +1,423 @@
+//! Downloading puzzle inputs from and submitting answers to adventofcode.com (requires the
+//! `network` feature).
+//!
+//! Inputs are cached at the location given by `project::Project::input_path()` and only
+//! downloaded if missing. Requests are made with the `curl` command line tool, which has to be
+//! installed, and all go through one process-wide `client::Client`, so they are rate limited
+//! and identified, also across downloads and submissions.
+//!
+//! Two settings are needed:
+//! - the session cookie, from the `AOC_SESSION` environment variable or the file `aoc/session`
+//!   in the user's configuration directory (`$XDG_CONFIG_HOME` or `~/.config`);
+//! - contact information for the `User-Agent`, from the `AOC_CONTACT` environment variable.
+//!
+//! With the `async` feature, `download_input_async()` and `submit_answer_async()` return futures
+//! instead of blocking. They run the request on a separate thread and therefore work with any
+//! executor.
+//!
+//! # Examples
+//! ```no_run
+//! use aoc_util::fetch::Fetch;
+//! use aoc_util::input::FileReader;
+//!
+//! let seeds: Vec<String> = FileReader::new().split_lines().fetch(2023, 5).unwrap();
+//! ```
+
+use std::env;
+use std::fs;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::io::{self, Write};
+#[cfg(feature = "async")]
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::process::{Command, Stdio};
+#[cfg(feature = "async")]
+use std::sync::Arc;
+use std::sync::{Mutex, OnceLock};
+#[cfg(feature = "async")]
+use std::task::{Context, Poll, Waker};
+#[cfg(feature = "async")]
+use std::thread;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::client::Client;
+use crate::input::FromFile;
+use crate::project::Project;
+
+/// Environment variable containing the session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Environment variable containing contact information for the `User-Agent`.
+pub const CONTACT_VAR: &str = "AOC_CONTACT";
+
+/// The client shared by all requests of the process, created on first use.
+static CLIENT: OnceLock<Mutex<Option<Client>>> = OnceLock::new();
+
+/// The URL of the input of the puzzle of the given `year` and `day`.
+pub fn input_url(year: u32, day: u32) -> String {
+    format!("https://adventofcode.com/{}/day/{}/input", year, day)
+}
+
+/// The session cookie, read from `AOC_SESSION` or the configuration file.
+///
+/// # Failures
+/// Returns an error if neither is set.
+pub fn session() -> io::Result<String> {
+    if let Some(session) = env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()) {
+        return Ok(session.trim().to_string());
+    }
+
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no configuration directory"))?;
+    let session = fs::read_to_string(config.join("aoc").join("session")).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("no session cookie in {} or configuration file", SESSION_VAR),
+        )
+    })?;
+
+    Ok(session.trim().to_string())
+}
+
+/// A `Client` identified by the contact information in `AOC_CONTACT`.
+///
+/// # Failures
+/// Returns an error if `AOC_CONTACT` is not set.
+pub fn client() -> io::Result<Client> {
+    let contact = env::var(CONTACT_VAR).unwrap_or_default();
+    Client::new(&contact).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} (set {})", e, CONTACT_VAR),
+        )
+    })
+}
+
+/// Run `f` with the process-wide client, which is created with `client()` on first use (unless
+/// one was installed with `set_client()`). Requests made this way are spaced by the client's
+/// minimum interval even if they come from different functions.
+///
+/// # Failures
+/// Returns an error if the client has to be created and `AOC_CONTACT` is not set, or if `f`
+/// fails.
+pub fn with_client<T, F: FnOnce(&mut Client) -> io::Result<T>>(f: F) -> io::Result<T> {
+    let mut shared = CLIENT
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if shared.is_none() {
+        *shared = Some(client()?);
+    }
+    f(shared.as_mut().unwrap())
+}
+
+/// Use `client` for all further requests of the process, e.g. to change its settings.
+pub fn set_client(client: Client) {
+    *CLIENT
+        .get_or_init(|| Mutex::new(None))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = Some(client);
+}
+
+/// Perform a GET request for `url` with `curl`, sending `session` as cookie.
+///
+/// # Failures
+/// Returns an error if `curl` cannot be run or the request fails.
+pub fn get(client: &mut Client, url: &str, session: &str) -> io::Result<String> {
+    request(client, url, session, &[])
+}
+
+/// Perform a POST request of the url-encoded `form` fields to `url` with `curl`, sending
+/// `session` as cookie.
+///
+/// # Failures
+/// Returns an error if `curl` cannot be run or the request fails.
+pub fn post(
+    client: &mut Client,
+    url: &str,
+    session: &str,
+    form: &[(&str, &str)],
+) -> io::Result<String> {
+    request(client, url, session, form)
+}
+
+fn request(
+    client: &mut Client,
+    url: &str,
+    session: &str,
+    form: &[(&str, &str)],
+) -> io::Result<String> {
+    client.send(|user_agent| {
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--location"])
+            .args(["--user-agent", user_agent])
+            // Pass the cookie through stdin so it doesn't show up in the process list.
+            .args(["--header", "@-"])
+            .args(["--write-out", "\n%{http_code}"]);
+        for (key, value) in form {
+            command
+                .arg("--data-urlencode")
+                .arg(format!("{}={}", key, value));
+        }
+        let mut child = command
+            .arg(url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(format!("Cookie: session={}\n", session).as_bytes())?;
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(io::Error::other(
+                String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            ));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| io::Error::other("missing HTTP status"))?;
+        let status = status
+            .trim()
+            .parse()
+            .map_err(|_| io::Error::other(format!("invalid HTTP status: {}", status)))?;
+        Ok((status, body.to_string()))
+    })
+}
+
+/// The path of the cached input of the given puzzle in `project`, downloaded first if missing.
+///
+/// # Failures
+/// Returns an error if the input isn't cached and cannot be downloaded or stored.
+pub fn cached_input(project: &Project, year: u32, day: u32) -> io::Result<PathBuf> {
+    let path = project.input_path(year, day);
+    if path.is_file() {
+        return Ok(path);
+    }
+
+    let session = session()?;
+    let input = with_client(|client| get(client, &input_url(year, day), &session))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+
+    Ok(path)
+}
+
+/// The path of the cached input of the given puzzle in the project containing the current
+/// working directory, downloaded first if missing.
+///
+/// # Failures
+/// Returns an error if the current directory isn't inside a Cargo project or the input isn't
+/// cached and cannot be downloaded or stored.
+pub fn input_path(year: u32, day: u32) -> io::Result<PathBuf> {
+    cached_input(&Project::locate()?, year, day)
+}
+
+/// The outcome of submitting an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer is right.
+    Correct,
+    /// The answer is wrong and too low.
+    TooLow,
+    /// The answer is wrong and too high.
+    TooHigh,
+    /// The answer is wrong.
+    Incorrect,
+    /// An answer was submitted too recently; the remaining wait time, if given.
+    RateLimited(Option<Duration>),
+    /// The part has already been solved (or is not unlocked yet).
+    WrongLevel,
+    /// The response could not be interpreted.
+    Unknown,
+}
+
+/// Interpret the HTML response to an answer submission.
+pub fn parse_verdict(html: &str) -> Verdict {
+    if html.contains("That's the right answer") {
+        Verdict::Correct
+    } else if html.contains("You gave an answer too recently") {
+        Verdict::RateLimited(parse_wait(html))
+    } else if html.contains("That's not the right answer") {
+        if html.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if html.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else {
+            Verdict::Incorrect
+        }
+    } else if html.contains("You don't seem to be solving the right level") {
+        Verdict::WrongLevel
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Parse `You have 1m 5s left to wait`.
+fn parse_wait(html: &str) -> Option<Duration> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for part in html[start..end].split_whitespace() {
+        let (value, factor) = match part.as_bytes().last()? {
+            b'h' => (&part[..part.len() - 1], 3600),
+            b'm' => (&part[..part.len() - 1], 60),
+            b's' => (&part[..part.len() - 1], 1),
+            _ => return None,
+        };
+        seconds += value.parse::<u64>().ok()? * factor;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Submit the `answer` of `part` (1 or 2) of the puzzle of the given `year` and `day`.
+///
+/// # Failures
+/// Returns an error if the settings are missing, the answer is an undecoded grid, or the request
+/// fails.
+pub fn submit_answer<A: Into<Answer>>(
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: A,
+) -> io::Result<Verdict> {
+    let answer = answer
+        .into()
+        .submission()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "answer is not submittable"))?;
+    let url = format!("https://adventofcode.com/{}/day/{}/answer", year, day);
+    let session = session()?;
+    let html = with_client(|client| {
+        post(
+            client,
+            &url,
+            &session,
+            &[("level", &part.to_string()), ("answer", &answer)],
+        )
+    })?;
+
+    Ok(parse_verdict(&html))
+}
+
+/// A future running a blocking function on its own thread, started when it is first polled. If
+/// the function panics, the panic is resumed when the future is polled again.
+#[cfg(feature = "async")]
+pub struct Blocking<T> {
+    task: Option<Box<dyn FnOnce() -> T + Send>>,
+    state: Arc<Mutex<BlockingState<T>>>,
+}
+
+#[cfg(feature = "async")]
+struct BlockingState<T> {
+    result: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + 'static> Blocking<T> {
+    /// Create a future that runs `task` on a new thread.
+    pub fn new<F: FnOnce() -> T + Send + 'static>(task: F) -> Self {
+        Self {
+            task: Some(Box::new(task)),
+            state: Arc::new(Mutex::new(BlockingState {
+                result: None,
+                waker: None,
+            })),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<T: Send + 'static> Future for Blocking<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut state = self.state.lock().unwrap();
+        match state.result.take() {
+            Some(Ok(result)) => return Poll::Ready(result),
+            Some(Err(payload)) => {
+                drop(state);
+                panic::resume_unwind(payload);
+            }
+            None => {}
+        }
+        state.waker = Some(cx.waker().clone());
+        drop(state);
+
+        if let Some(task) = self.task.take() {
+            let state = Arc::clone(&self.state);
+            thread::spawn(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(task));
+                let mut state = state.lock().unwrap();
+                state.result = Some(result);
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            });
+        }
+        Poll::Pending
+    }
+}
+
+/// Like `cached_input()`, but returns a future instead of blocking.
+#[cfg(feature = "async")]
+pub fn download_input_async(
+    project: Project,
+    year: u32,
+    day: u32,
+) -> Blocking<io::Result<PathBuf>> {
+    Blocking::new(move || cached_input(&project, year, day))
+}
+
+/// Like `submit_answer()`, but returns a future instead of blocking.
+#[cfg(feature = "async")]
+pub fn submit_answer_async<A: Into<Answer>>(
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: A,
+) -> Blocking<io::Result<Verdict>> {
+    let answer = answer.into();
+    Blocking::new(move || submit_answer(year, day, part, answer))
+}
+
+/// Generic trait to read the (downloaded and cached) input of a puzzle into a destination of type
+/// `T`.
+///
+/// Implemented by all readers that implement `FromFile<T>`, using the same splitting, trimming
+/// and parsing.
+pub trait Fetch<T> {
+    /// The error type
+    type Error;
+
+    /// Reads the input of the puzzle of the given `year` and `day`, downloading it if necessary.
+    fn fetch(&self, year: u32, day: u32) -> Result<T, Self::Error>;
+}
+
+impl<T, X> Fetch<T> for X
+where
+    X: FromFile<T>,
+    X::Error: From<io::Error>,
+{
+    type Error = X::Error;
+
+    fn fetch(&self, year: u32, day: u32) -> Result<T, Self::Error> {
+        self.read_from_file(input_path(year, day)?)
+    }
+}
@@ -0,0 +1,97 @@
+//! Packet scanners moving back and forth in firewall layers (2017 day 13).
+//!
+//! A scanner with range `r` is back at the top every `2 * (r - 1)` picoseconds, so whether a
+//! packet is caught is a simple modular check and no simulation is necessary.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::firewall::{min_delay, severity, Scanner};
+//!
+//! let scanners: Vec<Scanner> = ["0: 3", "1: 2", "4: 4", "6: 4"]
+//!     .iter()
+//!     .map(|s| s.parse().unwrap())
+//!     .collect();
+//!
+//! assert_eq!(24, severity(&scanners, 0));
+//! assert_eq!(10, min_delay(&scanners));
+//! ```
+
+use std::str::FromStr;
+
+/// A scanner in a firewall layer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scanner {
+    /// Depth of the layer (the time at which an undelayed packet reaches it).
+    pub depth: u64,
+    /// Number of positions the scanner moves through.
+    pub range: u64,
+}
+
+impl Scanner {
+    /// Number of picoseconds after which the scanner is back at its initial position.
+    pub fn period(&self) -> u64 {
+        (2 * self.range.saturating_sub(1)).max(1)
+    }
+
+    /// Position of the scanner at time `t` (triangle wave between `0` and `range - 1`).
+    pub fn position(&self, t: u64) -> u64 {
+        let p = t % self.period();
+        if p < self.range {
+            p
+        } else {
+            self.period() - p
+        }
+    }
+
+    /// Returns `true` if a packet leaving after `delay` picoseconds is caught by this scanner.
+    pub fn catches(&self, delay: u64) -> bool {
+        (delay + self.depth).is_multiple_of(self.period())
+    }
+
+    /// Severity of getting caught by this scanner.
+    pub fn severity(&self) -> u64 {
+        self.depth * self.range
+    }
+}
+
+impl FromStr for Scanner {
+    type Err = String;
+
+    /// Parses scanners of the form `depth: range`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid scanner: {}", s);
+        let mut parts = s.splitn(2, ':');
+        match (parts.next(), parts.next()) {
+            (Some(depth), Some(range)) => Ok(Scanner {
+                depth: depth.trim().parse().map_err(|_| invalid())?,
+                range: range.trim().parse().map_err(|_| invalid())?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Total severity of a trip leaving after `delay` picoseconds.
+pub fn severity(scanners: &[Scanner], delay: u64) -> u64 {
+    scanners
+        .iter()
+        .filter(|s| s.catches(delay))
+        .map(Scanner::severity)
+        .sum()
+}
+
+/// Returns `true` if a packet leaving after `delay` picoseconds passes without getting caught.
+pub fn passes(scanners: &[Scanner], delay: u64) -> bool {
+    !scanners.iter().any(|s| s.catches(delay))
+}
+
+/// Smallest delay with which a packet passes without getting caught.
+///
+/// Never returns if every delay gets caught (e.g. if there is a scanner with range `1`).
+pub fn min_delay(scanners: &[Scanner]) -> u64 {
+    // Check the scanners with the shortest periods first, since they reject the most delays.
+    let mut sorted = scanners.to_vec();
+    sorted.sort_by_key(Scanner::period);
+
+    (0..).find(|&delay| passes(&sorted, delay)).unwrap()
+}
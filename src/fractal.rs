@@ -0,0 +1,162 @@
+//! Pattern enhancement with rule books (2017 day 21, "Fractal Art").
+//!
+//! Each iteration splits the square grid into 2x2 blocks (if its size is even) or 3x3 blocks,
+//! and replaces every block with the output of the rule matching any of its orientations.
+//!
+//! After three iterations, every 3x3 block has become a 9x9 grid which splits into nine 3x3
+//! blocks that evolve independently of each other. `Enhancer::count_on()` exploits this and
+//! memoizes the result per block, which makes large iteration counts cheap.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::fractal::{Enhancer, START};
+//!
+//! let enhancer: Enhancer = "../.# => ##./#../...\n.#./..#/### => #..#/..../..../#..#"
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(12, enhancer.count_on(START, 2).unwrap());
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// The start pattern of the puzzle.
+pub const START: &str = ".#./..#/###";
+
+/// Parse a pattern in the slash-separated notation (e.g. `.#./..#/###`).
+pub fn parse_pattern(s: &str) -> Result<Grid<bool>, String> {
+    let rows: Vec<&str> = s.trim().split('/').collect();
+    let size = rows.len();
+    if rows.iter().any(|r| r.chars().count() != size) {
+        return Err(format!("pattern is not square: {}", s));
+    }
+    let cells = rows
+        .iter()
+        .flat_map(|r| r.chars())
+        .map(|c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(format!("invalid character in pattern: {}", c)),
+        })
+        .collect::<Result<Vec<bool>, String>>()?;
+
+    Grid::from_vec(size, cells).ok_or_else(|| format!("invalid pattern: {}", s))
+}
+
+fn count(grid: &Grid<bool>) -> usize {
+    (0..grid.height())
+        .flat_map(|r| (0..grid.width()).map(move |c| (r, c)))
+        .filter(|&(r, c)| grid.get(r, c) == Some(&true))
+        .count()
+}
+
+/// A rule book for enhancing patterns.
+#[derive(Debug, Clone, Default)]
+pub struct Enhancer {
+    rules: HashMap<Grid<bool>, Grid<bool>>,
+}
+
+impl Enhancer {
+    /// Add a rule. It applies to all orientations of `input`.
+    pub fn add_rule(&mut self, input: &Grid<bool>, output: Grid<bool>) {
+        for orientation in input.orientations() {
+            self.rules.insert(orientation, output.clone());
+        }
+    }
+
+    /// Apply one enhancement step.
+    ///
+    /// # Failures
+    /// Returns an error if a block matches no rule, or the grid isn't square.
+    pub fn enhance(&self, grid: &Grid<bool>) -> Result<Grid<bool>, String> {
+        let size = grid.width();
+        if grid.height() != size {
+            return Err(String::from("grid is not square"));
+        }
+        let block = if size.is_multiple_of(2) { 2 } else { 3 };
+        let per_row = size / block;
+
+        let blocks = (0..per_row * per_row)
+            .map(|i| {
+                let sub = grid
+                    .subgrid((i / per_row) * block, (i % per_row) * block, block, block)
+                    .ok_or_else(|| String::from("grid size is not divisible into blocks"))?;
+                self.rules
+                    .get(&sub)
+                    .cloned()
+                    .ok_or_else(|| String::from("no rule matches block"))
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Grid::from_blocks(&blocks, per_row))
+    }
+
+    /// Apply `iterations` enhancement steps.
+    pub fn enhance_n(&self, grid: &Grid<bool>, iterations: usize) -> Result<Grid<bool>, String> {
+        let mut grid = grid.clone();
+        for _ in 0..iterations {
+            grid = self.enhance(&grid)?;
+        }
+        Ok(grid)
+    }
+
+    /// Number of cells that are on after `iterations` steps, starting from `start` (given in the
+    /// slash-separated notation).
+    pub fn count_on(&self, start: &str, iterations: usize) -> Result<usize, String> {
+        let start = parse_pattern(start)?;
+        if start.width() != 3 {
+            return Ok(count(&self.enhance_n(&start, iterations)?));
+        }
+        let mut memo = HashMap::new();
+        self.count_block(&start, iterations, &mut memo)
+    }
+
+    fn count_block(
+        &self,
+        block: &Grid<bool>,
+        iterations: usize,
+        memo: &mut HashMap<(Grid<bool>, usize), usize>,
+    ) -> Result<usize, String> {
+        if iterations < 3 {
+            return Ok(count(&self.enhance_n(block, iterations)?));
+        }
+        if let Some(&n) = memo.get(&(block.clone(), iterations)) {
+            return Ok(n);
+        }
+
+        // 3x3 -> 4x4 -> 6x6 -> 9x9, which decomposes into nine independent 3x3 blocks.
+        let expanded = self.enhance_n(block, 3)?;
+        let mut total = 0;
+        for r in 0..3 {
+            for c in 0..3 {
+                let sub = expanded.subgrid(r * 3, c * 3, 3, 3).unwrap();
+                total += self.count_block(&sub, iterations - 3, memo)?;
+            }
+        }
+
+        memo.insert((block.clone(), iterations), total);
+        Ok(total)
+    }
+}
+
+impl FromStr for Enhancer {
+    type Err = String;
+
+    /// Parses one rule `input => output` per line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut enhancer = Enhancer::default();
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let mut parts = line.splitn(2, "=>");
+            match (parts.next(), parts.next()) {
+                (Some(input), Some(output)) => {
+                    enhancer.add_rule(&parse_pattern(input)?, parse_pattern(output)?)
+                }
+                _ => return Err(format!("invalid rule: {}", line)),
+            }
+        }
+        Ok(enhancer)
+    }
+}
@@ -0,0 +1,304 @@
+//! Combat between elves and goblins in a cave ("Beverage Bandits").
+//!
+//! Units act in reading order (top to bottom, left to right). On its turn, a unit that is not
+//! next to an enemy moves one step towards the nearest square in range of an enemy, then
+//! attacks the adjacent enemy with the fewest hit points. All ties are broken in reading order.
+//! Combat ends as soon as a unit finds no enemies at the start of its turn.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::game::melee::{Battle, Race};
+//!
+//! let map = "#######\n#.G...#\n#...EG#\n#.#.#G#\n#..G#E#\n#.....#\n#######";
+//! let mut battle: Battle = map.parse().unwrap();
+//! let outcome = battle.run();
+//!
+//! assert_eq!(Race::Goblin, outcome.winner);
+//! assert_eq!(27730, outcome.score());
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// Position of a unit, given as `(row, col)` so that ordering is reading order.
+pub type Pos = (usize, usize);
+
+/// Attack power every unit starts with.
+pub const ATTACK_POWER: u32 = 3;
+/// Hit points every unit starts with.
+pub const HIT_POINTS: u32 = 200;
+
+/// The side a unit fights on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Race {
+    /// Elf (`E`)
+    Elf,
+    /// Goblin (`G`)
+    Goblin,
+}
+
+/// A single combatant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unit {
+    /// Side of the unit.
+    pub race: Race,
+    /// Current position.
+    pub pos: Pos,
+    /// Remaining hit points; the unit is dead at `0`.
+    pub hp: u32,
+    /// Damage dealt per attack.
+    pub attack: u32,
+}
+
+impl Unit {
+    /// Returns `true` if the unit has hit points left.
+    pub fn is_alive(&self) -> bool {
+        self.hp > 0
+    }
+}
+
+/// Result of a finished battle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outcome {
+    /// Side of the surviving units.
+    pub winner: Race,
+    /// Number of rounds that were completed before combat ended.
+    pub full_rounds: usize,
+    /// Sum of the hit points of all surviving units.
+    pub remaining_hp: u32,
+    /// Number of elves that died.
+    pub elf_deaths: usize,
+}
+
+impl Outcome {
+    /// The puzzle answer: completed rounds times remaining hit points.
+    pub fn score(&self) -> u64 {
+        self.full_rounds as u64 * u64::from(self.remaining_hp)
+    }
+}
+
+/// State of a battle: the cave and all units (dead ones included).
+#[derive(Debug, Clone)]
+pub struct Battle {
+    open: Grid<bool>,
+    units: Vec<Unit>,
+    rounds: usize,
+}
+
+impl Battle {
+    /// Create new `Battle` from a map of open squares and a list of units.
+    pub fn new(open: Grid<bool>, units: Vec<Unit>) -> Self {
+        Self {
+            open,
+            units,
+            rounds: 0,
+        }
+    }
+
+    /// All units, including dead ones.
+    pub fn units(&self) -> &[Unit] {
+        &self.units
+    }
+
+    /// Number of rounds completed so far.
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Set the attack power of all elves.
+    pub fn set_elf_attack(&mut self, attack: u32) {
+        for unit in self.units.iter_mut().filter(|u| u.race == Race::Elf) {
+            unit.attack = attack;
+        }
+    }
+
+    /// Simulate a single round. Returns `false` if combat ended during the round (in which case
+    /// the round doesn't count as completed).
+    pub fn round(&mut self) -> bool {
+        let mut order: Vec<usize> = (0..self.units.len())
+            .filter(|&i| self.units[i].is_alive())
+            .collect();
+        order.sort_by_key(|&i| self.units[i].pos);
+
+        for i in order {
+            if !self.units[i].is_alive() {
+                continue;
+            }
+            let race = self.units[i].race;
+            if !self.units.iter().any(|u| u.is_alive() && u.race != race) {
+                return false;
+            }
+
+            if self.adjacent_target(i).is_none() {
+                if let Some(step) = self.next_step(i) {
+                    self.units[i].pos = step;
+                }
+            }
+            if let Some(target) = self.adjacent_target(i) {
+                let attack = self.units[i].attack;
+                let enemy = &mut self.units[target];
+                enemy.hp = enemy.hp.saturating_sub(attack);
+            }
+        }
+
+        self.rounds += 1;
+        true
+    }
+
+    /// Simulate until combat ends.
+    pub fn run(&mut self) -> Outcome {
+        while self.round() {}
+        self.outcome()
+    }
+
+    /// Simulate until combat ends or an elf dies. Returns `None` if an elf died.
+    pub fn run_without_elf_losses(&mut self) -> Option<Outcome> {
+        let elves = self.alive(Race::Elf);
+        loop {
+            let running = self.round();
+            if self.alive(Race::Elf) < elves {
+                return None;
+            }
+            if !running {
+                return Some(self.outcome());
+            }
+        }
+    }
+
+    fn alive(&self, race: Race) -> usize {
+        self.units
+            .iter()
+            .filter(|u| u.is_alive() && u.race == race)
+            .count()
+    }
+
+    fn outcome(&self) -> Outcome {
+        let survivors = self.units.iter().filter(|u| u.is_alive());
+        Outcome {
+            winner: survivors.clone().next().map_or(Race::Elf, |u| u.race),
+            full_rounds: self.rounds,
+            remaining_hp: survivors.map(|u| u.hp).sum(),
+            elf_deaths: self
+                .units
+                .iter()
+                .filter(|u| !u.is_alive() && u.race == Race::Elf)
+                .count(),
+        }
+    }
+
+    /// Adjacent squares in reading order.
+    fn neighbors(&self, (row, col): Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.open.neighbors4(row, col)
+    }
+
+    /// The adjacent enemy with the fewest hit points.
+    fn adjacent_target(&self, i: usize) -> Option<usize> {
+        let unit = self.units[i];
+        let adjacent: Vec<Pos> = self.neighbors(unit.pos).collect();
+        (0..self.units.len())
+            .filter(|&j| {
+                let other = &self.units[j];
+                other.is_alive() && other.race != unit.race && adjacent.contains(&other.pos)
+            })
+            .min_by_key(|&j| (self.units[j].hp, self.units[j].pos))
+    }
+
+    /// The square unit `i` moves to, if it can reach any square in range of an enemy.
+    fn next_step(&self, i: usize) -> Option<Pos> {
+        let unit = self.units[i];
+        let occupied: HashSet<Pos> = self
+            .units
+            .iter()
+            .filter(|u| u.is_alive())
+            .map(|u| u.pos)
+            .collect();
+        let free = |p: Pos| self.open.get(p.0, p.1) == Some(&true) && !occupied.contains(&p);
+
+        let in_range: HashSet<Pos> = self
+            .units
+            .iter()
+            .filter(|u| u.is_alive() && u.race != unit.race)
+            .flat_map(|u| self.neighbors(u.pos))
+            .filter(|&p| free(p))
+            .collect();
+
+        // Nearest square in range, ties broken in reading order.
+        let distances = self.distances(unit.pos, &free);
+        let target = in_range
+            .iter()
+            .filter_map(|p| distances.get(p).map(|&d| (d, *p)))
+            .min()?
+            .1;
+
+        // First step along a shortest path to the target, ties broken in reading order.
+        let back = self.distances(target, &free);
+        self.neighbors(unit.pos)
+            .filter_map(|p| back.get(&p).map(|&d| (d, p)))
+            .min()
+            .map(|(_, p)| p)
+    }
+
+    /// Breadth-first distances from `start` to all reachable free squares (`start` itself
+    /// doesn't need to be free).
+    fn distances<F: Fn(Pos) -> bool>(&self, start: Pos, free: &F) -> HashMap<Pos, usize> {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+        distances.insert(start, 0);
+        queue.push_back(start);
+
+        while let Some(p) = queue.pop_front() {
+            let d = distances[&p];
+            for n in self.neighbors(p) {
+                if free(n) && !distances.contains_key(&n) {
+                    distances.insert(n, d + 1);
+                    queue.push_back(n);
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl FromStr for Battle {
+    type Err = String;
+
+    /// Parses a map of walls (`#`), open squares (`.`), elves (`E`) and goblins (`G`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut units = Vec::new();
+        for (row, line) in s.lines().filter(|l| !l.trim().is_empty()).enumerate() {
+            for (col, c) in line.chars().enumerate() {
+                let race = match c {
+                    'E' => Race::Elf,
+                    'G' => Race::Goblin,
+                    '#' | '.' => continue,
+                    _ => return Err(format!("invalid map character: {}", c)),
+                };
+                units.push(Unit {
+                    race,
+                    pos: (row, col),
+                    hp: HIT_POINTS,
+                    attack: ATTACK_POWER,
+                });
+            }
+        }
+
+        let open = Grid::parse(s, |c| c != '#')?;
+        Ok(Battle::new(open, units))
+    }
+}
+
+/// Find the smallest elf attack power with which the elves win without losing a single elf.
+///
+/// Attack powers above the default are tried in increasing order; returns `None` if the elves
+/// lose even when killing every goblin with a single hit.
+pub fn min_winning_elf_attack(battle: &Battle) -> Option<(u32, Outcome)> {
+    let max = HIT_POINTS.max(ATTACK_POWER + 1);
+    (ATTACK_POWER + 1..=max).find_map(|attack| {
+        let mut battle = battle.clone();
+        battle.set_elf_attack(attack);
+        battle.run_without_elf_losses().map(|o| (attack, o))
+    })
+}
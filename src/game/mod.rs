@@ -0,0 +1,3 @@
+//! Turn-based game simulations.
+
+pub mod melee;
@@ -0,0 +1,101 @@
+//! Dueling generators (2017 day 15): multiplicative generators modulo `2^31 - 1` and a judge
+//! comparing the lowest 16 bits of their values.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::generators::{count_matches, Generator};
+//!
+//! let a = Generator::a(65);
+//! let b = Generator::b(8921);
+//! assert_eq!(1, count_matches(a, b, 5));
+//! ```
+
+/// The modulus used by the generators: `2^31 - 1`.
+pub const MODULUS: u64 = 0x7FFF_FFFF;
+/// Factor of generator A.
+pub const FACTOR_A: u64 = 16807;
+/// Factor of generator B.
+pub const FACTOR_B: u64 = 48271;
+
+/// A generator producing `previous * factor mod (2^31 - 1)`, optionally only yielding values
+/// that are multiples of a criterion.
+#[derive(Debug, Clone)]
+pub struct Generator {
+    value: u64,
+    factor: u64,
+    multiple_of: u64,
+}
+
+impl Generator {
+    /// Create new `Generator` with the given start value and factor.
+    pub fn new(start: u64, factor: u64) -> Self {
+        Self {
+            value: start % MODULUS,
+            factor,
+            multiple_of: 1,
+        }
+    }
+
+    /// Create new generator A.
+    pub fn a(start: u64) -> Self {
+        Self::new(start, FACTOR_A)
+    }
+
+    /// Create new generator B.
+    pub fn b(start: u64) -> Self {
+        Self::new(start, FACTOR_B)
+    }
+
+    /// Only yield values that are multiples of `n`. If `n` is a power of two, the check is a
+    /// single bitmask test.
+    pub fn multiples_of(mut self, n: u64) -> Self {
+        self.multiple_of = n.max(1);
+        self
+    }
+
+    fn advance(&mut self) -> u64 {
+        // Reduction modulo 2^31 - 1 without division: 2^31 ≡ 1.
+        let product = self.value * self.factor;
+        let mut value = (product & MODULUS) + (product >> 31);
+        if value >= MODULUS {
+            value -= MODULUS;
+        }
+        self.value = value;
+        value
+    }
+}
+
+impl Iterator for Generator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.multiple_of.is_power_of_two() {
+            let mask = self.multiple_of - 1;
+            loop {
+                let value = self.advance();
+                if value & mask == 0 {
+                    return Some(value);
+                }
+            }
+        } else {
+            loop {
+                let value = self.advance();
+                if value.is_multiple_of(self.multiple_of) {
+                    return Some(value);
+                }
+            }
+        }
+    }
+}
+
+/// Count how many of the first `pairs` value pairs agree in their lowest 16 bits.
+pub fn count_matches<A, B>(a: A, b: B, pairs: usize) -> usize
+where
+    A: Iterator<Item = u64>,
+    B: Iterator<Item = u64>,
+{
+    a.zip(b)
+        .take(pairs)
+        .filter(|(x, y)| (x ^ y) & 0xFFFF == 0)
+        .count()
+}
@@ -0,0 +1,268 @@
+//! Coordinate types.
+//!
+//! Coordinates follow the usual screen convention of puzzle maps: `x` grows to the right and `y`
+//! grows downwards. Rotations are named by how they look on such a map.
+
+use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point (or vector) in two dimensions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    /// Horizontal coordinate, growing to the right.
+    pub x: T,
+    /// Vertical coordinate, growing downwards.
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    /// Create new `Point2`.
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Point2<T> {
+    /// Rotate by 90° counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// Rotate by 90° clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+}
+
+impl Point2<i64> {
+    /// Unit vector pointing up (towards smaller `y`).
+    pub const UP: Self = Self::new(0, -1);
+    /// Unit vector pointing down (towards larger `y`).
+    pub const DOWN: Self = Self::new(0, 1);
+    /// Unit vector pointing left (towards smaller `x`).
+    pub const LEFT: Self = Self::new(-1, 0);
+    /// Unit vector pointing right (towards larger `x`).
+    pub const RIGHT: Self = Self::new(1, 0);
+
+    /// Manhattan distance to another point.
+    pub fn manhattan(self, other: Self) -> u64 {
+        (self.x - other.x).unsigned_abs() + (self.y - other.y).unsigned_abs()
+    }
+}
+
+/// One of the four cardinal directions. North points up on the map (towards smaller `y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    /// Up (towards smaller `y`).
+    North,
+    /// Right (towards larger `x`).
+    East,
+    /// Down (towards larger `y`).
+    South,
+    /// Left (towards smaller `x`).
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise starting at `North`.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Parse a direction from one of `NESW`, `URDL` or `^>v<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'N' | 'U' | '^' => Some(Direction::North),
+            'E' | 'R' | '>' => Some(Direction::East),
+            'S' | 'D' | 'v' => Some(Direction::South),
+            'W' | 'L' | '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    /// The direction after turning 90° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    /// The direction after turning 90° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// The unit vector pointing in this direction.
+    pub fn delta(self) -> Point2<i64> {
+        match self {
+            Direction::North => Point2::UP,
+            Direction::East => Point2::RIGHT,
+            Direction::South => Point2::DOWN,
+            Direction::West => Point2::LEFT,
+        }
+    }
+}
+
+/// One of the four cardinal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    /// Up (towards smaller `y`).
+    North,
+    /// Up and right.
+    NorthEast,
+    /// Right (towards larger `x`).
+    East,
+    /// Down and right.
+    SouthEast,
+    /// Down (towards larger `y`).
+    South,
+    /// Down and left.
+    SouthWest,
+    /// Left (towards smaller `x`).
+    West,
+    /// Up and left.
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise starting at `North`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The direction after turning 45° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// The direction after turning 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The opposite direction.
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// The offset of one step in this direction (diagonal steps change both coordinates).
+    pub fn delta(self) -> Point2<i64> {
+        match self {
+            Direction8::North => Point2::UP,
+            Direction8::NorthEast => Point2::UP + Point2::RIGHT,
+            Direction8::East => Point2::RIGHT,
+            Direction8::SouthEast => Point2::DOWN + Point2::RIGHT,
+            Direction8::South => Point2::DOWN,
+            Direction8::SouthWest => Point2::DOWN + Point2::LEFT,
+            Direction8::West => Point2::LEFT,
+            Direction8::NorthWest => Point2::UP + Point2::LEFT,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+impl Add<Direction> for Point2<i64> {
+    type Output = Self;
+
+    fn add(self, direction: Direction) -> Self {
+        self + direction.delta()
+    }
+}
+
+impl AddAssign<Direction> for Point2<i64> {
+    fn add_assign(&mut self, direction: Direction) {
+        *self += direction.delta();
+    }
+}
+
+impl Add<Direction8> for Point2<i64> {
+    type Output = Self;
+
+    fn add(self, direction: Direction8) -> Self {
+        self + direction.delta()
+    }
+}
+
+/// Smallest axis-aligned rectangle containing all points, as `(min, max)` (inclusive).
+pub fn bounding_box<'a, I>(points: I) -> Option<(Point2<i64>, Point2<i64>)>
+where
+    I: IntoIterator<Item = &'a Point2<i64>>,
+{
+    let mut iter = points.into_iter();
+    let first = *iter.next()?;
+    Some(iter.fold((first, first), |(min, max), p| {
+        (
+            Point2::new(min.x.min(p.x), min.y.min(p.y)),
+            Point2::new(max.x.max(p.x), max.y.max(p.y)),
+        )
+    }))
+}
+
+impl<T: Add<Output = T>> Add for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point2<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self::new(self.x * scalar, self.y * scalar)
+    }
+}
+
+impl<T> From<(T, T)> for Point2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self::new(x, y)
+    }
+}
@@ -0,0 +1,639 @@
+//! Two-dimensional grids of cells, as found in most map-based puzzles.
+//!
+//! Cells of a `Grid` are addressed by `(row, col)`, with row `0` at the top. A `SparseGrid`
+//! covers unbounded maps and is addressed by `Point2<i64>` coordinates instead.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::grid::Grid;
+//!
+//! let grid = Grid::parse("..##.\n#...#\n.#...", |c| c == '#').unwrap();
+//!
+//! assert_eq!((3, 5), (grid.height(), grid.width()));
+//! assert_eq!(Some(&true), grid.get(1, 0));
+//! assert_eq!(1, grid.count_on_slope(3, 1, |&tree| tree));
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::iter::FromIterator;
+use std::ops::{Add, Index, IndexMut, Sub};
+
+use crate::geometry::{self, Direction, Point2};
+
+/// Offsets `(row, col)` of the 4 orthogonal neighbors, in reading order.
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets `(row, col)` of the 8 orthogonal and diagonal neighbors, in reading order.
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create new `Grid` from a row-major list of cells.
+    ///
+    /// Returns `None` if the number of cells is not a multiple of `width` (or `width` is zero
+    /// while there are cells).
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Option<Self> {
+        let height = match cells.len().checked_div(width) {
+            Some(height) if height * width == cells.len() => height,
+            None if cells.is_empty() => 0,
+            _ => return None,
+        };
+
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parse a grid from lines of characters, mapping each character with `f`.
+    ///
+    /// # Failures
+    /// Returns an error if the lines don't all have the same length.
+    pub fn parse<F: FnMut(char) -> T>(input: &str, mut f: F) -> Result<Self, String> {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for line in input.lines().filter(|l| !l.trim().is_empty()) {
+            let len = line.chars().count();
+            match width {
+                None => width = Some(len),
+                Some(w) if w != len => {
+                    return Err(format!(
+                        "expected line of length {}, got {}: {}",
+                        w, len, line
+                    ))
+                }
+                _ => {}
+            }
+            cells.extend(line.chars().map(&mut f));
+        }
+
+        let width = width.unwrap_or(0);
+        Ok(Self {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        })
+    }
+
+    /// Number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The cell at `(row, col)`, or `None` if out of bounds.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Mutable reference to the cell at `(row, col)`, or `None` if out of bounds.
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            self.cells.get_mut(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// The cells of row `row`, or `None` if out of bounds.
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row < self.height {
+            Some(&self.cells[row * self.width..(row + 1) * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// All rows, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+
+    /// The cells of column `col`, from top to bottom. Empty if out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let rows = if col < self.width { self.height } else { 0 };
+        (0..rows).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    /// All columns, from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    /// The positions of the (up to 4) orthogonal neighbors of `(row, col)` within the grid, in
+    /// reading order.
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(row, col, &OFFSETS4, false)
+    }
+
+    /// The positions of the (up to 8) orthogonal and diagonal neighbors of `(row, col)` within
+    /// the grid, in reading order.
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(row, col, &OFFSETS8, false)
+    }
+
+    /// Like `neighbors4()`, but the grid wraps around at its edges, so every cell has 4 neighbors
+    /// (which need not be distinct on very small grids).
+    pub fn neighbors4_wrapping(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(row, col, &OFFSETS4, true)
+    }
+
+    /// Like `neighbors8()`, but the grid wraps around at its edges, so every cell has 8 neighbors
+    /// (which need not be distinct on very small grids).
+    pub fn neighbors8_wrapping(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(row, col, &OFFSETS8, true)
+    }
+
+    /// The position one step from `(row, col)` in `direction`, or `None` if it leaves the grid.
+    pub fn step(&self, row: usize, col: usize, direction: Direction) -> Option<(usize, usize)> {
+        let delta = direction.delta();
+        let row = row.checked_add_signed(delta.y as isize)?;
+        let col = col.checked_add_signed(delta.x as isize)?;
+        if row < self.height && col < self.width {
+            Some((row, col))
+        } else {
+            None
+        }
+    }
+
+    fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+        offsets: &'static [(isize, isize)],
+        wrap: bool,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let (height, width) = (self.height as isize, self.width as isize);
+        let inside = row < self.height && col < self.width;
+        offsets
+            .iter()
+            .filter(move |_| inside)
+            .filter_map(move |&(dr, dc)| {
+                let (r, c) = (row as isize + dr, col as isize + dc);
+                if wrap {
+                    Some((r.rem_euclid(height) as usize, c.rem_euclid(width) as usize))
+                } else if (0..height).contains(&r) && (0..width).contains(&c) {
+                    Some((r as usize, c as usize))
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Count the cells matching `predicate` when moving from the top-left corner `dx` columns to
+    /// the right and `dy` rows down per step until passing the bottom. The grid repeats
+    /// infinitely to the right.
+    ///
+    /// # Panics
+    /// Panics if `dy` is zero.
+    pub fn count_on_slope<P: Fn(&T) -> bool>(&self, dx: usize, dy: usize, predicate: P) -> usize {
+        assert!(dy > 0, "slope must move downwards");
+        if self.width == 0 {
+            return 0;
+        }
+
+        (0..self.height)
+            .step_by(dy)
+            .enumerate()
+            .filter(|&(step, row)| {
+                let col = (step * dx) % self.width;
+                predicate(&self.cells[row * self.width + col])
+            })
+            .count()
+    }
+
+    /// Product of `count_on_slope()` over several `(dx, dy)` slopes.
+    pub fn slope_product<P: Fn(&T) -> bool>(&self, slopes: &[(usize, usize)], predicate: P) -> u64 {
+        slopes
+            .iter()
+            .map(|&(dx, dy)| self.count_on_slope(dx, dy, &predicate) as u64)
+            .product()
+    }
+
+    /// All cells of the region (4-connected cells matching `predicate`) containing `(row, col)`.
+    /// Returns an empty list if the start cell doesn't match.
+    pub fn flood_fill<P: Fn(&T) -> bool>(
+        &self,
+        row: usize,
+        col: usize,
+        predicate: P,
+    ) -> Vec<(usize, usize)> {
+        let mut visited = vec![false; self.cells.len()];
+        self.fill_from(row, col, &predicate, &mut visited)
+    }
+
+    fn fill_from<P: Fn(&T) -> bool>(
+        &self,
+        row: usize,
+        col: usize,
+        predicate: &P,
+        visited: &mut [bool],
+    ) -> Vec<(usize, usize)> {
+        let mut region = Vec::new();
+        match self.get(row, col) {
+            Some(cell) if predicate(cell) && !visited[row * self.width + col] => {}
+            _ => return region,
+        }
+
+        let mut stack = vec![(row, col)];
+        visited[row * self.width + col] = true;
+        while let Some((r, c)) = stack.pop() {
+            region.push((r, c));
+            for (nr, nc) in self.neighbors4(r, c) {
+                let idx = nr * self.width + nc;
+                if !visited[idx] && predicate(&self.cells[idx]) {
+                    visited[idx] = true;
+                    stack.push((nr, nc));
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Number of regions (4-connected groups of cells matching `predicate`).
+    pub fn count_regions<P: Fn(&T) -> bool>(&self, predicate: P) -> usize {
+        let mut visited = vec![false; self.cells.len()];
+        let mut regions = 0;
+        for row in 0..self.height {
+            for col in 0..self.width {
+                if !self
+                    .fill_from(row, col, &predicate, &mut visited)
+                    .is_empty()
+                {
+                    regions += 1;
+                }
+            }
+        }
+        regions
+    }
+
+    /// Number of 4-connected steps from every cell to the nearest of `sources`, moving only
+    /// through cells matching `passable`. Unreachable cells are `None`.
+    ///
+    /// Sources are at distance `0` even if they are not passable themselves; sources outside of
+    /// the grid are ignored.
+    pub fn distance_field<I, P>(&self, sources: I, passable: P) -> Grid<Option<u32>>
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+        P: Fn(&T) -> bool,
+    {
+        let mut distances = vec![None; self.cells.len()];
+        let mut queue = VecDeque::new();
+        for (row, col) in sources {
+            if row < self.height && col < self.width && distances[row * self.width + col].is_none()
+            {
+                distances[row * self.width + col] = Some(0);
+                queue.push_back((row, col, 0));
+            }
+        }
+
+        while let Some((row, col, distance)) = queue.pop_front() {
+            for (nr, nc) in self.neighbors4(row, col) {
+                let idx = nr * self.width + nc;
+                if distances[idx].is_none() && passable(&self.cells[idx]) {
+                    distances[idx] = Some(distance + 1);
+                    queue.push_back((nr, nc, distance + 1));
+                }
+            }
+        }
+
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: distances,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// # Panics
+    /// Panics if `(row, col)` is out of bounds.
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        self.get(row, col).expect("grid position out of bounds")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    /// # Panics
+    /// Panics if `(row, col)` is out of bounds.
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        self.get_mut(row, col).expect("grid position out of bounds")
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create new `Grid` with all cells set to `value`.
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Copy of the grid rotated by 90° clockwise.
+    pub fn rotate_right(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|col| {
+                (0..self.height)
+                    .rev()
+                    .map(move |row| self.cells[row * self.width + col].clone())
+            })
+            .collect();
+        Self {
+            width: self.height,
+            height: self.width,
+            cells,
+        }
+    }
+
+    /// Copy of the grid mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        let cells = (0..self.height)
+            .flat_map(|row| {
+                (0..self.width)
+                    .rev()
+                    .map(move |col| self.cells[row * self.width + col].clone())
+            })
+            .collect();
+        Self {
+            width: self.width,
+            height: self.height,
+            cells,
+        }
+    }
+
+    /// All eight orientations of the grid (four rotations, each optionally mirrored).
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+        let mut current = self.clone();
+        for _ in 0..4 {
+            orientations.push(current.flip_horizontal());
+            let next = current.rotate_right();
+            orientations.push(current);
+            current = next;
+        }
+        orientations
+    }
+
+    /// Copy of the rectangular section with top-left corner `(row, col)`, or `None` if it
+    /// doesn't fit into the grid.
+    pub fn subgrid(&self, row: usize, col: usize, height: usize, width: usize) -> Option<Self> {
+        if row + height > self.height || col + width > self.width {
+            return None;
+        }
+        let cells = (row..row + height)
+            .flat_map(|r| self.cells[r * self.width + col..r * self.width + col + width].iter())
+            .cloned()
+            .collect();
+        Some(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Assemble a grid from a square arrangement of equally sized blocks, given row by row.
+    ///
+    /// # Panics
+    /// Panics if the blocks don't all have the same size or their number doesn't equal
+    /// `blocks_per_row` squared.
+    pub fn from_blocks(blocks: &[Self], blocks_per_row: usize) -> Self {
+        assert_eq!(
+            blocks_per_row * blocks_per_row,
+            blocks.len(),
+            "blocks must form a square"
+        );
+        let (bh, bw) = match blocks.first() {
+            Some(b) => (b.height, b.width),
+            None => return Self::from_vec(0, Vec::new()).unwrap(),
+        };
+        assert!(
+            blocks.iter().all(|b| b.height == bh && b.width == bw),
+            "blocks must have the same size"
+        );
+
+        let mut cells = Vec::with_capacity(blocks.len() * bh * bw);
+        for block_row in blocks.chunks(blocks_per_row) {
+            for r in 0..bh {
+                for block in block_row {
+                    cells.extend_from_slice(&block.cells[r * bw..(r + 1) * bw]);
+                }
+            }
+        }
+        Self {
+            width: bw * blocks_per_row,
+            height: bh * blocks_per_row,
+            cells,
+        }
+    }
+}
+
+/// Summed-area table of a grid: answers sums over arbitrary rectangles in constant time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummedArea<T> {
+    width: usize,
+    height: usize,
+    /// `sums[(r * (width + 1)) + c]` is the sum of all cells above and left of `(r, c)`.
+    sums: Vec<T>,
+}
+
+impl<T> SummedArea<T>
+where
+    T: Copy + Default + Add<Output = T> + Sub<Output = T>,
+{
+    /// Build the table for a grid.
+    pub fn new(grid: &Grid<T>) -> Self {
+        let stride = grid.width + 1;
+        let mut sums = vec![T::default(); stride * (grid.height + 1)];
+        for row in 0..grid.height {
+            for col in 0..grid.width {
+                let cell = grid.cells[row * grid.width + col];
+                sums[(row + 1) * stride + col + 1] =
+                    cell + sums[row * stride + col + 1] + sums[(row + 1) * stride + col]
+                        - sums[row * stride + col];
+            }
+        }
+
+        Self {
+            width: grid.width,
+            height: grid.height,
+            sums,
+        }
+    }
+
+    /// Sum of the `height` x `width` rectangle with its top left corner at `(row, col)`, or
+    /// `None` if the rectangle exceeds the grid.
+    pub fn sum(&self, row: usize, col: usize, height: usize, width: usize) -> Option<T> {
+        if row + height > self.height || col + width > self.width {
+            return None;
+        }
+        let stride = self.width + 1;
+        let at = |r: usize, c: usize| self.sums[r * stride + c];
+        Some(
+            at(row + height, col + width) + at(row, col)
+                - at(row, col + width)
+                - at(row + height, col),
+        )
+    }
+}
+
+/// A square within a grid, as found by `Grid::max_square_sum()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Square<T> {
+    /// Row of the top left corner.
+    pub row: usize,
+    /// Column of the top left corner.
+    pub col: usize,
+    /// Side length.
+    pub size: usize,
+    /// Sum of all cells.
+    pub sum: T,
+}
+
+impl<T> Grid<T>
+where
+    T: Copy + Default + Ord + Add<Output = T> + Sub<Output = T>,
+{
+    /// Summed-area table of the grid.
+    pub fn summed_area(&self) -> SummedArea<T> {
+        SummedArea::new(self)
+    }
+
+    /// The square with the largest sum among all squares with a side length in `sizes`. Ties
+    /// are broken by size, then row, then column (smallest first).
+    ///
+    /// Uses a summed-area table, so every square costs constant time regardless of its size.
+    pub fn max_square_sum<I: IntoIterator<Item = usize>>(&self, sizes: I) -> Option<Square<T>> {
+        let table = self.summed_area();
+        let mut best: Option<Square<T>> = None;
+
+        for size in sizes {
+            if size == 0 || size > self.width || size > self.height {
+                continue;
+            }
+            for row in 0..=self.height - size {
+                for col in 0..=self.width - size {
+                    let sum = table.sum(row, col, size, size).unwrap();
+                    let square = Square {
+                        row,
+                        col,
+                        size,
+                        sum,
+                    };
+                    best = match best {
+                        Some(b) if b.sum > sum || (b.sum == sum && b.size <= size) => Some(b),
+                        _ => Some(square),
+                    };
+                }
+            }
+        }
+
+        best
+    }
+}
+
+/// A grid of cells without fixed bounds, storing only the cells that have been set.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2<i64>, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Create new, empty `SparseGrid`.
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Number of cells that have been set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns `true` if no cell has been set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Get a reference to the cell at `pos`, if it has been set.
+    pub fn get(&self, pos: Point2<i64>) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    /// Set the cell at `pos`, returning its previous value.
+    pub fn insert(&mut self, pos: Point2<i64>, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    /// Unset the cell at `pos`, returning its value.
+    pub fn remove(&mut self, pos: Point2<i64>) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// Returns `true` if the cell at `pos` has been set.
+    pub fn contains(&self, pos: Point2<i64>) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    /// Iterate over all cells that have been set, in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point2<i64>, &T)> {
+        self.cells.iter().map(|(&p, v)| (p, v))
+    }
+
+    /// Number of set cells matching a predicate.
+    pub fn count<P: Fn(&T) -> bool>(&self, predicate: P) -> usize {
+        self.cells.values().filter(|v| predicate(v)).count()
+    }
+
+    /// Smallest axis-aligned rectangle containing all set cells, as `(min, max)` (inclusive).
+    pub fn bounds(&self) -> Option<(Point2<i64>, Point2<i64>)> {
+        geometry::bounding_box(self.cells.keys())
+    }
+}
+
+impl<T> FromIterator<(Point2<i64>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2<i64>, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
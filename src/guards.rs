@@ -0,0 +1,194 @@
+//! Guard shift records and sleep analysis ("Repose Record").
+//!
+//! Records like `[1518-11-01 00:05] falls asleep` can be read in any order with
+//! `FileReader::split_lines()`; `SleepLog` sorts them chronologically before attributing each
+//! sleep interval to the guard on duty.
+//!
+//! # Examples
+//! ```no_run
+//! use aoc_util::guards::{Record, SleepLog};
+//! use aoc_util::input::{FileReader, FromFile};
+//!
+//! let records: Vec<Record> = FileReader::new()
+//!     .split_lines()
+//!     .read_from_file("guards.txt")
+//!     .unwrap();
+//! let log = SleepLog::new(records).unwrap();
+//!
+//! println!("{:?}", log.sleepiest_guard());
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Minutes of the midnight hour, during which guards sleep.
+pub const MINUTES: usize = 60;
+
+/// A point in time with minute resolution. Ordering is chronological.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Timestamp {
+    /// Year
+    pub year: u32,
+    /// Month (1-based)
+    pub month: u32,
+    /// Day of month (1-based)
+    pub day: u32,
+    /// Hour
+    pub hour: u32,
+    /// Minute
+    pub minute: u32,
+}
+
+impl FromStr for Timestamp {
+    type Err = String;
+
+    /// Parses `1518-11-01 00:05`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers: Vec<u32> = s
+            .split(&['-', ' ', ':'][..])
+            .map(|n| n.parse().map_err(|_| format!("invalid timestamp: {}", s)))
+            .collect::<Result<_, _>>()?;
+        match numbers.as_slice() {
+            &[year, month, day, hour, minute] => Ok(Timestamp {
+                year,
+                month,
+                day,
+                hour,
+                minute,
+            }),
+            _ => Err(format!("invalid timestamp: {}", s)),
+        }
+    }
+}
+
+/// What happened at a point in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Event {
+    /// A guard with the given ID begins their shift.
+    BeginShift(u32),
+    /// The guard on duty falls asleep.
+    FallsAsleep,
+    /// The guard on duty wakes up.
+    WakesUp,
+}
+
+/// A single timestamped log entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Record {
+    /// Time of the event.
+    pub time: Timestamp,
+    /// The event.
+    pub event: Event,
+}
+
+impl FromStr for Record {
+    type Err = String;
+
+    /// Parses `[1518-11-01 00:00] Guard #10 begins shift`, `[...] falls asleep` and
+    /// `[...] wakes up`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let end = s
+            .find(']')
+            .filter(|_| s.starts_with('['))
+            .ok_or_else(|| format!("missing timestamp: {}", s))?;
+        let time = s[1..end].parse()?;
+
+        let event = match s[end + 1..].trim() {
+            "falls asleep" => Event::FallsAsleep,
+            "wakes up" => Event::WakesUp,
+            text => text
+                .strip_prefix("Guard #")
+                .and_then(|t| t.strip_suffix(" begins shift"))
+                .and_then(|id| id.parse().ok())
+                .map(Event::BeginShift)
+                .ok_or_else(|| format!("invalid event: {}", text))?,
+        };
+
+        Ok(Record { time, event })
+    }
+}
+
+/// Number of times each guard was asleep at each minute of the midnight hour.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SleepLog {
+    minutes: HashMap<u32, [u32; MINUTES]>,
+}
+
+impl SleepLog {
+    /// Create new `SleepLog` from records in any order.
+    ///
+    /// # Failures
+    /// Returns an error if a guard falls asleep or wakes up before the first shift begins, or
+    /// the records of a shift don't alternate between falling asleep and waking up.
+    pub fn new(mut records: Vec<Record>) -> Result<Self, String> {
+        records.sort_by_key(|r| r.time);
+
+        let mut minutes: HashMap<u32, [u32; MINUTES]> = HashMap::new();
+        let mut guard = None;
+        let mut asleep_since = None;
+
+        for record in &records {
+            match (record.event, guard, asleep_since) {
+                (Event::BeginShift(id), _, None) => {
+                    minutes.entry(id).or_insert([0; MINUTES]);
+                    guard = Some(id);
+                }
+                (Event::FallsAsleep, Some(_), None) => asleep_since = Some(record.time.minute),
+                (Event::WakesUp, Some(id), Some(since)) => {
+                    let histogram = minutes.entry(id).or_insert([0; MINUTES]);
+                    let until = (record.time.minute as usize).min(MINUTES);
+                    for count in &mut histogram[since as usize..until] {
+                        *count += 1;
+                    }
+                    asleep_since = None;
+                }
+                _ => {
+                    return Err(format!(
+                        "unexpected event at {:?}: {:?}",
+                        record.time, record.event
+                    ))
+                }
+            }
+        }
+
+        Ok(Self { minutes })
+    }
+
+    /// Sleep histogram of a guard.
+    pub fn minutes(&self, guard: u32) -> Option<&[u32; MINUTES]> {
+        self.minutes.get(&guard)
+    }
+
+    /// Total number of minutes a guard was asleep.
+    pub fn total_asleep(&self, guard: u32) -> u32 {
+        self.minutes(guard).map_or(0, |m| m.iter().sum())
+    }
+
+    /// The minute a guard was asleep most often and how often. Ties go to the earliest minute.
+    pub fn sleepiest_minute(&self, guard: u32) -> Option<(usize, u32)> {
+        let histogram = self.minutes(guard)?;
+        let (minute, &count) = histogram
+            .iter()
+            .enumerate()
+            .max_by_key(|&(minute, count)| (count, std::cmp::Reverse(minute)))?;
+        Some((minute, count))
+    }
+
+    fn best_guard<K: Ord, F: Fn(u32) -> K>(&self, key: F) -> Option<(u32, usize)> {
+        let guard = *self.minutes.keys().max_by_key(|&&id| (key(id), id))?;
+        let (minute, _) = self.sleepiest_minute(guard)?;
+        Some((guard, minute))
+    }
+
+    /// Strategy 1: the guard with the most minutes asleep, and the minute that guard was asleep
+    /// most often.
+    pub fn sleepiest_guard(&self) -> Option<(u32, usize)> {
+        self.best_guard(|id| self.total_asleep(id))
+    }
+
+    /// Strategy 2: the guard most frequently asleep on the same minute, and that minute.
+    pub fn most_regular_sleeper(&self) -> Option<(u32, usize)> {
+        self.best_guard(|id| self.sleepiest_minute(id).map_or(0, |(_, count)| count))
+    }
+}
@@ -0,0 +1,95 @@
+//! The knot hash (2017 day 10), also used to build the disk grid of 2017 day 14.
+
+/// Lengths appended to the input bytes by the full knot hash.
+const SUFFIX: [u8; 5] = [17, 31, 73, 47, 23];
+
+/// Number of elements of the list of the full knot hash.
+const SIZE: usize = 256;
+
+/// Apply one round of knot tying to `list`, updating the current position and skip size.
+fn round(list: &mut [u8], lengths: &[usize], position: &mut usize, skip: &mut usize) {
+    let size = list.len();
+    for &length in lengths {
+        for i in 0..length / 2 {
+            let a = (*position + i) % size;
+            let b = (*position + length - 1 - i) % size;
+            list.swap(a, b);
+        }
+        *position = (*position + length + *skip) % size;
+        *skip += 1;
+    }
+}
+
+/// Tie `rounds` rounds of knots with `lengths` into the list `0..size`, keeping the position
+/// and skip size between rounds.
+///
+/// A single round on a list of 256 elements is part one of 2017 day 10.
+///
+/// # Panics
+/// Panics if `size` exceeds 256 or a length exceeds `size`.
+///
+/// # Examples
+/// ```
+/// use aoc_util::hash::sparse_hash;
+///
+/// assert_eq!(vec![3, 4, 2, 1, 0], sparse_hash(5, &[3, 4, 1, 5], 1));
+/// ```
+pub fn sparse_hash(size: usize, lengths: &[usize], rounds: usize) -> Vec<u8> {
+    assert!(size <= SIZE, "list of knot hash has at most 256 elements");
+    assert!(
+        lengths.iter().all(|&length| length <= size),
+        "length exceeds list size"
+    );
+
+    let mut list: Vec<u8> = (0..size).map(|i| i as u8).collect();
+    let (mut position, mut skip) = (0, 0);
+    for _ in 0..rounds {
+        round(&mut list, lengths, &mut position, &mut skip);
+    }
+    list
+}
+
+/// Reduce a sparse hash of 256 elements to 16 bytes by XOR-ing blocks of 16 elements.
+///
+/// # Panics
+/// Panics if `sparse` doesn't have 256 elements.
+pub fn dense_hash(sparse: &[u8]) -> [u8; 16] {
+    assert_eq!(SIZE, sparse.len(), "sparse hash must have 256 elements");
+    let mut dense = [0u8; 16];
+    for (block, d) in sparse.chunks(16).zip(dense.iter_mut()) {
+        *d = block.iter().fold(0, |acc, &x| acc ^ x);
+    }
+    dense
+}
+
+/// Compute the knot hash (2017 day 10) of the input string.
+///
+/// # Examples
+/// ```
+/// use aoc_util::hash::knot_hash;
+///
+/// assert_eq!(0xa2, knot_hash("")[0]);
+/// ```
+pub fn knot_hash(input: &str) -> [u8; 16] {
+    let lengths: Vec<usize> = input
+        .bytes()
+        .chain(SUFFIX.iter().copied())
+        .map(usize::from)
+        .collect();
+    dense_hash(&sparse_hash(SIZE, &lengths, 64))
+}
+
+/// The knot hash of the input string as 32 lowercase hex digits.
+///
+/// # Examples
+/// ```
+/// use aoc_util::hash::knot_hash_hex;
+///
+/// assert_eq!("33efeb34ea91902bb2f59c9920caa6cd", knot_hash_hex("AoC 2017"));
+/// ```
+pub fn knot_hash_hex(input: &str) -> String {
+    knot_hash(input)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
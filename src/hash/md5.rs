@@ -0,0 +1,257 @@
+//! MD5 (RFC 1321), and brute-force searches over `prefix + counter` as in 2015 day 4 or 2016
+//! day 5.
+
+use std::collections::VecDeque;
+use std::thread;
+
+/// Per-round shift amounts.
+const SHIFTS: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9,
+    14, 20, 5, 9, 14, 20, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 6, 10, 15,
+    21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21,
+];
+
+/// Per-round constants, the integer parts of `abs(sin(i + 1)) * 2^32`.
+const K: [u32; 64] = [
+    0xd76a_a478,
+    0xe8c7_b756,
+    0x2420_70db,
+    0xc1bd_ceee,
+    0xf57c_0faf,
+    0x4787_c62a,
+    0xa830_4613,
+    0xfd46_9501,
+    0x6980_98d8,
+    0x8b44_f7af,
+    0xffff_5bb1,
+    0x895c_d7be,
+    0x6b90_1122,
+    0xfd98_7193,
+    0xa679_438e,
+    0x49b4_0821,
+    0xf61e_2562,
+    0xc040_b340,
+    0x265e_5a51,
+    0xe9b6_c7aa,
+    0xd62f_105d,
+    0x0244_1453,
+    0xd8a1_e681,
+    0xe7d3_fbc8,
+    0x21e1_cde6,
+    0xc337_07d6,
+    0xf4d5_0d87,
+    0x455a_14ed,
+    0xa9e3_e905,
+    0xfcef_a3f8,
+    0x676f_02d9,
+    0x8d2a_4c8a,
+    0xfffa_3942,
+    0x8771_f681,
+    0x6d9d_6122,
+    0xfde5_380c,
+    0xa4be_ea44,
+    0x4bde_cfa9,
+    0xf6bb_4b60,
+    0xbebf_bc70,
+    0x289b_7ec6,
+    0xeaa1_27fa,
+    0xd4ef_3085,
+    0x0488_1d05,
+    0xd9d4_d039,
+    0xe6db_99e5,
+    0x1fa2_7cf8,
+    0xc4ac_5665,
+    0xf429_2244,
+    0x432a_ff97,
+    0xab94_23a7,
+    0xfc93_a039,
+    0x655b_59c3,
+    0x8f0c_cc92,
+    0xffef_f47d,
+    0x8584_5dd1,
+    0x6fa8_7e4f,
+    0xfe2c_e6e0,
+    0xa301_4314,
+    0x4e08_11a1,
+    0xf753_7e82,
+    0xbd3a_f235,
+    0x2ad7_d2bb,
+    0xeb86_d391,
+];
+
+/// Number of counters each thread hashes per batch of a parallel search.
+const BATCH: u64 = 1 << 12;
+
+/// Mix one 64-byte block into the state.
+fn compress(state: &mut [u32; 4], block: &[u8]) {
+    let mut m = [0u32; 16];
+    for (word, bytes) in m.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+    for i in 0..64 {
+        let (f, g) = match i / 16 {
+            0 => ((b & c) | (!b & d), i),
+            1 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+            2 => (b ^ c ^ d, (3 * i + 5) % 16),
+            _ => (c ^ (b | !d), (7 * i) % 16),
+        };
+        let f = f.wrapping_add(a).wrapping_add(K[i]).wrapping_add(m[g]);
+        a = d;
+        d = c;
+        c = b;
+        b = b.wrapping_add(f.rotate_left(SHIFTS[i]));
+    }
+
+    for (s, v) in state.iter_mut().zip([a, b, c, d]) {
+        *s = s.wrapping_add(v);
+    }
+}
+
+/// The MD5 digest of `bytes`.
+///
+/// # Examples
+/// ```
+/// use aoc_util::hash::md5_hex;
+///
+/// assert_eq!("900150983cd24fb0d6963f7d28e17f72", md5_hex(b"abc"));
+/// ```
+pub fn md5(bytes: &[u8]) -> [u8; 16] {
+    let mut state: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+
+    let mut blocks = bytes.chunks_exact(64);
+    for block in &mut blocks {
+        compress(&mut state, block);
+    }
+
+    // Pad the rest with a one bit, zeros and the message length in bits.
+    let rest = blocks.remainder();
+    let mut tail = [0u8; 128];
+    tail[..rest.len()].copy_from_slice(rest);
+    tail[rest.len()] = 0x80;
+    let tail_len = if rest.len() < 56 { 64 } else { 128 };
+    let bits = (bytes.len() as u64).wrapping_mul(8);
+    tail[tail_len - 8..tail_len].copy_from_slice(&bits.to_le_bytes());
+    for block in tail[..tail_len].chunks_exact(64) {
+        compress(&mut state, block);
+    }
+
+    let mut digest = [0u8; 16];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state.iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+/// The MD5 digest of `bytes` as 32 lowercase hex digits.
+pub fn md5_hex(bytes: &[u8]) -> String {
+    md5(bytes)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Returns `true` if the hex representation of `digest` starts with (at least) `count` zeros.
+pub fn has_leading_zeros(digest: &[u8; 16], count: usize) -> bool {
+    let (bytes, nibble) = (count / 2, count % 2);
+    digest[..bytes.min(16)].iter().all(|&b| b == 0)
+        && (nibble == 0 || bytes >= 16 || digest[bytes] >> 4 == 0)
+}
+
+/// Search for counters `n` such that the digest of `prefix` followed by the decimal digits of
+/// `n` matches `predicate`.
+///
+/// The returned iterator yields `(n, digest)` in increasing order of `n`, starting at `0`.
+///
+/// # Examples
+/// ```
+/// use aoc_util::hash::{has_leading_zeros, md5_search};
+///
+/// let mut search = md5_search("abcdef", |digest| has_leading_zeros(digest, 5))
+///     .starting_at(609000);
+///
+/// assert_eq!(Some(609043), search.next().map(|(n, _)| n));
+/// ```
+pub fn md5_search<P: Fn(&[u8; 16]) -> bool + Sync>(prefix: &str, predicate: P) -> Md5Search<P> {
+    Md5Search {
+        prefix: prefix.as_bytes().to_vec(),
+        predicate,
+        next: 0,
+        threads: 1,
+        found: VecDeque::new(),
+    }
+}
+
+/// Iterator over matching counters, see `md5_search()`.
+#[derive(Debug, Clone)]
+pub struct Md5Search<P> {
+    prefix: Vec<u8>,
+    predicate: P,
+    next: u64,
+    threads: usize,
+    found: VecDeque<(u64, [u8; 16])>,
+}
+
+impl<P: Fn(&[u8; 16]) -> bool + Sync> Md5Search<P> {
+    /// Start searching at counter `n` instead of `0`.
+    pub fn starting_at(mut self, n: u64) -> Self {
+        self.next = n;
+        self
+    }
+
+    /// Hash on `threads` threads in parallel (`1` by default). Matches are still yielded in
+    /// order.
+    ///
+    /// # Panics
+    /// Panics if `threads` is zero.
+    pub fn threads(mut self, threads: usize) -> Self {
+        assert!(threads > 0, "number of threads must be positive");
+        self.threads = threads;
+        self
+    }
+
+    /// All matches among the counters `start..end`.
+    fn search_range(&self, start: u64, end: u64) -> Vec<(u64, [u8; 16])> {
+        let mut input = self.prefix.clone();
+        (start..end)
+            .filter_map(|n| {
+                input.truncate(self.prefix.len());
+                input.extend_from_slice(n.to_string().as_bytes());
+                let digest = md5(&input);
+                (self.predicate)(&digest).then_some((n, digest))
+            })
+            .collect()
+    }
+}
+
+impl<P: Fn(&[u8; 16]) -> bool + Sync> Iterator for Md5Search<P> {
+    type Item = (u64, [u8; 16]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.found.is_empty() {
+            let start = self.next;
+            self.next = start.checked_add(BATCH * self.threads as u64)?;
+
+            if self.threads == 1 {
+                self.found.extend(self.search_range(start, self.next));
+            } else {
+                let this = &*self;
+                let batches: Vec<Vec<(u64, [u8; 16])>> = thread::scope(|scope| {
+                    let handles: Vec<_> = (0..this.threads as u64)
+                        .map(|i| {
+                            let from = start + i * BATCH;
+                            scope.spawn(move || this.search_range(from, from + BATCH))
+                        })
+                        .collect();
+                    handles
+                        .into_iter()
+                        .map(|handle| handle.join().expect("search thread panicked"))
+                        .collect()
+                });
+                self.found.extend(batches.into_iter().flatten());
+            }
+        }
+        self.found.pop_front()
+    }
+}
@@ -0,0 +1,7 @@
+//! Puzzle-specific hash functions.
+
+mod knot;
+mod md5;
+
+pub use self::knot::{dense_hash, knot_hash, knot_hash_hex, sparse_hash};
+pub use self::md5::{has_leading_zeros, md5, md5_hex, md5_search, Md5Search};
@@ -0,0 +1,144 @@
+//! Hexagonal grids in axial coordinates, including the tile-flipping lobby puzzle.
+//!
+//! Tiles are pointy-topped, so each tile has neighbors to the east, southeast, southwest, west,
+//! northwest and northeast. Paths are written as concatenated directions, e.g. `esenee`.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::hex::{parse_path, Hex};
+//!
+//! let path = parse_path("nwwswee").unwrap();
+//! assert_eq!(Hex::new(0, 0), Hex::walk(&path));
+//! ```
+
+use std::collections::HashSet;
+
+use crate::automaton;
+
+/// Direction to one of the six neighbors of a tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    /// East (`e`)
+    East,
+    /// Southeast (`se`)
+    SouthEast,
+    /// Southwest (`sw`)
+    SouthWest,
+    /// West (`w`)
+    West,
+    /// Northwest (`nw`)
+    NorthWest,
+    /// Northeast (`ne`)
+    NorthEast,
+}
+
+impl HexDirection {
+    /// All six directions, clockwise starting from east.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::East,
+        HexDirection::SouthEast,
+        HexDirection::SouthWest,
+        HexDirection::West,
+        HexDirection::NorthWest,
+        HexDirection::NorthEast,
+    ];
+
+    /// Offset of the neighbor in axial coordinates.
+    pub fn delta(self) -> Hex {
+        match self {
+            HexDirection::East => Hex::new(1, 0),
+            HexDirection::SouthEast => Hex::new(0, 1),
+            HexDirection::SouthWest => Hex::new(-1, 1),
+            HexDirection::West => Hex::new(-1, 0),
+            HexDirection::NorthWest => Hex::new(0, -1),
+            HexDirection::NorthEast => Hex::new(1, -1),
+        }
+    }
+}
+
+/// Parse a path of concatenated directions (`e`, `se`, `sw`, `w`, `nw`, `ne`).
+pub fn parse_path(s: &str) -> Result<Vec<HexDirection>, String> {
+    let mut path = Vec::new();
+    let mut chars = s.trim().chars();
+
+    while let Some(c) = chars.next() {
+        let direction = match c {
+            'e' => HexDirection::East,
+            'w' => HexDirection::West,
+            'n' | 's' => match (c, chars.next()) {
+                ('s', Some('e')) => HexDirection::SouthEast,
+                ('s', Some('w')) => HexDirection::SouthWest,
+                ('n', Some('w')) => HexDirection::NorthWest,
+                ('n', Some('e')) => HexDirection::NorthEast,
+                _ => return Err(format!("invalid direction in path: {}", s)),
+            },
+            _ => return Err(format!("invalid direction in path: {}", s)),
+        };
+        path.push(direction);
+    }
+
+    Ok(path)
+}
+
+/// A tile in axial coordinates `(q, r)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Hex {
+    /// Column axis (grows to the east).
+    pub q: i64,
+    /// Row axis (grows to the southeast).
+    pub r: i64,
+}
+
+impl Hex {
+    /// Create new `Hex`.
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// Follow a path starting from the reference tile `(0, 0)`.
+    pub fn walk(path: &[HexDirection]) -> Self {
+        path.iter()
+            .fold(Hex::default(), |tile, &direction| tile.step(direction))
+    }
+
+    /// The neighboring tile in the specified direction.
+    pub fn step(self, direction: HexDirection) -> Self {
+        let d = direction.delta();
+        Hex::new(self.q + d.q, self.r + d.r)
+    }
+
+    /// All six neighbors, clockwise starting from east.
+    pub fn neighbors(self) -> [Hex; 6] {
+        HexDirection::ALL.map(|d| self.step(d))
+    }
+
+    /// Number of steps between two tiles.
+    pub fn distance(self, other: Hex) -> u64 {
+        let dq = self.q - other.q;
+        let dr = self.r - other.r;
+        (dq.unsigned_abs() + dr.unsigned_abs() + (dq + dr).unsigned_abs()) / 2
+    }
+}
+
+/// Flip the tile at the end of each path. Returns the set of tiles that have been flipped an odd
+/// number of times (the black tiles).
+pub fn flip_tiles<P: AsRef<[HexDirection]>>(paths: &[P]) -> HashSet<Hex> {
+    let mut black = HashSet::new();
+    for path in paths {
+        let tile = Hex::walk(path.as_ref());
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+    black
+}
+
+/// Apply one day of the lobby flip rules: a black tile with zero or more than two black neighbors
+/// turns white, a white tile with exactly two black neighbors turns black.
+pub fn flip_daily(black: &HashSet<Hex>) -> HashSet<Hex> {
+    automaton::step_sparse(
+        black,
+        |tile| tile.neighbors(),
+        automaton::life_rule(&[2], &[1, 2]),
+    )
+}
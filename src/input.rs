@@ -1,7 +1,10 @@
-//! Helper module that allows to read input from a file and into a user-specified destination.
+//! Helper module that allows to read input from a file, stdin, any other reader or an
+//! `InputProvider` (for targets such as `wasm32` without file system) and into a user-specified
+//! destination.
 //!
 //! For most implementations, the input is expected to consist of a list of values of the same type
-//! separated by newlines, whitespace or a user-specified delimiter.
+//! separated by newlines, whitespace or a user-specified delimiter, or of groups of such lists
+//! separated by blank lines.
 //!
 //! # Examples
 //! ```no_run
@@ -23,21 +26,214 @@
 //!     .split_lines()
 //!     .read_from_file("double_input.txt")
 //!     .unwrap();
+//!
+//! // Read whitespace-separated numbers piped into the program
+//! use aoc_util::input::FromStdin;
+//!
+//! let numbers: Vec<u64> = FileReader::new()
+//!     .split_whitespace()
+//!     .read_from_stdin()
+//!     .unwrap();
+//!
+//! // Parse from anything implementing `std::io::Read`, e.g. an in-memory string
+//! use aoc_util::input::FromReader;
+//!
+//! let numbers: Vec<u64> = FileReader::new()
+//!     .split_char(',')
+//!     .read_from_reader("4,8,15".as_bytes())
+//!     .unwrap();
+//!
+//! // Lazily parse lines, stopping at the first value above 100
+//! let first_large = FileReader::new()
+//!     .split_lines()
+//!     .iter_from_file::<u64, _>("numbers.txt")
+//!     .unwrap()
+//!     .map(Result::unwrap)
+//!     .find(|&n| n > 100);
 //! ```
 
 use std::fs::File;
-use std::io::{BufRead, BufReader, Read};
+use std::io::{self, BufRead, BufReader, Read};
+use std::marker::PhantomData;
 use std::path::Path;
 
+use crate::blocks;
+use crate::grid::Grid;
+
+/// Generic trait to read from any reader and into a destination of type `T`.
+///
+/// This is where the parsing happens; `FromFile` and `FromStdin` only provide the reader.
+pub trait FromReader<T> {
+    /// The error type
+    type Error;
+
+    /// Reads `reader` until EOF and tries to parse the content into a destination of type `T`.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<T, Self::Error>;
+}
+
 /// Generic trait to read from file and into a destination of type `T`.
+///
+/// Implemented by all readers that implement `FromReader<T>`, using the same splitting, trimming
+/// and parsing.
 pub trait FromFile<T> {
     /// The error type
     type Error;
 
     /// Takes a file path and tries to read the file content into a destination of type `T`.
+    ///
+    /// # Failures
+    /// Returns an error if the specified file cannot be opened, or if reading or parsing the
+    /// content fails.
     fn read_from_file<P: AsRef<Path>>(&self, path: P) -> Result<T, Self::Error>;
 }
 
+impl<T, X> FromFile<T> for X
+where
+    X: FromReader<T>,
+    X::Error: From<io::Error>,
+{
+    type Error = X::Error;
+
+    fn read_from_file<P: AsRef<Path>>(&self, path: P) -> Result<T, Self::Error> {
+        self.read_from_reader(File::open(path)?)
+    }
+}
+
+/// Generic trait to read from stdin and into a destination of type `T`.
+///
+/// Implemented by all readers that implement `FromReader<T>`, using the same splitting, trimming
+/// and parsing.
+pub trait FromStdin<T> {
+    /// The error type
+    type Error;
+
+    /// Reads stdin until EOF and tries to parse the content into a destination of type `T`.
+    ///
+    /// # Failures
+    /// Returns an error if reading or parsing the content fails.
+    fn read_from_stdin(&self) -> Result<T, Self::Error>;
+}
+
+impl<T, X> FromStdin<T> for X
+where
+    X: FromReader<T>,
+    X::Error: From<io::Error>,
+{
+    type Error = X::Error;
+
+    fn read_from_stdin(&self) -> Result<T, Self::Error> {
+        self.read_from_reader(io::stdin().lock())
+    }
+}
+
+/// Generic trait to read from a file if a path is given and from stdin otherwise.
+///
+/// Implemented by all readers that implement `FromReader<T>`, so binaries can accept either
+/// `./day05 input.txt` or `./day05 < input.txt` with the same splitting, trimming and parsing.
+///
+/// # Examples
+/// ```no_run
+/// use aoc_util::input::{FileReader, FromFileOrStdin};
+///
+/// let numbers: Vec<u64> = FileReader::new()
+///     .split_lines()
+///     .read_from_file_or_stdin(std::env::args().nth(1))
+///     .unwrap();
+/// ```
+pub trait FromFileOrStdin<T> {
+    /// The error type
+    type Error;
+
+    /// Reads the file at `path`, or stdin if `path` is `None` or `"-"`, and tries to parse the
+    /// content into a destination of type `T`.
+    fn read_from_file_or_stdin<P: AsRef<Path>>(&self, path: Option<P>) -> Result<T, Self::Error>;
+}
+
+impl<T, X> FromFileOrStdin<T> for X
+where
+    X: FromReader<T>,
+    X::Error: From<io::Error>,
+{
+    type Error = X::Error;
+
+    fn read_from_file_or_stdin<P: AsRef<Path>>(&self, path: Option<P>) -> Result<T, Self::Error> {
+        match path {
+            Some(path) if path.as_ref() != Path::new("-") => {
+                self.read_from_reader(File::open(path)?)
+            }
+            _ => self.read_from_reader(io::stdin().lock()),
+        }
+    }
+}
+
+/// A source of puzzle input that needs neither a file system nor stdin, e.g. on `wasm32` targets
+/// where the input is embedded in the page or fetched by the host.
+pub trait InputProvider {
+    /// Provides the complete input.
+    fn input(&mut self) -> io::Result<String>;
+}
+
+impl InputProvider for &str {
+    fn input(&mut self) -> io::Result<String> {
+        Ok(self.to_string())
+    }
+}
+
+impl InputProvider for String {
+    fn input(&mut self) -> io::Result<String> {
+        Ok(self.clone())
+    }
+}
+
+/// Input provided by a callback, e.g. one that fetches the input through JavaScript. Created
+/// using `from_callback()`.
+pub struct Callback<F> {
+    f: F,
+}
+
+/// Provide input by calling `f`. An error message returned by `f` is turned into an
+/// `io::Error`.
+pub fn from_callback<F>(f: F) -> Callback<F>
+where
+    F: FnMut() -> Result<String, String>,
+{
+    Callback { f }
+}
+
+impl<F> InputProvider for Callback<F>
+where
+    F: FnMut() -> Result<String, String>,
+{
+    fn input(&mut self) -> io::Result<String> {
+        (self.f)().map_err(io::Error::other)
+    }
+}
+
+/// Generic trait to read from an `InputProvider` and into a destination of type `T`.
+///
+/// Implemented by all readers that implement `FromReader<T>`, using the same splitting, trimming
+/// and parsing.
+pub trait FromProvider<T> {
+    /// The error type
+    type Error;
+
+    /// Requests the input from `provider` and tries to parse it into a destination of type `T`.
+    fn read_from_provider<P: InputProvider>(&self, provider: P) -> Result<T, Self::Error>;
+}
+
+impl<T, X> FromProvider<T> for X
+where
+    X: FromReader<T>,
+    X::Error: From<io::Error>,
+{
+    type Error = X::Error;
+
+    fn read_from_provider<P: InputProvider>(&self, mut provider: P) -> Result<T, Self::Error> {
+        let input = provider.input()?;
+        self.read_from_reader(input.as_bytes())
+    }
+}
+
 #[derive(Debug)]
 /// Generic error type that is returned by `FileReader` if it fails to read the input from file.
 pub enum Error<E> {
@@ -100,21 +296,55 @@ impl FileReader {
             delimiter,
         }
     }
+
+    /// Split input at a specified string delimiter.
+    pub fn split_str(self, delimiter: &str) -> SplitStr {
+        SplitStr {
+            trim: self.trim,
+            delimiter: delimiter.to_string(),
+        }
+    }
+
+    /// Split input at every match of a regular expression.
+    ///
+    /// # Failures
+    /// Returns an error if `pattern` is not a valid regular expression.
+    #[cfg(feature = "regex")]
+    pub fn split_regex(self, pattern: &str) -> Result<SplitRegex, regex::Error> {
+        Ok(SplitRegex {
+            trim: self.trim,
+            delimiter: regex::Regex::new(pattern)?,
+        })
+    }
+
+    /// Split input into groups of lines separated by blank lines.
+    pub fn split_groups(self) -> SplitGroups {
+        SplitGroups { trim: self.trim }
+    }
+
+    /// Read the input into a `Grid`, mapping each character with `f`.
+    pub fn as_grid<T, F: Fn(char) -> T>(self, f: F) -> AsGrid<F> {
+        AsGrid { trim: self.trim, f }
+    }
+
+    /// Guess the format of the input instead of parsing it.
+    pub fn detect_format(self) -> DetectFormat {
+        DetectFormat { _private: () }
+    }
 }
 
 /// Read input into a `String`.
-impl FromFile<String> for FileReader {
+impl FromReader<String> for FileReader {
     type Error = std::io::Error;
 
-    /// Takes a file path and tries to read the file content into a `String`.
+    /// Reads the reader's content into a `String`.
     ///
     /// # Failures
-    /// Returns an error if the specified file cannot be opened or contains invalid UTF-8.
-    fn read_from_file<P: AsRef<Path>>(&self, path: P) -> Result<String, Self::Error> {
-        let mut file = File::open(path)?;
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<String, Self::Error> {
         let mut buffer = String::new();
 
-        file.read_to_string(&mut buffer)?;
+        reader.read_to_string(&mut buffer)?;
 
         if self.trim {
             buffer = buffer.trim().to_string();
@@ -135,26 +365,79 @@ impl SplitLines {
         self.trim = true;
         self
     }
+
+    /// Additionally split each line into fields at a specified delimiter, e.g. to read lines
+    /// into tuples. With `trim()`, each field is trimmed as well.
+    pub fn then_split_char(self, delimiter: char) -> SplitFields {
+        SplitFields {
+            trim: self.trim,
+            delimiter: Some(delimiter),
+        }
+    }
+
+    /// Additionally split each line into fields at whitespace, e.g. to read lines into
+    /// `Vec<Vec<T>>`.
+    pub fn then_split_whitespace(self) -> SplitFields {
+        SplitFields {
+            trim: self.trim,
+            delimiter: None,
+        }
+    }
+
+    /// Lazily parse the lines of `reader` into values of type `T`.
+    ///
+    /// Lines are read and parsed one at a time as the iterator advances.
+    pub fn iter_from_reader<T, R>(&self, reader: R) -> ParsedLines<T, BufReader<R>>
+    where
+        T: std::str::FromStr,
+        R: Read,
+    {
+        ParsedLines {
+            lines: BufReader::new(reader).lines(),
+            trim: self.trim,
+            _value: PhantomData,
+        }
+    }
+
+    /// Lazily parse the lines of a file into values of type `T`.
+    ///
+    /// # Failures
+    /// Returns an error if the specified file cannot be opened.
+    pub fn iter_from_file<T, P>(
+        &self,
+        path: P,
+    ) -> Result<ParsedLines<T, BufReader<File>>, io::Error>
+    where
+        T: std::str::FromStr,
+        P: AsRef<Path>,
+    {
+        Ok(self.iter_from_reader(File::open(path)?))
+    }
+
+    /// Lazily parse the lines of stdin into values of type `T`.
+    pub fn iter_from_stdin<T>(&self) -> ParsedLines<T, BufReader<io::StdinLock<'static>>>
+    where
+        T: std::str::FromStr,
+    {
+        self.iter_from_reader(io::stdin().lock())
+    }
 }
 
 /// Read input into a `Vec<T>`. Input is assumed to be a list of values that can be parsed into `T`
 /// that are separated by newlines.
-impl<T> FromFile<Vec<T>> for SplitLines
+impl<T> FromReader<Vec<T>> for SplitLines
 where
     T: std::str::FromStr,
 {
     type Error = Error<<T as std::str::FromStr>::Err>;
 
-    /// Takes a file path and tries to read the file content into a destination of type `Vec<T>`.
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<T>`.
     ///
     /// # Failures
-    /// Returns an error if the specified file cannot be opened or contains invalid UTF-8.
-    /// Also returns an error if the file contents cannot be parsed into values of type `T`.
-    fn read_from_file<P: AsRef<Path>>(&self, path: P) -> Result<Vec<T>, Self::Error> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-
-        reader
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the content cannot be parsed into values of type `T`.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<Vec<T>, Self::Error> {
+        BufReader::new(reader)
             .lines()
             .map(|line| {
                 if self.trim {
@@ -167,6 +450,214 @@ where
     }
 }
 
+/// Iterator over the parsed lines of a reader. Created using `SplitLines::iter_from_reader()`,
+/// `SplitLines::iter_from_file()` or `SplitLines::iter_from_stdin()`.
+///
+/// Yields one `Result` per line; iteration can simply continue after an error.
+pub struct ParsedLines<T, B> {
+    lines: io::Lines<B>,
+    trim: bool,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T, B> Iterator for ParsedLines<T, B>
+where
+    T: std::str::FromStr,
+    B: BufRead,
+{
+    type Item = Result<T, Error<<T as std::str::FromStr>::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = match self.lines.next()? {
+            Ok(line) => line,
+            Err(e) => return Some(Err(Error::IoError(e))),
+        };
+        let line = if self.trim { line.trim() } else { &line };
+        Some(line.parse().map_err(Error::ParseError))
+    }
+}
+
+/// Values parsed by a lenient reader, together with the lines that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lenient<T, E> {
+    /// All values that could be parsed, in input order.
+    pub values: Vec<T>,
+    /// Line number (1-based) and parse error of every line that could not be parsed.
+    pub errors: Vec<(usize, E)>,
+}
+
+/// Read input from file and split at newlines, dropping lines that cannot be parsed. Created
+/// using `SplitLines::skip_unparseable()`.
+pub struct SkipUnparseable {
+    lines: SplitLines,
+}
+
+/// Read input from file and split at newlines, collecting the errors of lines that cannot be
+/// parsed instead of failing. Created using `SplitLines::collect_errors()`.
+pub struct CollectErrors {
+    lines: SplitLines,
+}
+
+impl SplitLines {
+    /// Drop lines that cannot be parsed instead of failing.
+    pub fn skip_unparseable(self) -> SkipUnparseable {
+        SkipUnparseable { lines: self }
+    }
+
+    /// Collect the parse errors together with their line numbers instead of failing.
+    pub fn collect_errors(self) -> CollectErrors {
+        CollectErrors { lines: self }
+    }
+
+    fn parse_lenient<T, R>(&self, reader: R) -> Result<Lenient<T, T::Err>, io::Error>
+    where
+        T: std::str::FromStr,
+        R: Read,
+    {
+        let mut parsed = Lenient {
+            values: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line = if self.trim { line.trim() } else { &line };
+            match line.parse() {
+                Ok(value) => parsed.values.push(value),
+                Err(e) => parsed.errors.push((i + 1, e)),
+            }
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Read input into a `Vec<T>`, skipping lines that cannot be parsed into `T`.
+impl<T> FromReader<Vec<T>> for SkipUnparseable
+where
+    T: std::str::FromStr,
+{
+    type Error = std::io::Error;
+
+    /// Reads the reader's content and parses every line that is a valid `T`.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<Vec<T>, Self::Error> {
+        Ok(self.lines.parse_lenient(reader)?.values)
+    }
+}
+
+/// Read input into the parsed values and the errors of all lines that cannot be parsed into `T`.
+impl<T> FromReader<Lenient<T, T::Err>> for CollectErrors
+where
+    T: std::str::FromStr,
+{
+    type Error = std::io::Error;
+
+    /// Reads the reader's content and parses every line, collecting parse errors.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<Lenient<T, T::Err>, Self::Error> {
+        self.lines.parse_lenient(reader)
+    }
+}
+
+/// Types that can be built from the fields of a single line, e.g. tuples or vectors whose
+/// elements implement `FromStr`.
+pub trait FromFields: Sized {
+    /// Builds a value from the (already split and trimmed) fields of a line.
+    fn from_fields(fields: &[&str]) -> Result<Self, String>;
+}
+
+/// Any number of fields of the same type. An empty line yields an empty `Vec`.
+impl<T> FromFields for Vec<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    fn from_fields(fields: &[&str]) -> Result<Self, String> {
+        if let [""] = fields {
+            return Ok(Vec::new());
+        }
+        fields
+            .iter()
+            .map(|field| {
+                field
+                    .parse()
+                    .map_err(|e| format!("invalid field {}: {}", field, e))
+            })
+            .collect()
+    }
+}
+
+macro_rules! impl_from_fields {
+    ($count:expr; $($field:ident: $idx:tt),+) => {
+        impl<$($field),+> FromFields for ($($field,)+)
+        where
+            $($field: std::str::FromStr, $field::Err: std::fmt::Display,)+
+        {
+            fn from_fields(fields: &[&str]) -> Result<Self, String> {
+                if fields.len() != $count {
+                    return Err(format!(
+                        "invalid field count: expected {}, got {}",
+                        $count,
+                        fields.len()
+                    ));
+                }
+                Ok(($(fields[$idx]
+                    .parse::<$field>()
+                    .map_err(|e| format!("invalid field {}: {}", fields[$idx], e))?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(2; A: 0, B: 1);
+impl_from_fields!(3; A: 0, B: 1, C: 2);
+impl_from_fields!(4; A: 0, B: 1, C: 2, D: 3);
+impl_from_fields!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_from_fields!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+
+/// Read input from file, split at newlines and split each line into fields at a specified
+/// delimiter or at whitespace. Created using `SplitLines::then_split_char()` or
+/// `SplitLines::then_split_whitespace()`.
+pub struct SplitFields {
+    trim: bool,
+    delimiter: Option<char>,
+}
+
+/// Read input into a `Vec<T>`, where `T` is built from the fields of one line (usually a tuple).
+impl<T> FromReader<Vec<T>> for SplitFields
+where
+    T: FromFields,
+{
+    type Error = Error<String>;
+
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<T>`.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if a line has the wrong number of fields or a field cannot be parsed.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<Vec<T>, Self::Error> {
+        BufReader::new(reader)
+            .lines()
+            .map(|line| {
+                let line = line?;
+                let fields: Vec<&str> = match self.delimiter {
+                    Some(delimiter) if self.trim => {
+                        line.trim().split(delimiter).map(str::trim).collect()
+                    }
+                    Some(delimiter) => line.split(delimiter).collect(),
+                    None => line.split_whitespace().collect(),
+                };
+                T::from_fields(&fields).map_err(Error::ParseError)
+            })
+            .collect()
+    }
+}
+
 /// Read input from file and split at whitespace. Created using `FileReader::split_whitespace()`.
 pub struct SplitWhitespace {
     _private: (),
@@ -174,20 +665,18 @@ pub struct SplitWhitespace {
 
 /// Read input into a `Vec<T>`. Input is assumed to be a list of values that can be parsed into `T`
 /// that are separated by whitespace.
-impl<T> FromFile<Vec<T>> for SplitWhitespace
+impl<T> FromReader<Vec<T>> for SplitWhitespace
 where
     T: std::str::FromStr,
 {
     type Error = Error<<T as std::str::FromStr>::Err>;
 
-    /// Takes a file path and tries to read the file content into a destination of type `Vec<T>`.
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<T>`.
     ///
     /// # Failures
-    /// Returns an error if the specified file cannot be opened or contains invalid UTF-8.
-    /// Also returns an error if the file contents cannot be parsed into values of type `T`.
-    fn read_from_file<P: AsRef<Path>>(&self, path: P) -> Result<Vec<T>, Self::Error> {
-        let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the content cannot be parsed into values of type `T`.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<Vec<T>, Self::Error> {
         let mut buffer = String::new();
 
         reader.read_to_string(&mut buffer)?;
@@ -215,20 +704,18 @@ impl SplitChar {
 
 /// Read input into a `Vec<T>`. Input is assumed to be a list of values that can be parsed into `T`
 /// that are separated by a specified delimiter.
-impl<T> FromFile<Vec<T>> for SplitChar
+impl<T> FromReader<Vec<T>> for SplitChar
 where
     T: std::str::FromStr,
 {
     type Error = Error<<T as std::str::FromStr>::Err>;
 
-    /// Takes a file path and tries to read the file content into a destination of type `Vec<T>`.
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<T>`.
     ///
     /// # Failures
-    /// Returns an error if the specified file cannot be opened or contains invalid UTF-8.
-    /// Also returns an error if the file contents cannot be parsed into values of type `T`.
-    fn read_from_file<P: AsRef<Path>>(&self, path: P) -> Result<Vec<T>, Self::Error> {
-        let file = File::open(path)?;
-        let mut reader = BufReader::new(file);
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the content cannot be parsed into values of type `T`.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<Vec<T>, Self::Error> {
         let mut buffer = String::new();
 
         reader.read_to_string(&mut buffer)?;
@@ -245,3 +732,337 @@ where
             .collect()
     }
 }
+
+/// Read input from file and split at a specified string delimiter. Created using
+/// `FileReader::split_str()`.
+pub struct SplitStr {
+    trim: bool,
+    delimiter: String,
+}
+
+impl SplitStr {
+    /// Trim whitespace at the beginning and end.
+    pub fn trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+}
+
+/// Read input into a `Vec<T>`. Input is assumed to be a list of values that can be parsed into `T`
+/// that are separated by a specified string delimiter.
+impl<T> FromReader<Vec<T>> for SplitStr
+where
+    T: std::str::FromStr,
+{
+    type Error = Error<<T as std::str::FromStr>::Err>;
+
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<T>`.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the content cannot be parsed into values of type `T`.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<Vec<T>, Self::Error> {
+        let mut buffer = String::new();
+
+        reader.read_to_string(&mut buffer)?;
+
+        buffer
+            .split(self.delimiter.as_str())
+            .map(|chunk| {
+                if self.trim {
+                    chunk.trim().parse().map_err(Error::ParseError)
+                } else {
+                    chunk.parse().map_err(Error::ParseError)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Read input from file and split at every match of a regular expression. Created using
+/// `FileReader::split_regex()`.
+#[cfg(feature = "regex")]
+pub struct SplitRegex {
+    trim: bool,
+    delimiter: regex::Regex,
+}
+
+#[cfg(feature = "regex")]
+impl SplitRegex {
+    /// Trim whitespace at the beginning and end.
+    pub fn trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+}
+
+/// Read input into a `Vec<T>`. Input is assumed to be a list of values that can be parsed into `T`
+/// that are separated by matches of a regular expression.
+#[cfg(feature = "regex")]
+impl<T> FromReader<Vec<T>> for SplitRegex
+where
+    T: std::str::FromStr,
+{
+    type Error = Error<<T as std::str::FromStr>::Err>;
+
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<T>`.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the content cannot be parsed into values of type `T`.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<Vec<T>, Self::Error> {
+        let mut buffer = String::new();
+
+        reader.read_to_string(&mut buffer)?;
+
+        self.delimiter
+            .split(&buffer)
+            .map(|chunk| {
+                if self.trim {
+                    chunk.trim().parse().map_err(Error::ParseError)
+                } else {
+                    chunk.parse().map_err(Error::ParseError)
+                }
+            })
+            .collect()
+    }
+}
+
+/// Read input from file and split into groups separated by blank lines (lines that are empty or
+/// contain only whitespace). Created using `FileReader::split_groups()`.
+///
+/// Groups can be read as a whole (`Vec<String>`, the lines of each group joined by newlines) or
+/// line by line (`Vec<Vec<T>>`).
+pub struct SplitGroups {
+    trim: bool,
+}
+
+impl SplitGroups {
+    /// Trim whitespace at the beginning and end of each line.
+    pub fn trim(mut self) -> Self {
+        self.trim = true;
+        self
+    }
+
+    fn groups<R: Read>(&self, mut reader: R) -> Result<Vec<Vec<String>>, io::Error> {
+        let mut buffer = String::new();
+
+        reader.read_to_string(&mut buffer)?;
+
+        Ok(blocks::split_blocks(&buffer)
+            .into_iter()
+            .map(|block| {
+                block
+                    .into_iter()
+                    .map(|line| if self.trim { line.trim() } else { line }.to_string())
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+/// Read input into a `Vec<String>` with one entry per group.
+impl FromReader<Vec<String>> for SplitGroups {
+    type Error = std::io::Error;
+
+    /// Reads the reader's content and splits it into groups.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<Vec<String>, Self::Error> {
+        Ok(self
+            .groups(reader)?
+            .into_iter()
+            .map(|group| group.join("\n"))
+            .collect())
+    }
+}
+
+/// Read input into a `Vec<Vec<T>>`. Each group is assumed to be a list of values that can be
+/// parsed into `T` that are separated by newlines.
+impl<T> FromReader<Vec<Vec<T>>> for SplitGroups
+where
+    T: std::str::FromStr,
+{
+    type Error = Error<<T as std::str::FromStr>::Err>;
+
+    /// Reads the reader's content and tries to parse it into a destination of type `Vec<Vec<T>>`.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the content cannot be parsed into values of type `T`.
+    fn read_from_reader<R: Read>(&self, reader: R) -> Result<Vec<Vec<T>>, Self::Error> {
+        self.groups(reader)?
+            .iter()
+            .map(|group| {
+                group
+                    .iter()
+                    .map(|line| line.parse().map_err(Error::ParseError))
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Inspect the input and guess its format instead of parsing it. Created using
+/// `FileReader::detect_format()`.
+pub struct DetectFormat {
+    _private: (),
+}
+
+/// The guessed shape of an input, as reported by `DetectFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// The input is empty or contains only whitespace.
+    Empty,
+    /// A single value without any delimiter.
+    Single,
+    /// A single line of values separated by `delimiter`.
+    Delimited {
+        /// The guessed delimiter.
+        delimiter: char,
+        /// Number of values.
+        count: usize,
+    },
+    /// A single line of values separated by whitespace.
+    Whitespace {
+        /// Number of values.
+        count: usize,
+    },
+    /// One value per line.
+    Lines {
+        /// Number of lines.
+        count: usize,
+    },
+    /// A rectangular block of characters.
+    Grid {
+        /// Number of rows.
+        rows: usize,
+        /// Number of columns.
+        cols: usize,
+    },
+    /// Groups of lines separated by blank lines.
+    Groups {
+        /// Number of groups.
+        count: usize,
+    },
+}
+
+impl Format {
+    /// Guess the format of `input`.
+    ///
+    /// A trailing newline is ignored. Inputs with several lines of equal length that contain
+    /// no whitespace are considered grids, unless every line is an integer; single lines are
+    /// split at `,` or `;` if present and at whitespace otherwise.
+    pub fn detect(input: &str) -> Self {
+        let input = input.trim_end_matches(&['\n', '\r'][..]);
+        if input.trim().is_empty() {
+            return Format::Empty;
+        }
+
+        let lines: Vec<&str> = input.lines().collect();
+        if lines.len() == 1 {
+            let line = lines[0].trim();
+            for &delimiter in &[',', ';'] {
+                if line.contains(delimiter) {
+                    return Format::Delimited {
+                        delimiter,
+                        count: line.split(delimiter).count(),
+                    };
+                }
+            }
+            let count = line.split_whitespace().count();
+            return if count > 1 {
+                Format::Whitespace { count }
+            } else {
+                Format::Single
+            };
+        }
+
+        if lines.iter().any(|line| line.trim().is_empty()) {
+            let mut count = 0;
+            let mut in_group = false;
+            for line in &lines {
+                let blank = line.trim().is_empty();
+                if !blank && !in_group {
+                    count += 1;
+                }
+                in_group = !blank;
+            }
+            return Format::Groups { count };
+        }
+
+        let cols = lines[0].chars().count();
+        let rectangular = lines
+            .iter()
+            .all(|line| line.chars().count() == cols && !line.contains(char::is_whitespace));
+        let numbers = lines.iter().all(|line| line.parse::<i128>().is_ok());
+        if rectangular && cols > 1 && !numbers {
+            Format::Grid {
+                rows: lines.len(),
+                cols,
+            }
+        } else {
+            Format::Lines { count: lines.len() }
+        }
+    }
+
+    /// The `FileReader` configuration that reads this format.
+    pub fn suggestion(&self) -> String {
+        match self {
+            Format::Empty | Format::Single => String::from("FileReader::new().trim()"),
+            Format::Grid { .. } => String::from("FileReader::new().as_grid(|c| c)"),
+            Format::Delimited { delimiter, .. } => {
+                format!("FileReader::new().trim().split_char({:?})", delimiter)
+            }
+            Format::Whitespace { .. } => String::from("FileReader::new().split_whitespace()"),
+            Format::Lines { .. } => String::from("FileReader::new().split_lines()"),
+            Format::Groups { .. } => String::from("FileReader::new().split_groups()"),
+        }
+    }
+}
+
+/// Read input and guess its format.
+impl FromReader<Format> for DetectFormat {
+    type Error = std::io::Error;
+
+    /// Reads the reader's content and guesses its format.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<Format, Self::Error> {
+        let mut buffer = String::new();
+        reader.read_to_string(&mut buffer)?;
+        Ok(Format::detect(&buffer))
+    }
+}
+
+/// Read input from file into a `Grid`, mapping each character with a closure. Created using
+/// `FileReader::as_grid()`.
+pub struct AsGrid<F> {
+    trim: bool,
+    f: F,
+}
+
+/// Read input into a `Grid<T>`. Input is assumed to be lines of characters of equal length.
+impl<T, F> FromReader<Grid<T>> for AsGrid<F>
+where
+    F: Fn(char) -> T,
+{
+    type Error = Error<String>;
+
+    /// Reads the reader's content and maps every character into a cell of the grid. Blank lines
+    /// are ignored, and with `trim()` whitespace around each line is removed first.
+    ///
+    /// # Failures
+    /// Returns an error if the reader fails or the content is invalid UTF-8.
+    /// Also returns an error if the lines don't all have the same length.
+    fn read_from_reader<R: Read>(&self, mut reader: R) -> Result<Grid<T>, Self::Error> {
+        let mut buffer = String::new();
+        reader.read_to_string(&mut buffer)?;
+        if self.trim {
+            buffer = buffer.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+        }
+        Grid::parse(&buffer, &self.f).map_err(Error::FormatError)
+    }
+}
@@ -0,0 +1,102 @@
+//! Conditional register instructions of the form `b inc 5 if a > 1` (2017 day 8).
+//!
+//! # Examples
+//! ```
+//! use aoc_util::instructions::conditional::{run, Instruction};
+//!
+//! let program: Vec<Instruction> = ["b inc 5 if a > 1", "a inc 1 if b < 5", "c dec -10 if a >= 1"]
+//!     .iter()
+//!     .map(|line| line.parse().unwrap())
+//!     .collect();
+//!
+//! let outcome = run(&program);
+//! assert_eq!(Some(10), outcome.final_max);
+//! ```
+
+use std::str::FromStr;
+
+use super::{Comparison, Registers};
+
+/// A single conditional instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// Register to modify.
+    pub register: String,
+    /// Amount to add to the register (negative for `dec`).
+    pub delta: i64,
+    /// Register the condition is checked on.
+    pub condition_register: String,
+    /// Comparison operator of the condition.
+    pub comparison: Comparison,
+    /// Right-hand side of the condition.
+    pub condition_value: i64,
+}
+
+impl Instruction {
+    /// Execute the instruction. Returns the new value of the register if it was modified.
+    pub fn execute(&self, registers: &mut Registers) -> Option<i64> {
+        let lhs = registers.get(&self.condition_register);
+        if self.comparison.eval(lhs, self.condition_value) {
+            Some(registers.add(&self.register, self.delta))
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid instruction: {}", s);
+        let tokens: Vec<&str> = s.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            [register, op, amount, "if", cond_register, comparison, cond_value] => {
+                let amount: i64 = amount.parse().map_err(|_| invalid())?;
+                let delta = match *op {
+                    "inc" => amount,
+                    "dec" => -amount,
+                    _ => return Err(invalid()),
+                };
+                Ok(Instruction {
+                    register: register.to_string(),
+                    delta,
+                    condition_register: cond_register.to_string(),
+                    comparison: comparison.parse()?,
+                    condition_value: cond_value.parse().map_err(|_| invalid())?,
+                })
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Result of running a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outcome {
+    /// Final state of all registers.
+    pub registers: Registers,
+    /// Largest register value after the program finished (`None` if nothing was written).
+    pub final_max: Option<i64>,
+    /// Largest value held by any register at any time (`None` if nothing was written).
+    pub all_time_max: Option<i64>,
+}
+
+/// Run the program from start to end, with all registers initially `0`.
+pub fn run(program: &[Instruction]) -> Outcome {
+    let mut registers = Registers::new();
+    let mut all_time_max = None;
+
+    for instruction in program {
+        if let Some(value) = instruction.execute(&mut registers) {
+            all_time_max = Some(all_time_max.map_or(value, |max: i64| max.max(value)));
+        }
+    }
+
+    Outcome {
+        final_max: registers.max(),
+        registers,
+        all_time_max,
+    }
+}
@@ -0,0 +1,95 @@
+//! Building blocks for interpreting register-machine style puzzle programs.
+
+pub mod conditional;
+pub mod opcodes;
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Named registers holding signed integers. Registers that were never written hold `0`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Registers {
+    values: HashMap<String, i64>,
+}
+
+impl Registers {
+    /// Create new `Registers` with all registers set to `0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Value of a register.
+    pub fn get(&self, name: &str) -> i64 {
+        self.values.get(name).copied().unwrap_or(0)
+    }
+
+    /// Set a register to a value.
+    pub fn set(&mut self, name: &str, value: i64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    /// Add to a register. Returns the new value.
+    pub fn add(&mut self, name: &str, delta: i64) -> i64 {
+        let value = self.values.entry(name.to_string()).or_insert(0);
+        *value += delta;
+        *value
+    }
+
+    /// Largest value of any register that has been written, or `None` if no register has been
+    /// written.
+    pub fn max(&self) -> Option<i64> {
+        self.values.values().copied().max()
+    }
+
+    /// Iterate over all registers that have been written.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, i64)> {
+        self.values.iter().map(|(k, &v)| (k.as_str(), v))
+    }
+}
+
+/// Comparison operator, as used in conditions like `a > 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+}
+
+impl Comparison {
+    /// Evaluate `lhs <op> rhs`.
+    pub fn eval(self, lhs: i64, rhs: i64) -> bool {
+        match self {
+            Comparison::Less => lhs < rhs,
+            Comparison::LessOrEqual => lhs <= rhs,
+            Comparison::Greater => lhs > rhs,
+            Comparison::GreaterOrEqual => lhs >= rhs,
+            Comparison::Equal => lhs == rhs,
+            Comparison::NotEqual => lhs != rhs,
+        }
+    }
+}
+
+impl FromStr for Comparison {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "<" => Ok(Comparison::Less),
+            "<=" => Ok(Comparison::LessOrEqual),
+            ">" => Ok(Comparison::Greater),
+            ">=" => Ok(Comparison::GreaterOrEqual),
+            "==" => Ok(Comparison::Equal),
+            "!=" => Ok(Comparison::NotEqual),
+            other => Err(format!("invalid comparison operator: {}", other)),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Opcode inference for a small register machine with numbered, unknown opcodes ("Chronal
+//! Classification").
+//!
+//! Samples record the registers before and after executing a single instruction. Each sample
+//! restricts the operations its opcode number can stand for; `deduce()` resolves the full
+//! mapping by elimination.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::instructions::opcodes::{Opcode, Sample};
+//!
+//! let sample: Sample = "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]".parse().unwrap();
+//!
+//! assert_eq!(
+//!     vec![Opcode::Addi, Opcode::Mulr, Opcode::Seti],
+//!     sample.candidates(&Opcode::ALL)
+//! );
+//! ```
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+use crate::matching;
+
+/// An instruction `opcode a b c` with a numeric opcode.
+pub type Instruction = [u64; 4];
+
+/// Operations of the register machine. Suffix `r` means "register", `i` means "immediate";
+/// the result is always written to register `c`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Opcode {
+    /// `r[a] + r[b]`
+    Addr,
+    /// `r[a] + b`
+    Addi,
+    /// `r[a] * r[b]`
+    Mulr,
+    /// `r[a] * b`
+    Muli,
+    /// `r[a] & r[b]`
+    Banr,
+    /// `r[a] & b`
+    Bani,
+    /// `r[a] | r[b]`
+    Borr,
+    /// `r[a] | b`
+    Bori,
+    /// `r[a]`
+    Setr,
+    /// `a`
+    Seti,
+    /// `a > r[b]`
+    Gtir,
+    /// `r[a] > b`
+    Gtri,
+    /// `r[a] > r[b]`
+    Gtrr,
+    /// `a == r[b]`
+    Eqir,
+    /// `r[a] == b`
+    Eqri,
+    /// `r[a] == r[b]`
+    Eqrr,
+}
+
+impl Opcode {
+    /// All operations.
+    pub const ALL: [Opcode; 16] = [
+        Opcode::Addr,
+        Opcode::Addi,
+        Opcode::Mulr,
+        Opcode::Muli,
+        Opcode::Banr,
+        Opcode::Bani,
+        Opcode::Borr,
+        Opcode::Bori,
+        Opcode::Setr,
+        Opcode::Seti,
+        Opcode::Gtir,
+        Opcode::Gtri,
+        Opcode::Gtrr,
+        Opcode::Eqir,
+        Opcode::Eqri,
+        Opcode::Eqrr,
+    ];
+
+    /// Execute the operation with operands `a`, `b`, `c` on `registers`.
+    ///
+    /// Returns `None` (leaving the registers unchanged) if an operand refers to a register that
+    /// doesn't exist.
+    pub fn execute(self, registers: &mut [u64], a: u64, b: u64, c: u64) -> Option<()> {
+        let reg = |r: u64| registers.get(r as usize).copied();
+        let value = match self {
+            Opcode::Addr => reg(a)?.wrapping_add(reg(b)?),
+            Opcode::Addi => reg(a)?.wrapping_add(b),
+            Opcode::Mulr => reg(a)?.wrapping_mul(reg(b)?),
+            Opcode::Muli => reg(a)?.wrapping_mul(b),
+            Opcode::Banr => reg(a)? & reg(b)?,
+            Opcode::Bani => reg(a)? & b,
+            Opcode::Borr => reg(a)? | reg(b)?,
+            Opcode::Bori => reg(a)? | b,
+            Opcode::Setr => reg(a)?,
+            Opcode::Seti => a,
+            Opcode::Gtir => u64::from(a > reg(b)?),
+            Opcode::Gtri => u64::from(reg(a)? > b),
+            Opcode::Gtrr => u64::from(reg(a)? > reg(b)?),
+            Opcode::Eqir => u64::from(a == reg(b)?),
+            Opcode::Eqri => u64::from(reg(a)? == b),
+            Opcode::Eqrr => u64::from(reg(a)? == reg(b)?),
+        };
+        *registers.get_mut(c as usize)? = value;
+        Some(())
+    }
+}
+
+/// Registers before and after executing a single instruction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    /// Registers before the instruction.
+    pub before: Vec<u64>,
+    /// The instruction.
+    pub instruction: Instruction,
+    /// Registers after the instruction.
+    pub after: Vec<u64>,
+}
+
+impl Sample {
+    /// All operations from `candidates` that are consistent with the sample.
+    pub fn candidates(&self, candidates: &[Opcode]) -> Vec<Opcode> {
+        let [_, a, b, c] = self.instruction;
+        candidates
+            .iter()
+            .copied()
+            .filter(|op| {
+                let mut registers = self.before.clone();
+                op.execute(&mut registers, a, b, c).is_some() && registers == self.after
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Sample {
+    type Err = String;
+
+    /// Parses a sample of the form
+    /// `Before: [3, 2, 1, 1]`, `9 2 1 2`, `After:  [3, 2, 2, 1]` (one per line).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = s.lines().map(str::trim).filter(|l| !l.is_empty()).collect();
+        match lines.as_slice() {
+            [before, instruction, after] => Ok(Sample {
+                before: parse_registers(before, "Before:")?,
+                instruction: parse_instruction(instruction)?,
+                after: parse_registers(after, "After:")?,
+            }),
+            _ => Err(format!("invalid sample: {}", s)),
+        }
+    }
+}
+
+fn parse_registers(line: &str, prefix: &str) -> Result<Vec<u64>, String> {
+    line.strip_prefix(prefix)
+        .map(str::trim)
+        .and_then(|l| l.strip_prefix('['))
+        .and_then(|l| l.strip_suffix(']'))
+        .ok_or_else(|| format!("invalid registers: {}", line))?
+        .split(',')
+        .map(|n| {
+            n.trim()
+                .parse()
+                .map_err(|_| format!("invalid register value: {}", line))
+        })
+        .collect()
+}
+
+/// Parse an instruction of the form `9 2 1 2`.
+pub fn parse_instruction(line: &str) -> Result<Instruction, String> {
+    let values: Vec<u64> = line
+        .split_whitespace()
+        .map(|n| n.parse())
+        .collect::<Result<_, _>>()
+        .map_err(|_| format!("invalid instruction: {}", line))?;
+    match values.as_slice() {
+        &[op, a, b, c] => Ok([op, a, b, c]),
+        _ => Err(format!("invalid instruction: {}", line)),
+    }
+}
+
+/// Number of samples consistent with at least `n` of the `candidates`.
+pub fn count_ambiguous(samples: &[Sample], candidates: &[Opcode], n: usize) -> usize {
+    samples
+        .iter()
+        .filter(|s| s.candidates(candidates).len() >= n)
+        .count()
+}
+
+/// Deduce the operation of every opcode number by elimination. The result is indexed by opcode
+/// number; opcode numbers are expected to be `0..candidates.len()`.
+///
+/// Returns `None` if the samples don't determine a unique mapping.
+pub fn deduce(samples: &[Sample], candidates: &[Opcode]) -> Option<Vec<Opcode>> {
+    let all: BTreeSet<Opcode> = candidates.iter().copied().collect();
+    let mut possible = vec![all; candidates.len()];
+
+    for sample in samples {
+        let matching: BTreeSet<Opcode> = sample.candidates(candidates).into_iter().collect();
+        let number = possible.get_mut(sample.instruction[0] as usize)?;
+        *number = number.intersection(&matching).copied().collect();
+    }
+
+    matching::eliminate(possible)
+}
+
+/// Execute a program with numeric opcodes, using `mapping` to look up the operations.
+///
+/// Returns `None` if an instruction has an unknown opcode or refers to a missing register.
+pub fn run(program: &[Instruction], mapping: &[Opcode], registers: &mut [u64]) -> Option<()> {
+    for &[op, a, b, c] in program {
+        mapping.get(op as usize)?.execute(registers, a, b, c)?;
+    }
+    Some(())
+}
+
+/// Parse the puzzle input: samples separated by blank lines, followed by (after several blank
+/// lines) the test program.
+pub fn parse(input: &str) -> Result<(Vec<Sample>, Vec<Instruction>), String> {
+    let mut samples = Vec::new();
+    let mut program = Vec::new();
+    let mut block = String::new();
+
+    for line in input.lines().map(str::trim) {
+        if line.starts_with("Before:") || !block.is_empty() {
+            block.push_str(line);
+            block.push('\n');
+            if line.starts_with("After:") {
+                samples.push(block.parse()?);
+                block.clear();
+            }
+        } else if !line.is_empty() {
+            program.push(parse_instruction(line)?);
+        }
+    }
+    if !block.is_empty() {
+        return Err(format!("incomplete sample: {}", block));
+    }
+
+    Ok((samples, program))
+}
@@ -0,0 +1,317 @@
+//! The Intcode machine of 2019: all opcodes and parameter modes, a relative base and memory that
+//! grows as needed.
+//!
+//! Input and output are pluggable through the `Input` and `Output` traits, which are
+//! implemented for queues, vectors, channels and (wrapped) closures. When a machine needs input
+//! that isn't available yet, it pauses and can be resumed later. Machines can also run on their
+//! own threads connected by channels, or as a `Network` exchanging packets.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::intcode::Machine;
+//!
+//! // Output 1 if the input equals 8, otherwise 0.
+//! let mut machine: Machine = "3,9,8,9,10,9,4,9,99,-1,8".parse().unwrap();
+//!
+//! assert_eq!(Ok(vec![1]), machine.run_with(&[8]));
+//! ```
+
+mod network;
+
+pub use self::network::{spawn, Network, Packet, Round};
+
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::str::FromStr;
+use std::sync::mpsc::{Receiver, Sender};
+
+/// Source of input values.
+pub trait Input {
+    /// The next input value, or `None` if there is none (yet).
+    fn read(&mut self) -> Option<i64>;
+}
+
+/// Destination of output values.
+pub trait Output {
+    /// Accept an output value.
+    fn write(&mut self, value: i64);
+}
+
+impl<I: Input + ?Sized> Input for &mut I {
+    fn read(&mut self) -> Option<i64> {
+        (**self).read()
+    }
+}
+
+impl<O: Output + ?Sized> Output for &mut O {
+    fn write(&mut self, value: i64) {
+        (**self).write(value)
+    }
+}
+
+impl Input for VecDeque<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.pop_front()
+    }
+}
+
+impl Output for VecDeque<i64> {
+    fn write(&mut self, value: i64) {
+        self.push_back(value);
+    }
+}
+
+impl Output for Vec<i64> {
+    fn write(&mut self, value: i64) {
+        self.push(value);
+    }
+}
+
+/// Blocks until a value is received; there is no more input once all senders are gone.
+impl Input for Receiver<i64> {
+    fn read(&mut self) -> Option<i64> {
+        self.recv().ok()
+    }
+}
+
+/// Values sent after the receiver is gone are dropped.
+impl Output for Sender<i64> {
+    fn write(&mut self, value: i64) {
+        let _ = self.send(value);
+    }
+}
+
+/// No input at all.
+impl Input for () {
+    fn read(&mut self) -> Option<i64> {
+        None
+    }
+}
+
+/// Input from a closure, see `from_fn()`.
+#[derive(Debug, Clone, Copy)]
+pub struct FnInput<F>(F);
+
+/// Output to a closure, see `to_fn()`.
+#[derive(Debug, Clone, Copy)]
+pub struct FnOutput<F>(F);
+
+/// Use a closure returning the next input value (or `None`) as `Input`.
+pub fn from_fn<F: FnMut() -> Option<i64>>(f: F) -> FnInput<F> {
+    FnInput(f)
+}
+
+/// Use a closure accepting output values as `Output`.
+pub fn to_fn<F: FnMut(i64)>(f: F) -> FnOutput<F> {
+    FnOutput(f)
+}
+
+impl<F: FnMut() -> Option<i64>> Input for FnInput<F> {
+    fn read(&mut self) -> Option<i64> {
+        (self.0)()
+    }
+}
+
+impl<F: FnMut(i64)> Output for FnOutput<F> {
+    fn write(&mut self, value: i64) {
+        (self.0)(value)
+    }
+}
+
+/// State of a machine after executing instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The machine can execute further instructions.
+    Running,
+    /// The next instruction reads input, but none was available. Running again retries it.
+    NeedsInput,
+    /// The machine executed opcode `99`.
+    Halted,
+}
+
+/// An Intcode machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    memory: Vec<i64>,
+    ip: usize,
+    relative_base: i64,
+    halted: bool,
+}
+
+/// Parse a comma-separated Intcode program.
+///
+/// # Failures
+/// Returns an error for values that are not integers.
+pub fn parse_program(s: &str) -> Result<Vec<i64>, String> {
+    s.trim()
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .parse()
+                .map_err(|_| format!("invalid intcode value: {}", v))
+        })
+        .collect()
+}
+
+impl FromStr for Machine {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_program(s).map(Machine::new)
+    }
+}
+
+impl Machine {
+    /// Create new `Machine` with the program loaded at address `0`.
+    pub fn new(program: Vec<i64>) -> Self {
+        Self {
+            memory: program,
+            ip: 0,
+            relative_base: 0,
+            halted: false,
+        }
+    }
+
+    /// The memory that has been loaded or written. All other addresses hold `0`.
+    pub fn memory(&self) -> &[i64] {
+        &self.memory
+    }
+
+    /// Value at `address`.
+    pub fn get(&self, address: usize) -> i64 {
+        self.memory.get(address).copied().unwrap_or(0)
+    }
+
+    /// Set the value at `address`, growing the memory if needed.
+    pub fn set(&mut self, address: usize, value: i64) {
+        if address >= self.memory.len() {
+            self.memory.resize(address + 1, 0);
+        }
+        self.memory[address] = value;
+    }
+
+    /// Address of the next instruction.
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Returns `true` if the machine has halted.
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Address of parameter `n` (starting at 1) of the current instruction, according to its
+    /// mode.
+    fn address(&self, n: usize) -> Result<usize, String> {
+        let mode = self.get(self.ip) / 10i64.pow(n as u32 + 1) % 10;
+        let param = self.ip + n;
+        let address = match mode {
+            0 => self.get(param),
+            1 => return Ok(param),
+            2 => self.relative_base + self.get(param),
+            _ => return Err(format!("invalid parameter mode {} at {}", mode, self.ip)),
+        };
+        usize::try_from(address).map_err(|_| format!("negative address {} at {}", address, self.ip))
+    }
+
+    fn read(&self, n: usize) -> Result<i64, String> {
+        self.address(n).map(|address| self.get(address))
+    }
+
+    fn write(&mut self, n: usize, value: i64) -> Result<(), String> {
+        if self.get(self.ip) / 10i64.pow(n as u32 + 1) % 10 == 1 {
+            return Err(format!("write in immediate mode at {}", self.ip));
+        }
+        let address = self.address(n)?;
+        self.set(address, value);
+        Ok(())
+    }
+
+    /// Execute one instruction.
+    ///
+    /// # Failures
+    /// Returns an error for invalid opcodes or parameter modes and negative addresses.
+    pub fn step<I: Input, O: Output>(
+        &mut self,
+        mut input: I,
+        mut output: O,
+    ) -> Result<Status, String> {
+        if self.halted {
+            return Ok(Status::Halted);
+        }
+
+        let opcode = self.get(self.ip) % 100;
+        match opcode {
+            1 | 2 | 7 | 8 => {
+                let (a, b) = (self.read(1)?, self.read(2)?);
+                let value = match opcode {
+                    1 => a + b,
+                    2 => a * b,
+                    7 => (a < b) as i64,
+                    _ => (a == b) as i64,
+                };
+                self.write(3, value)?;
+                self.ip += 4;
+            }
+            3 => match input.read() {
+                Some(value) => {
+                    self.write(1, value)?;
+                    self.ip += 2;
+                }
+                None => return Ok(Status::NeedsInput),
+            },
+            4 => {
+                output.write(self.read(1)?);
+                self.ip += 2;
+            }
+            5 | 6 => {
+                let (value, target) = (self.read(1)?, self.read(2)?);
+                if (value != 0) == (opcode == 5) {
+                    self.ip = usize::try_from(target)
+                        .map_err(|_| format!("negative jump target {} at {}", target, self.ip))?;
+                } else {
+                    self.ip += 3;
+                }
+            }
+            9 => {
+                self.relative_base += self.read(1)?;
+                self.ip += 2;
+            }
+            99 => {
+                self.halted = true;
+                return Ok(Status::Halted);
+            }
+            _ => return Err(format!("invalid opcode {} at {}", opcode, self.ip)),
+        }
+
+        Ok(Status::Running)
+    }
+
+    /// Execute instructions until the machine halts or needs input that isn't available.
+    ///
+    /// # Failures
+    /// Returns an error for invalid instructions, see `step()`.
+    pub fn run<I: Input, O: Output>(
+        &mut self,
+        mut input: I,
+        mut output: O,
+    ) -> Result<Status, String> {
+        loop {
+            match self.step(&mut input, &mut output)? {
+                Status::Running => {}
+                status => return Ok(status),
+            }
+        }
+    }
+
+    /// Run with the given input values and return all output values.
+    ///
+    /// # Failures
+    /// Returns an error for invalid instructions, see `step()`.
+    pub fn run_with(&mut self, inputs: &[i64]) -> Result<Vec<i64>, String> {
+        let mut input: VecDeque<i64> = inputs.iter().copied().collect();
+        let mut output = Vec::new();
+        self.run(&mut input, &mut output)?;
+        Ok(output)
+    }
+}
@@ -0,0 +1,149 @@
+use std::collections::VecDeque;
+use std::convert::TryFrom;
+use std::sync::mpsc::{Receiver, Sender};
+use std::thread::{self, JoinHandle};
+
+use super::{from_fn, Machine};
+
+/// Run `machine` on its own thread, reading from `input` and writing to `output`, until it
+/// halts or all senders of `input` are gone. The thread returns the machine in its final state.
+///
+/// Chaining machines with channels gives setups like the amplifier feedback loop of 2019 day 7.
+pub fn spawn(
+    mut machine: Machine,
+    input: Receiver<i64>,
+    output: Sender<i64>,
+) -> JoinHandle<Result<Machine, String>> {
+    thread::spawn(move || machine.run(input, output).map(|_| machine))
+}
+
+/// A packet of the network of 2019 day 23.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Packet {
+    /// Address of the receiver.
+    pub destination: i64,
+    /// First value.
+    pub x: i64,
+    /// Second value.
+    pub y: i64,
+}
+
+/// Outcome of one round of `Network::step()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    /// Packets sent to addresses outside of the network, in the order they were sent.
+    pub external: Vec<Packet>,
+    /// `true` if no machine had incoming packets and no packet was sent.
+    pub idle: bool,
+}
+
+/// Machines exchanging packets by address (2019 day 23).
+///
+/// Machine `i` has address `i` and receives it as its first input. Packets are output as
+/// destination, `x` and `y`, and input as `x` and `y`. A machine reading while its queue is empty
+/// gets `-1`; reading again without new packets pauses it until the next round.
+#[derive(Debug, Clone)]
+pub struct Network {
+    machines: Vec<Machine>,
+    queues: Vec<VecDeque<i64>>,
+    outputs: Vec<Vec<i64>>,
+}
+
+impl Network {
+    /// Create new `Network` of `size` machines running `program`.
+    pub fn new(program: &[i64], size: usize) -> Self {
+        Self {
+            machines: (0..size).map(|_| Machine::new(program.to_vec())).collect(),
+            queues: (0..size).map(|i| VecDeque::from(vec![i as i64])).collect(),
+            outputs: vec![Vec::new(); size],
+        }
+    }
+
+    /// Number of machines.
+    pub fn len(&self) -> usize {
+        self.machines.len()
+    }
+
+    /// Returns `true` if the network has no machines.
+    pub fn is_empty(&self) -> bool {
+        self.machines.is_empty()
+    }
+
+    /// Queue a packet for the machine at `address`.
+    ///
+    /// # Panics
+    /// Panics if there is no machine at `address`.
+    pub fn send(&mut self, address: usize, x: i64, y: i64) {
+        self.queues[address].extend([x, y]);
+    }
+
+    /// Let every machine run until it has processed its incoming packets. Packets between
+    /// machines are delivered immediately, so later machines may receive them in the same round.
+    ///
+    /// # Failures
+    /// Returns an error if a machine executes an invalid instruction.
+    pub fn step(&mut self) -> Result<Round, String> {
+        let mut idle = self.queues.iter().all(VecDeque::is_empty);
+        let mut external = Vec::new();
+
+        for i in 0..self.machines.len() {
+            let queue = &mut self.queues[i];
+            let mut polled = false;
+            let input = from_fn(|| {
+                queue.pop_front().or_else(|| {
+                    let first = !polled;
+                    polled = true;
+                    first.then_some(-1)
+                })
+            });
+            self.machines[i].run(input, &mut self.outputs[i])?;
+
+            let complete = self.outputs[i].len() / 3 * 3;
+            let values: Vec<i64> = self.outputs[i].drain(..complete).collect();
+            for packet in values.chunks_exact(3) {
+                idle = false;
+                let (destination, x, y) = (packet[0], packet[1], packet[2]);
+                match usize::try_from(destination) {
+                    Ok(address) if address < self.queues.len() => self.send(address, x, y),
+                    _ => external.push(Packet { destination, x, y }),
+                }
+            }
+        }
+
+        Ok(Round { external, idle })
+    }
+
+    /// Run the network with a NAT at address `nat`: the NAT keeps the last packet sent to it and
+    /// sends it to machine `0` whenever the network is idle.
+    ///
+    /// Returns the `y` of the first packet sent to the NAT, and the first `y` the NAT delivers
+    /// twice in a row. Returns `None` if the network is idle before the NAT has a packet.
+    ///
+    /// # Failures
+    /// Returns an error if a machine executes an invalid instruction.
+    pub fn run_nat(&mut self, nat: i64) -> Result<Option<(i64, i64)>, String> {
+        let mut first = None;
+        let mut last = None;
+        let mut delivered = None;
+        loop {
+            let round = self.step()?;
+            for packet in round.external {
+                if packet.destination == nat {
+                    first.get_or_insert(packet.y);
+                    last = Some(packet);
+                }
+            }
+            if round.idle {
+                let packet = match last {
+                    Some(packet) => packet,
+                    None => return Ok(None),
+                };
+                if delivered == Some(packet.y) {
+                    return Ok(first.map(|first| (first, packet.y)));
+                }
+                delivered = Some(packet.y);
+                self.send(0, packet.x, packet.y);
+            }
+        }
+    }
+}
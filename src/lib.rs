@@ -1 +1,119 @@
+//! Helpers for solving Advent of Code puzzles.
+//!
+//! The algorithmic core (`collections`, `geometry`, `math` and `search`) only needs `core` and
+//! `alloc`. Everything else, including all input handling, requires the `std` feature, which is
+//! enabled by default. Disable default features to build the core for `no_std` targets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod collections;
+pub mod geometry;
+pub mod math;
+pub mod search;
+
+#[cfg(feature = "std")]
+pub mod answer;
+#[cfg(feature = "std")]
+pub mod automaton;
+#[cfg(feature = "std")]
+pub mod bench;
+#[cfg(feature = "std")]
+pub mod bitmask;
+#[cfg(feature = "std")]
+pub mod blocks;
+#[cfg(feature = "std")]
+pub mod bridge;
+#[cfg(feature = "std")]
+pub mod bsp;
+#[cfg(feature = "std")]
+pub mod bus;
+#[cfg(feature = "std")]
+pub mod carts;
+#[cfg(feature = "std")]
+pub mod claims;
+#[cfg(feature = "std")]
+pub mod client;
+#[cfg(feature = "std")]
+pub mod cycles;
+#[cfg(feature = "std")]
+pub mod debug;
+#[cfg(feature = "std")]
+pub mod defrag;
+#[cfg(feature = "std")]
+pub mod diffusion;
+#[cfg(feature = "network")]
+pub mod fetch;
+#[cfg(feature = "std")]
+pub mod firewall;
+#[cfg(feature = "std")]
+pub mod fractal;
+#[cfg(feature = "std")]
+pub mod game;
+#[cfg(feature = "std")]
+pub mod generators;
+#[cfg(feature = "std")]
+pub mod grid;
+#[cfg(feature = "std")]
+pub mod guards;
+#[cfg(feature = "std")]
+pub mod hash;
+#[cfg(feature = "std")]
+pub mod hex;
+#[cfg(feature = "std")]
 pub mod input;
+#[cfg(feature = "std")]
+pub mod instructions;
+#[cfg(feature = "std")]
+pub mod intcode;
+#[cfg(feature = "std")]
+pub mod marbles;
+#[cfg(feature = "std")]
+pub mod matching;
+#[cfg(feature = "std")]
+pub mod molecule;
+#[cfg(feature = "std")]
+pub mod monkeys;
+#[cfg(feature = "std")]
+pub mod navigation;
+#[cfg(feature = "std")]
+pub mod ocr;
+#[cfg(feature = "std")]
+pub mod optimize;
+#[cfg(feature = "std")]
+pub mod output;
+#[cfg(feature = "std")]
+pub mod particles;
+#[cfg(feature = "std")]
+pub mod project;
+#[cfg(feature = "std")]
+pub mod registry;
+#[cfg(feature = "std")]
+pub mod reindeer;
+#[cfg(feature = "std")]
+pub mod reservoir;
+#[cfg(feature = "std")]
+pub mod runner;
+#[cfg(feature = "std")]
+pub mod sequences;
+#[cfg(feature = "std")]
+pub mod simulate;
+#[cfg(feature = "std")]
+pub mod slices;
+#[cfg(feature = "std")]
+pub mod stars;
+#[cfg(feature = "std")]
+pub mod strings;
+#[cfg(feature = "std")]
+pub mod testing;
+#[cfg(feature = "std")]
+pub mod tickets;
+#[cfg(feature = "std")]
+pub mod tournament;
+#[cfg(feature = "std")]
+pub mod tree;
+#[cfg(feature = "std")]
+pub mod validate;
+#[cfg(feature = "std")]
+pub mod voronoi;
@@ -0,0 +1,63 @@
+//! The elves' marble game ("Marble Mania").
+//!
+//! Marbles are placed into a circle one after another, between the marbles one and two
+//! positions clockwise of the current one. Marbles whose value is a multiple of 23 are kept
+//! instead, together with the marble seven positions counterclockwise, and both count as
+//! points for the current player.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::marbles;
+//!
+//! let (players, last_marble) = marbles::parse("10 players; last marble is worth 1618 points").unwrap();
+//!
+//! assert_eq!(8317, marbles::high_score(players, last_marble));
+//! ```
+
+use crate::collections::CircularList;
+
+/// Play the game and return the score of every player.
+///
+/// # Panics
+/// Panics if `players` is zero.
+pub fn scores(players: usize, last_marble: u32) -> Vec<u64> {
+    assert!(players > 0, "at least one player is required");
+
+    let mut scores = vec![0; players];
+    let mut circle = CircularList::with_capacity(last_marble as usize + 1);
+    circle.insert(0);
+
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            circle.move_backward(7);
+            let removed = circle.remove().unwrap_or(0);
+            scores[(marble as usize - 1) % players] += u64::from(marble + removed);
+        } else {
+            circle.move_forward(1);
+            circle.insert(marble);
+        }
+    }
+
+    scores
+}
+
+/// The winning score.
+pub fn high_score(players: usize, last_marble: u32) -> u64 {
+    scores(players, last_marble).into_iter().max().unwrap_or(0)
+}
+
+/// Parse the puzzle input `10 players; last marble is worth 1618 points`.
+pub fn parse(input: &str) -> Result<(usize, u32), String> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    match words.as_slice() {
+        [players, "players;", "last", "marble", "is", "worth", points, "points"] => Ok((
+            players
+                .parse()
+                .map_err(|_| format!("invalid number of players: {}", players))?,
+            points
+                .parse()
+                .map_err(|_| format!("invalid marble value: {}", points))?,
+        )),
+        _ => Err(format!("invalid game description: {}", input)),
+    }
+}
@@ -0,0 +1,49 @@
+//! Deducing one-to-one assignments from candidate sets.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::matching::eliminate;
+//! use std::collections::BTreeSet;
+//!
+//! let candidates: Vec<BTreeSet<char>> = vec![
+//!     "ab".chars().collect(),
+//!     "b".chars().collect(),
+//!     "abc".chars().collect(),
+//! ];
+//! assert_eq!(Some(vec!['a', 'b', 'c']), eliminate(candidates));
+//! ```
+
+use std::collections::BTreeSet;
+
+/// Resolve a one-to-one assignment by iterative elimination: an item with a single remaining
+/// candidate is assigned that candidate, which is then removed from all other items.
+///
+/// `candidates[i]` contains all values item `i` could be assigned to. Returns the assigned value
+/// per item, or `None` if elimination gets stuck (no unique solution can be deduced this way) or
+/// runs into a contradiction.
+pub fn eliminate<T: Ord + Copy>(mut candidates: Vec<BTreeSet<T>>) -> Option<Vec<T>> {
+    let mut assignment: Vec<Option<T>> = vec![None; candidates.len()];
+    let mut remaining = candidates.len();
+
+    while remaining > 0 {
+        let (item, value) = candidates
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| assignment[i].is_none())
+            .find(|(_, set)| set.len() == 1)
+            .map(|(i, set)| (i, *set.iter().next().unwrap()))?;
+
+        assignment[item] = Some(value);
+        remaining -= 1;
+        for (i, set) in candidates.iter_mut().enumerate() {
+            if i != item {
+                set.remove(&value);
+                if set.is_empty() && assignment[i].is_none() {
+                    return None;
+                }
+            }
+        }
+    }
+
+    assignment.into_iter().collect()
+}
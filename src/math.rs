@@ -0,0 +1,330 @@
+//! Number theory helpers.
+//!
+//! Modular arithmetic works on signed integers, with intermediate results computed in `i128`
+//! so that products of two `i64` moduli don't overflow.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::math::{discrete_log_bruteforce, mod_inverse, transform};
+//!
+//! let loop_size = discrete_log_bruteforce(7, 20201227, 5764801).unwrap();
+//! assert_eq!(8, loop_size);
+//! assert_eq!(14897079, transform(17807724, loop_size, 20201227));
+//!
+//! assert_eq!(Some(4), mod_inverse(-3, 13));
+//! ```
+
+use core::convert::TryFrom;
+use core::ops::{Div, Mul, Rem};
+
+/// Compute `base^exp mod modulus` by repeated squaring.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+
+    result as u64
+}
+
+/// The smallest multiple of `k` that is at least `t`, e.g. the first departure at or after `t`
+/// of a bus leaving every `k` minutes.
+///
+/// # Panics
+/// Panics if `k` is zero.
+pub fn next_multiple(t: u64, k: u64) -> u64 {
+    assert!(k > 0, "k must be positive");
+    t.div_ceil(k) * k
+}
+
+/// Transform a subject number: start with `1` and `loop_size` times multiply by `subject`
+/// modulo `modulus`.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+pub fn transform(subject: u64, loop_size: u64, modulus: u64) -> u64 {
+    mod_pow(subject, loop_size, modulus)
+}
+
+/// Find the smallest loop size `x` such that `subject^x mod modulus == target`, by trying all
+/// loop sizes below `modulus`. Returns `None` if no such loop size exists.
+///
+/// Runs in `O(modulus)`, which is fine for moduli up to a few hundred million.
+///
+/// # Panics
+/// Panics if `modulus` is zero.
+pub fn discrete_log_bruteforce(subject: u64, modulus: u64, target: u64) -> Option<u64> {
+    assert!(modulus > 0, "modulus must be positive");
+    let (subject, modulus_wide) = (u128::from(subject % modulus), u128::from(modulus));
+    let target = u128::from(target % modulus);
+    let mut value = 1 % modulus_wide;
+
+    for loop_size in 0..modulus {
+        if value == target {
+            return Some(loop_size);
+        }
+        value = value * subject % modulus_wide;
+    }
+
+    None
+}
+
+/// Extended Euclidean algorithm: returns `(g, x, y)` with `g = gcd(a, b) >= 0` and
+/// `a * x + b * y == g`.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (i128::from(a), i128::from(b));
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+/// The inverse of `a` modulo `modulus`, in `0..modulus`. Returns `None` if `a` and `modulus`
+/// are not coprime.
+///
+/// # Panics
+/// Panics if `modulus` is not positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    assert!(modulus > 0, "modulus must be positive");
+    let (g, x, _) = egcd(a.rem_euclid(modulus), modulus);
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/// Chinese remainder theorem: the smallest non-negative `x` with `x ≡ residues[i] (mod
+/// moduli[i])` for all `i`, together with the combined modulus (the lcm of the moduli), such
+/// that all solutions are `x + k * modulus`.
+///
+/// The moduli don't need to be coprime. Returns `None` if the congruences contradict each other
+/// or the combined modulus doesn't fit into an `i64`. Residues may be negative.
+///
+/// # Panics
+/// Panics if the slices differ in length or a modulus is not positive.
+///
+/// # Examples
+/// ```
+/// use aoc_util::math::crt;
+///
+/// assert_eq!(Some((23, 105)), crt(&[2, 3, 2], &[3, 5, 7]));
+/// assert_eq!(None, crt(&[0, 1], &[4, 6]));
+/// ```
+pub fn crt(residues: &[i64], moduli: &[i64]) -> Option<(i64, i64)> {
+    assert_eq!(residues.len(), moduli.len(), "one modulus per residue");
+    let (mut x, mut modulus) = (0i128, 1i128);
+    for (&r, &m) in residues.iter().zip(moduli) {
+        assert!(m > 0, "modulus must be positive");
+        let (r, m) = (i128::from(r).rem_euclid(i128::from(m)), i128::from(m));
+
+        // Solve x + k * modulus ≡ r (mod m) for k.
+        let (g, p, _) = egcd(modulus as i64, m as i64);
+        let (g, p) = (i128::from(g), i128::from(p));
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let m_g = m / g;
+        let k = ((r - x) / g % m_g * p).rem_euclid(m_g);
+        x += k * modulus;
+        modulus *= m_g;
+        if modulus > i128::from(i64::MAX) {
+            return None;
+        }
+        x = x.rem_euclid(modulus);
+    }
+    Some((x as i64, modulus as i64))
+}
+
+/// Primitive integer types, as supported by `gcd()` and `lcm()`.
+pub trait Integer:
+    Copy + Eq + Div<Output = Self> + Mul<Output = Self> + Rem<Output = Self>
+{
+    /// The unsigned type of the same size (the type itself for unsigned types).
+    type Unsigned: Integer;
+
+    /// `0`
+    const ZERO: Self;
+    /// `1`
+    const ONE: Self;
+
+    /// The absolute value (the value itself for unsigned types).
+    fn abs(self) -> Self;
+
+    /// The absolute value, or `None` if it overflows.
+    fn checked_abs(self) -> Option<Self>;
+
+    /// The absolute value as unsigned type, which never overflows.
+    fn unsigned_abs(self) -> Self::Unsigned;
+
+    /// Convert an unsigned value back, or `None` if it doesn't fit.
+    fn from_unsigned(n: Self::Unsigned) -> Option<Self>;
+
+    /// Convert an unsigned value back, wrapping around if it doesn't fit (like `as`).
+    fn from_unsigned_wrapping(n: Self::Unsigned) -> Self;
+
+    /// Multiplication returning `None` on overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_integer {
+    (@common $t:ty) => {
+        const ZERO: Self = 0;
+        const ONE: Self = 1;
+
+        fn checked_mul(self, other: Self) -> Option<Self> {
+            <$t>::checked_mul(self, other)
+        }
+    };
+    (signed $($t:ty => $u:ty),*) => {
+        $(
+            impl Integer for $t {
+                type Unsigned = $u;
+
+                impl_integer!(@common $t);
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t>::checked_abs(self)
+                }
+
+                fn unsigned_abs(self) -> $u {
+                    <$t>::unsigned_abs(self)
+                }
+
+                fn from_unsigned(n: $u) -> Option<Self> {
+                    <$t>::try_from(n).ok()
+                }
+
+                fn from_unsigned_wrapping(n: $u) -> Self {
+                    n as $t
+                }
+            }
+        )*
+    };
+    (unsigned $($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                type Unsigned = $t;
+
+                impl_integer!(@common $t);
+
+                fn abs(self) -> Self {
+                    self
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    Some(self)
+                }
+
+                fn unsigned_abs(self) -> Self {
+                    self
+                }
+
+                fn from_unsigned(n: Self) -> Option<Self> {
+                    Some(n)
+                }
+
+                fn from_unsigned_wrapping(n: Self) -> Self {
+                    n
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(signed i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize);
+impl_integer!(unsigned u8, u16, u32, u64, u128, usize);
+
+/// Greatest common divisor of the absolute values, computed without overflow.
+fn unsigned_gcd<T: Integer>(a: T, b: T) -> T::Unsigned {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != T::Unsigned::ZERO {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Greatest common divisor, which is non-negative. `gcd(0, 0)` is `0`.
+///
+/// Never panics. For signed types, the results of `gcd(T::MIN, 0)` and `gcd(T::MIN, T::MIN)`
+/// don't fit into `T` and wrap around to `T::MIN`; use `checked_gcd()` to detect this.
+///
+/// # Examples
+/// ```
+/// use aoc_util::math::{gcd, lcm, lcm_of};
+///
+/// assert_eq!(6, gcd(-12i32, 18));
+/// assert_eq!(36u64, lcm(12, 18));
+/// assert_eq!(2520u128, lcm_of(1..=10));
+/// ```
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    T::from_unsigned_wrapping(unsigned_gcd(a, b))
+}
+
+/// Greatest common divisor like `gcd()`, or `None` if it doesn't fit into `T`.
+pub fn checked_gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    T::from_unsigned(unsigned_gcd(a, b))
+}
+
+/// Least common multiple, which is non-negative. It is `0` if either number is `0`.
+///
+/// # Panics
+/// Panics (in debug builds) if the result overflows; see `checked_lcm()`. In release builds, it
+/// wraps around.
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::ZERO || b == T::ZERO {
+        return T::ZERO;
+    }
+    let lcm = a.unsigned_abs() / unsigned_gcd(a, b) * b.unsigned_abs();
+    debug_assert!(T::from_unsigned(lcm).is_some(), "lcm overflows");
+    T::from_unsigned_wrapping(lcm)
+}
+
+/// Least common multiple like `lcm()`, or `None` if it overflows.
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let lcm = (a.unsigned_abs() / unsigned_gcd(a, b)).checked_mul(b.unsigned_abs())?;
+    T::from_unsigned(lcm)
+}
+
+/// Least common multiple of all numbers; `1` if there are none.
+///
+/// # Panics
+/// Panics (in debug builds) if the result overflows; see `checked_lcm_of()`.
+pub fn lcm_of<T: Integer, I: IntoIterator<Item = T>>(numbers: I) -> T {
+    numbers.into_iter().fold(T::ONE, lcm)
+}
+
+/// Least common multiple of all numbers like `lcm_of()`, or `None` if it overflows.
+pub fn checked_lcm_of<T: Integer, I: IntoIterator<Item = T>>(numbers: I) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |acc, n| checked_lcm(acc, n))
+}
@@ -0,0 +1,151 @@
+//! Molecule replacement rules (`H => HO`): single-step productions and reduction search.
+//!
+//! Searching forward from `e` (or backwards with a plain BFS) is infeasible for realistic inputs.
+//! Instead, `Rules::reduce()` greedily applies the rules in reverse in a random order and
+//! restarts with a different order whenever it gets stuck.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::molecule;
+//!
+//! let input = "e => H\ne => O\nH => HO\nH => OH\nO => HH\n\nHOHOHO";
+//! let (rules, molecule) = molecule::parse(input).unwrap();
+//!
+//! assert_eq!(7, rules.single_step(&molecule).len());
+//! assert_eq!(Some(6), rules.reduce(&molecule, "e", 0, 100));
+//! ```
+
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::optimize;
+
+/// A list of replacement rules.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Rules {
+    rules: Vec<(String, String)>,
+}
+
+impl Rules {
+    /// Create new `Rules` from a list of `(from, to)` pairs.
+    pub fn new(rules: Vec<(String, String)>) -> Self {
+        Self { rules }
+    }
+
+    /// The rules as `(from, to)` pairs.
+    pub fn rules(&self) -> &[(String, String)] {
+        &self.rules
+    }
+
+    /// All distinct molecules that can be produced by applying exactly one replacement.
+    pub fn single_step(&self, molecule: &str) -> HashSet<String> {
+        let mut productions = HashSet::new();
+        for (from, to) in &self.rules {
+            for (idx, _) in molecule.match_indices(from.as_str()) {
+                let mut production = String::with_capacity(molecule.len() + to.len());
+                production.push_str(&molecule[..idx]);
+                production.push_str(to);
+                production.push_str(&molecule[idx + from.len()..]);
+                productions.insert(production);
+            }
+        }
+        productions
+    }
+
+    /// Search for a sequence of replacements that produces `molecule` from `target` by reducing
+    /// `molecule` back to `target`. Returns the number of replacements.
+    ///
+    /// Rules starting from `target` are only applied when they produce the whole remaining
+    /// molecule. All other rules are only applied if they make the molecule shorter, so rules
+    /// that don't grow the molecule (e.g. `ab => ba`) are never used. The reduction is greedy;
+    /// whenever it gets stuck the rule order is shuffled (deterministically derived from `seed`)
+    /// and the search restarts, up to `max_restarts` times.
+    pub fn reduce(
+        &self,
+        molecule: &str,
+        target: &str,
+        seed: u64,
+        max_restarts: usize,
+    ) -> Option<usize> {
+        let mut order: Vec<&(String, String)> = self.rules.iter().collect();
+        let mut first = true;
+
+        optimize::first_success(
+            |rng| {
+                if !first {
+                    rng.shuffle(&mut order);
+                }
+                first = false;
+                greedy_reduce(&order, molecule, target)
+            },
+            seed,
+            max_restarts,
+        )
+    }
+}
+
+fn greedy_reduce(order: &[&(String, String)], molecule: &str, target: &str) -> Option<usize> {
+    let mut current = molecule.to_string();
+    let mut steps = 0;
+
+    while current != target {
+        let mut progressed = false;
+        for (from, to) in order {
+            if to.is_empty() {
+                continue;
+            }
+            if from == target {
+                if current == *to {
+                    current = from.clone();
+                    progressed = true;
+                    break;
+                }
+            } else if to.len() <= from.len() {
+                // Only shrinking reductions, so every attempt terminates
+                continue;
+            } else if let Some(idx) = current.find(to.as_str()) {
+                current.replace_range(idx..idx + to.len(), from);
+                progressed = true;
+                break;
+            }
+        }
+        if !progressed {
+            return None;
+        }
+        steps += 1;
+    }
+
+    Some(steps)
+}
+
+impl FromStr for Rules {
+    type Err = String;
+
+    /// Parses one rule `from => to` per line. Blank lines are ignored.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| {
+                let mut parts = line.splitn(2, "=>");
+                match (parts.next(), parts.next()) {
+                    (Some(from), Some(to)) => Ok((from.trim().to_string(), to.trim().to_string())),
+                    _ => Err(format!("invalid replacement rule: {}", line)),
+                }
+            })
+            .collect::<Result<_, _>>()
+            .map(Rules::new)
+    }
+}
+
+/// Parse the puzzle input: a list of rules, a blank line, and the medicine molecule.
+pub fn parse(input: &str) -> Result<(Rules, String), String> {
+    let input = input.trim();
+    let idx = input
+        .rfind('\n')
+        .ok_or_else(|| String::from("missing molecule"))?;
+    let rules = input[..idx].parse()?;
+    let molecule = input[idx + 1..].trim().to_string();
+
+    Ok((rules, molecule))
+}
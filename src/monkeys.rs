@@ -0,0 +1,294 @@
+//! Simulation of "monkey in the middle" style item throwing with automatic modulus pooling.
+//!
+//! Item values ("worry levels") grow without bounds if they are not reduced. Since every monkey
+//! only checks divisibility, all values can be kept modulo the least common multiple of all
+//! divisors (multiplied by the relief factor, so that integer division still commutes with the
+//! reduction). `Troop` takes care of this automatically.
+//!
+//! # Examples
+//! ```no_run
+//! use aoc_util::input::{FileReader, FromFile};
+//! use aoc_util::monkeys::Troop;
+//!
+//! let input: String = FileReader::new().read_from_file("monkeys.txt").unwrap();
+//! let mut troop: Troop = input.parse().unwrap();
+//!
+//! troop.run(10_000, 1);
+//! println!("{}", troop.monkey_business());
+//! ```
+
+use std::str::FromStr;
+
+use crate::math::lcm_of;
+
+/// Operation that a monkey applies to an item's value when inspecting it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// `new = old + n`
+    Add(u64),
+    /// `new = old * n`
+    Mul(u64),
+    /// `new = old + old`
+    Double,
+    /// `new = old * old`
+    Square,
+}
+
+impl Operation {
+    /// Apply the operation to a value.
+    pub fn apply(self, old: u64) -> u64 {
+        match self {
+            Operation::Add(n) => old + n,
+            Operation::Mul(n) => old * n,
+            Operation::Double => old + old,
+            Operation::Square => old * old,
+        }
+    }
+}
+
+impl FromStr for Operation {
+    type Err = String;
+
+    /// Parses expressions of the form `new = old * 19` or `old + old`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expr = s.trim();
+        let expr = expr.strip_prefix("new =").unwrap_or(expr);
+        let tokens: Vec<&str> = expr.split_whitespace().collect();
+
+        match tokens.as_slice() {
+            ["old", "+", "old"] => Ok(Operation::Double),
+            ["old", "*", "old"] => Ok(Operation::Square),
+            ["old", op, n] => {
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("invalid operand in operation: {}", s))?;
+                match *op {
+                    "+" => Ok(Operation::Add(n)),
+                    "*" => Ok(Operation::Mul(n)),
+                    _ => Err(format!("invalid operator in operation: {}", s)),
+                }
+            }
+            _ => Err(format!("invalid operation: {}", s)),
+        }
+    }
+}
+
+/// Keeps values small by reducing them modulo the least common multiple of a set of divisors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModulusPool {
+    modulus: u64,
+}
+
+impl ModulusPool {
+    /// Create new `ModulusPool` from all divisors whose divisibility tests must be preserved.
+    ///
+    /// # Panics
+    /// Panics if any divisor is `0`.
+    pub fn new<I: IntoIterator<Item = u64>>(divisors: I) -> Self {
+        let modulus = lcm_of(divisors);
+        assert_ne!(0, modulus, "divisor must not be 0");
+        Self { modulus }
+    }
+
+    /// Create new `ModulusPool` that additionally preserves the result of an integer division by
+    /// `relief` applied after the reduction.
+    ///
+    /// # Panics
+    /// Panics if any divisor is `0`.
+    pub fn with_relief<I: IntoIterator<Item = u64>>(divisors: I, relief: u64) -> Self {
+        let pool = Self::new(divisors);
+        Self {
+            modulus: pool.modulus * relief.max(1),
+        }
+    }
+
+    /// The pooled modulus.
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Reduce a value modulo the pooled modulus.
+    pub fn reduce(&self, value: u64) -> u64 {
+        value % self.modulus
+    }
+}
+
+/// A single monkey of the ruleset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    /// Items the monkey is currently holding.
+    pub items: Vec<u64>,
+    /// Operation applied to each item during inspection.
+    pub operation: Operation,
+    /// Divisor of the divisibility test.
+    pub divisor: u64,
+    /// Target monkey if the test succeeds.
+    pub if_true: usize,
+    /// Target monkey if the test fails.
+    pub if_false: usize,
+}
+
+impl FromStr for Monkey {
+    type Err = String;
+
+    /// Parses a single monkey block, starting with the `Monkey n:` header line.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut items = None;
+        let mut operation = None;
+        let mut divisor = None;
+        let mut if_true = None;
+        let mut if_false = None;
+
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (key, value) = match line.find(':') {
+                Some(idx) => (&line[..idx], line[idx + 1..].trim()),
+                None => return Err(format!("invalid line: {}", line)),
+            };
+            let last_number = || {
+                value
+                    .rsplit(' ')
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .ok_or_else(|| format!("expected number: {}", line))
+            };
+
+            match key {
+                k if k.starts_with("Monkey") => {}
+                "Starting items" => {
+                    items = Some(
+                        value
+                            .split(',')
+                            .map(|n| n.trim())
+                            .filter(|n| !n.is_empty())
+                            .map(|n| n.parse().map_err(|_| format!("invalid item: {}", n)))
+                            .collect::<Result<Vec<u64>, _>>()?,
+                    )
+                }
+                "Operation" => operation = Some(value.parse()?),
+                "Test" => match last_number()? {
+                    0 => return Err(format!("divisor must not be 0: {}", line)),
+                    n => divisor = Some(n),
+                },
+                "If true" => if_true = Some(last_number()? as usize),
+                "If false" => if_false = Some(last_number()? as usize),
+                _ => return Err(format!("unknown key: {}", key)),
+            }
+        }
+
+        let missing = |name: &str| format!("missing field: {}", name);
+        Ok(Monkey {
+            items: items.ok_or_else(|| missing("Starting items"))?,
+            operation: operation.ok_or_else(|| missing("Operation"))?,
+            divisor: divisor.ok_or_else(|| missing("Test"))?,
+            if_true: if_true.ok_or_else(|| missing("If true"))?,
+            if_false: if_false.ok_or_else(|| missing("If false"))?,
+        })
+    }
+}
+
+/// A group of monkeys throwing items at each other.
+#[derive(Debug, Clone)]
+pub struct Troop {
+    monkeys: Vec<Monkey>,
+    inspections: Vec<usize>,
+}
+
+impl Troop {
+    /// Create new `Troop` from a list of monkeys.
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        let inspections = vec![0; monkeys.len()];
+        Self {
+            monkeys,
+            inspections,
+        }
+    }
+
+    /// The monkeys of the troop.
+    pub fn monkeys(&self) -> &[Monkey] {
+        &self.monkeys
+    }
+
+    /// Number of inspections per monkey so far.
+    pub fn inspections(&self) -> &[usize] {
+        &self.inspections
+    }
+
+    /// Modulus pool covering all divisibility tests of the troop for the given relief factor.
+    pub fn modulus_pool(&self, relief: u64) -> ModulusPool {
+        ModulusPool::with_relief(self.monkeys.iter().map(|m| m.divisor), relief)
+    }
+
+    /// Simulate a single round. After each inspection, the value is divided by `relief` (pass `1`
+    /// if worry levels are not reduced).
+    pub fn round(&mut self, relief: u64) {
+        let pool = self.modulus_pool(relief);
+        self.round_with_pool(relief, &pool);
+    }
+
+    /// Simulate `n` rounds.
+    pub fn run(&mut self, n: usize, relief: u64) {
+        let pool = self.modulus_pool(relief);
+        for _ in 0..n {
+            self.round_with_pool(relief, &pool);
+        }
+    }
+
+    fn round_with_pool(&mut self, relief: u64, pool: &ModulusPool) {
+        let relief = relief.max(1);
+        for i in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[i].items);
+            self.inspections[i] += items.len();
+
+            for item in items {
+                let monkey = &self.monkeys[i];
+                let value = monkey.operation.apply(pool.reduce(item)) / relief;
+                let target = if value.is_multiple_of(monkey.divisor) {
+                    monkey.if_true
+                } else {
+                    monkey.if_false
+                };
+                self.monkeys[target].items.push(value);
+            }
+        }
+    }
+
+    /// Product of the inspection counts of the two most active monkeys.
+    pub fn monkey_business(&self) -> usize {
+        let mut counts = self.inspections.clone();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts.iter().take(2).product()
+    }
+}
+
+impl FromStr for Troop {
+    type Err = String;
+
+    /// Parses a list of monkey blocks separated by blank lines.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut monkeys: Vec<Monkey> = Vec::new();
+        let mut block = String::new();
+
+        for line in s.lines() {
+            if line.trim().is_empty() {
+                if !block.is_empty() {
+                    monkeys.push(block.parse()?);
+                    block.clear();
+                }
+            } else {
+                block.push_str(line);
+                block.push('\n');
+            }
+        }
+        if !block.is_empty() {
+            monkeys.push(block.parse()?);
+        }
+
+        for monkey in &monkeys {
+            if monkey.if_true >= monkeys.len() || monkey.if_false >= monkeys.len() {
+                return Err(String::from("throw target out of range"));
+            }
+        }
+
+        Ok(Troop::new(monkeys))
+    }
+}
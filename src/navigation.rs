@@ -0,0 +1,174 @@
+//! Interpreter for navigation instructions such as `F10`, `N3` or `R90`.
+//!
+//! In `Mode::Ship`, the cardinal directions move the ship itself and rotations turn its heading.
+//! In `Mode::Waypoint`, they move and rotate a waypoint relative to the ship instead, and `F`
+//! moves the ship towards the waypoint.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::navigation::{Action, Ferry, Mode};
+//!
+//! let actions: Vec<Action> = ["F10", "N3", "F7", "R90", "F11"]
+//!     .iter()
+//!     .map(|a| a.parse().unwrap())
+//!     .collect();
+//!
+//! let mut ferry = Ferry::new(Mode::Ship);
+//! ferry.run(&actions);
+//! assert_eq!(25, ferry.distance_from_start());
+//!
+//! let mut ferry = Ferry::new(Mode::Waypoint);
+//! ferry.run(&actions);
+//! assert_eq!(286, ferry.distance_from_start());
+//! ```
+
+use std::str::FromStr;
+
+use crate::geometry::Point2;
+
+/// A single navigation instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    /// Move north by the given value.
+    North(i64),
+    /// Move south by the given value.
+    South(i64),
+    /// Move east by the given value.
+    East(i64),
+    /// Move west by the given value.
+    West(i64),
+    /// Turn left by the given number of degrees (multiple of 90).
+    Left(i64),
+    /// Turn right by the given number of degrees (multiple of 90).
+    Right(i64),
+    /// Move forward by the given value.
+    Forward(i64),
+}
+
+impl FromStr for Action {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut chars = s.chars();
+        let kind = chars
+            .next()
+            .ok_or_else(|| String::from("empty instruction"))?;
+        let value: i64 = chars
+            .as_str()
+            .parse()
+            .map_err(|_| format!("invalid value in instruction: {}", s))?;
+
+        let action = match kind {
+            'N' => Action::North(value),
+            'S' => Action::South(value),
+            'E' => Action::East(value),
+            'W' => Action::West(value),
+            'L' | 'R' if value % 90 != 0 => {
+                return Err(format!("rotation is not a multiple of 90 degrees: {}", s))
+            }
+            'L' => Action::Left(value),
+            'R' => Action::Right(value),
+            'F' => Action::Forward(value),
+            _ => return Err(format!("invalid instruction: {}", s)),
+        };
+
+        Ok(action)
+    }
+}
+
+/// Which object the cardinal directions and rotations apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Move and turn the ship itself.
+    Ship,
+    /// Move and rotate a waypoint around the ship.
+    Waypoint,
+}
+
+/// A ferry following navigation instructions.
+#[derive(Debug, Clone)]
+pub struct Ferry {
+    position: Point2<i64>,
+    heading: Point2<i64>,
+    mode: Mode,
+}
+
+impl Ferry {
+    /// Create new `Ferry` at the origin. In `Mode::Ship` it faces east, in `Mode::Waypoint` the
+    /// waypoint starts 10 units east and 1 unit north of the ship.
+    pub fn new(mode: Mode) -> Self {
+        let heading = match mode {
+            Mode::Ship => Point2::RIGHT,
+            Mode::Waypoint => Point2::new(10, -1),
+        };
+        Self {
+            position: Point2::new(0, 0),
+            heading,
+            mode,
+        }
+    }
+
+    /// Create new `Ferry` at the origin with a specific heading (`Mode::Ship`) or waypoint
+    /// (`Mode::Waypoint`), relative to the ship.
+    pub fn with_heading(mode: Mode, heading: Point2<i64>) -> Self {
+        Self {
+            position: Point2::new(0, 0),
+            heading,
+            mode,
+        }
+    }
+
+    /// Current position of the ship.
+    pub fn position(&self) -> Point2<i64> {
+        self.position
+    }
+
+    /// Current heading (`Mode::Ship`) or waypoint (`Mode::Waypoint`), relative to the ship.
+    pub fn heading(&self) -> Point2<i64> {
+        self.heading
+    }
+
+    /// Manhattan distance between the ship and its starting position.
+    pub fn distance_from_start(&self) -> u64 {
+        self.position.manhattan(Point2::new(0, 0))
+    }
+
+    /// Execute a single instruction.
+    pub fn apply(&mut self, action: Action) {
+        let offset = match action {
+            Action::North(n) => Point2::new(0, -n),
+            Action::South(n) => Point2::new(0, n),
+            Action::East(n) => Point2::new(n, 0),
+            Action::West(n) => Point2::new(-n, 0),
+            Action::Left(degrees) => {
+                for _ in 0..(degrees / 90).rem_euclid(4) {
+                    self.heading = self.heading.rotate_left();
+                }
+                return;
+            }
+            Action::Right(degrees) => {
+                for _ in 0..(degrees / 90).rem_euclid(4) {
+                    self.heading = self.heading.rotate_right();
+                }
+                return;
+            }
+            Action::Forward(n) => {
+                self.position += self.heading * n;
+                return;
+            }
+        };
+
+        match self.mode {
+            Mode::Ship => self.position += offset,
+            Mode::Waypoint => self.heading += offset,
+        }
+    }
+
+    /// Execute a list of instructions.
+    pub fn run(&mut self, actions: &[Action]) {
+        for &action in actions {
+            self.apply(action);
+        }
+    }
+}
@@ -0,0 +1,505 @@
+//! Decoding of capital letters rendered as ASCII art (`#` for lit pixels).
+//!
+//! There are two fonts: a small one of 6 rows, where glyphs are separated by one blank column,
+//! and a large one of 10 rows, where they are separated by two. Only the letters that appear in
+//! puzzle outputs are known.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::ocr;
+//!
+//! let rendered = [
+//!     "#....#..######",
+//!     "#....#..#.....",
+//!     "#....#..#.....",
+//!     "#....#..#.....",
+//!     "######..#####.",
+//!     "#....#..#.....",
+//!     "#....#..#.....",
+//!     "#....#..#.....",
+//!     "#....#..#.....",
+//!     "#....#..#.....",
+//! ]
+//! .join("\n");
+//!
+//! assert_eq!(Some(String::from("HF")), ocr::decode(&rendered));
+//! ```
+
+use crate::grid::Grid;
+
+/// A fixed-width font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Font {
+    /// Width of a glyph.
+    pub width: usize,
+    /// Height of a glyph.
+    pub height: usize,
+    /// Number of blank columns between two glyphs.
+    pub spacing: usize,
+    glyphs: &'static [(char, &'static [&'static str])],
+}
+
+/// The small font, e.g. used by "Two-Factor Authentication". Glyphs are 4 pixels wide (`Y`
+/// 5), followed by a blank column; they are stored 5 wide including that column.
+#[rustfmt::skip]
+pub const FONT_4X6: Font = Font {
+    width: 5,
+    height: 6,
+    spacing: 0,
+    glyphs: &[
+        ('A', &[
+            ".##..",
+            "#..#.",
+            "#..#.",
+            "####.",
+            "#..#.",
+            "#..#.",
+        ]),
+        ('B', &[
+            "###..",
+            "#..#.",
+            "###..",
+            "#..#.",
+            "#..#.",
+            "###..",
+        ]),
+        ('C', &[
+            ".##..",
+            "#..#.",
+            "#....",
+            "#....",
+            "#..#.",
+            ".##..",
+        ]),
+        ('E', &[
+            "####.",
+            "#....",
+            "###..",
+            "#....",
+            "#....",
+            "####.",
+        ]),
+        ('F', &[
+            "####.",
+            "#....",
+            "###..",
+            "#....",
+            "#....",
+            "#....",
+        ]),
+        ('G', &[
+            ".##..",
+            "#..#.",
+            "#....",
+            "#.##.",
+            "#..#.",
+            ".###.",
+        ]),
+        ('H', &[
+            "#..#.",
+            "#..#.",
+            "####.",
+            "#..#.",
+            "#..#.",
+            "#..#.",
+        ]),
+        ('I', &[
+            ".###.",
+            "..#..",
+            "..#..",
+            "..#..",
+            "..#..",
+            ".###.",
+        ]),
+        ('J', &[
+            "..##.",
+            "...#.",
+            "...#.",
+            "...#.",
+            "#..#.",
+            ".##..",
+        ]),
+        ('K', &[
+            "#..#.",
+            "#.#..",
+            "##...",
+            "#.#..",
+            "#.#..",
+            "#..#.",
+        ]),
+        ('L', &[
+            "#....",
+            "#....",
+            "#....",
+            "#....",
+            "#....",
+            "####.",
+        ]),
+        ('O', &[
+            ".##..",
+            "#..#.",
+            "#..#.",
+            "#..#.",
+            "#..#.",
+            ".##..",
+        ]),
+        ('P', &[
+            "###..",
+            "#..#.",
+            "#..#.",
+            "###..",
+            "#....",
+            "#....",
+        ]),
+        ('R', &[
+            "###..",
+            "#..#.",
+            "#..#.",
+            "###..",
+            "#.#..",
+            "#..#.",
+        ]),
+        ('S', &[
+            ".###.",
+            "#....",
+            "#....",
+            ".##..",
+            "...#.",
+            "###..",
+        ]),
+        ('U', &[
+            "#..#.",
+            "#..#.",
+            "#..#.",
+            "#..#.",
+            "#..#.",
+            ".##..",
+        ]),
+        ('Y', &[
+            "#...#",
+            "#...#",
+            ".#.#.",
+            "..#..",
+            "..#..",
+            "..#..",
+        ]),
+        ('Z', &[
+            "####.",
+            "...#.",
+            "..#..",
+            ".#...",
+            "#....",
+            "####.",
+        ]),
+    ],
+};
+
+/// All known fonts.
+pub const FONTS: [Font; 2] = [FONT_4X6, FONT_6X10];
+
+/// The 6x10 font, e.g. used by "The Stars Align".
+#[rustfmt::skip]
+pub const FONT_6X10: Font = Font {
+    width: 6,
+    height: 10,
+    spacing: 2,
+    glyphs: &[
+        ('A', &[
+            "..##..",
+            ".#..#.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "######",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+        ]),
+        ('B', &[
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+        ]),
+        ('C', &[
+            ".####.",
+            "#....#",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#....#",
+            ".####.",
+        ]),
+        ('E', &[
+            "######",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "######",
+        ]),
+        ('F', &[
+            "######",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+        ]),
+        ('G', &[
+            ".####.",
+            "#....#",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#..###",
+            "#....#",
+            "#....#",
+            "#...##",
+            ".###.#",
+        ]),
+        ('H', &[
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "######",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#....#",
+        ]),
+        ('J', &[
+            "...###",
+            "....#.",
+            "....#.",
+            "....#.",
+            "....#.",
+            "....#.",
+            "....#.",
+            "#...#.",
+            "#...#.",
+            ".###..",
+        ]),
+        ('K', &[
+            "#....#",
+            "#...#.",
+            "#..#..",
+            "#.#...",
+            "##....",
+            "##....",
+            "#.#...",
+            "#..#..",
+            "#...#.",
+            "#....#",
+        ]),
+        ('L', &[
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "######",
+        ]),
+        ('N', &[
+            "#....#",
+            "##...#",
+            "##...#",
+            "#.#..#",
+            "#.#..#",
+            "#..#.#",
+            "#..#.#",
+            "#...##",
+            "#...##",
+            "#....#",
+        ]),
+        ('P', &[
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+            "#.....",
+        ]),
+        ('R', &[
+            "#####.",
+            "#....#",
+            "#....#",
+            "#....#",
+            "#####.",
+            "#..#..",
+            "#...#.",
+            "#...#.",
+            "#....#",
+            "#....#",
+        ]),
+        ('X', &[
+            "#....#",
+            "#....#",
+            ".#..#.",
+            ".#..#.",
+            "..##..",
+            "..##..",
+            ".#..#.",
+            ".#..#.",
+            "#....#",
+            "#....#",
+        ]),
+        ('Z', &[
+            "######",
+            ".....#",
+            ".....#",
+            "....#.",
+            "...#..",
+            "..#...",
+            ".#....",
+            "#.....",
+            "#.....",
+            "######",
+        ]),
+    ],
+};
+
+impl Font {
+    /// Look up the letter matching a glyph, given row by row.
+    fn letter(&self, pixels: &[bool]) -> Option<char> {
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| {
+                glyph
+                    .iter()
+                    .flat_map(|row| row.chars())
+                    .map(|g| g == '#')
+                    .eq(pixels.iter().copied())
+            })
+            .map(|&(letter, _)| letter)
+    }
+
+    /// Render `text` in this font, using `#` for lit and `.` for unlit pixels. Returns `None` if
+    /// a letter is not part of the font.
+    pub fn render(&self, text: &str) -> Option<String> {
+        let glyphs: Vec<&[&str]> = text
+            .chars()
+            .map(|c| self.glyphs.iter().find(|g| g.0 == c).map(|g| g.1))
+            .collect::<Option<_>>()?;
+        let spacing = ".".repeat(self.spacing);
+
+        let rows: Vec<String> = (0..self.height)
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|g| g[row])
+                    .collect::<Vec<_>>()
+                    .join(&spacing)
+            })
+            .collect();
+        Some(rows.join("\n"))
+    }
+
+    /// Decode rows of pixels in this font. Glyphs are expected to start at the first column.
+    ///
+    /// Returns `None` if the number of rows doesn't match the font or a glyph is unknown.
+    pub fn decode(&self, rows: &[Vec<bool>]) -> Option<String> {
+        if rows.len() != self.height {
+            return None;
+        }
+        // Trailing blank columns don't start another glyph.
+        let width = rows
+            .iter()
+            .filter_map(|r| r.iter().rposition(|&p| p))
+            .max()
+            .map_or(0, |last| last + 1);
+        let pixel = |row: usize, col: usize| rows[row].get(col).copied().unwrap_or(false);
+
+        let mut letters = String::new();
+        let mut col = 0;
+        while col < width {
+            let pixels: Vec<bool> = (0..self.height)
+                .flat_map(|row| (col..col + self.width).map(move |c| (row, c)))
+                .map(|(row, c)| pixel(row, c))
+                .collect();
+            letters.push(self.letter(&pixels)?);
+            col += self.width + self.spacing;
+        }
+
+        Some(letters)
+    }
+}
+
+/// Decode a rendered message in any known font. Blank rows and columns around the message are
+/// ignored; any character other than `#` is an unlit pixel.
+pub fn decode(rendered: &str) -> Option<String> {
+    decode_rows(
+        rendered
+            .lines()
+            .map(|l| l.chars().map(|c| c == '#').collect())
+            .collect(),
+    )
+}
+
+/// Decode a message of lit pixels in any known font, like `decode()`.
+pub fn decode_grid(grid: &Grid<bool>) -> Option<String> {
+    decode_rows(grid.rows().map(<[bool]>::to_vec).collect())
+}
+
+fn decode_rows(mut rows: Vec<Vec<bool>>) -> Option<String> {
+    let lit = |r: &Vec<bool>| r.iter().any(|&p| p);
+    let first = rows.iter().position(lit)?;
+    let last = rows.iter().rposition(lit)?;
+    rows.truncate(last + 1);
+    rows.drain(..first);
+
+    let left = rows
+        .iter()
+        .filter_map(|r| r.iter().position(|&p| p))
+        .min()?;
+    for row in &mut rows {
+        row.drain(..left.min(row.len()));
+    }
+
+    // Some glyphs (like `I` of the small font) start with a blank column, which has just been
+    // trimmed if the message starts with one.
+    FONTS
+        .iter()
+        .filter(|font| font.height == rows.len())
+        .find_map(|font| {
+            (0..font.width).find_map(|shift| {
+                let shifted: Vec<Vec<bool>> = rows
+                    .iter()
+                    .map(|r| {
+                        std::iter::repeat_n(false, shift)
+                            .chain(r.iter().copied())
+                            .collect()
+                    })
+                    .collect();
+                font.decode(&shifted)
+            })
+        })
+}
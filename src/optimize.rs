@@ -0,0 +1,124 @@
+//! Randomized search helpers with deterministic seeding.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::optimize::{random_restart, Rng};
+//!
+//! // Find the smallest value a noisy search produces within 20 restarts.
+//! let best = random_restart(|rng: &mut Rng| Some(rng.below(1000)), 7, 20);
+//!
+//! assert!(best.unwrap() < 1000);
+//! ```
+
+use std::ops::RangeInclusive;
+
+/// Small deterministic pseudo-random number generator (xorshift64).
+///
+/// Not suitable for anything but search heuristics and test data, but reproducible across
+/// platforms and runs. The fuzzing helpers of `testing` use the same generator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Create new `Rng` from a seed. Every seed (including `0`) yields a valid generator.
+    pub fn new(seed: u64) -> Self {
+        // Scramble the seed (splitmix64) so that similar seeds yield unrelated sequences.
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+
+        Self {
+            state: if z == 0 { 0x9E37_79B9_7F4A_7C15 } else { z },
+        }
+    }
+
+    /// Next pseudo-random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Pseudo-random number in `0..n`.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "upper bound must be positive");
+        self.next_u64() % n
+    }
+
+    /// Pseudo-random number in the (inclusive) `range`.
+    ///
+    /// # Panics
+    /// Panics if the range is empty.
+    pub fn int_in(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (lo, hi) = (*range.start(), *range.end());
+        assert!(lo <= hi, "range must not be empty");
+        let span = (i128::from(hi) - i128::from(lo) + 1) as u128;
+        if span > u128::from(u64::MAX) {
+            return self.next_u64() as i64;
+        }
+        (i128::from(lo) + i128::from(self.below(span as u64))) as i64
+    }
+
+    /// Pseudo-random boolean that is `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        (self.next_u64() as f64 / u64::MAX as f64) < p
+    }
+
+    /// Pseudo-random element of `items`.
+    ///
+    /// # Panics
+    /// Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Shuffle a slice in place (Fisher–Yates).
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Run `search` once and then restart it up to `max_restarts` times, returning the best (i.e.
+/// smallest) result of all attempts. Attempts returning `None` are considered failed.
+///
+/// All attempts draw from a single generator seeded with `seed`, so the outcome is reproducible.
+/// To maximize instead, return `std::cmp::Reverse(value)` from `search`.
+pub fn random_restart<T, F>(mut search: F, seed: u64, max_restarts: usize) -> Option<T>
+where
+    T: Ord,
+    F: FnMut(&mut Rng) -> Option<T>,
+{
+    let mut rng = Rng::new(seed);
+    let mut best = None;
+
+    for _ in 0..=max_restarts {
+        if let Some(result) = search(&mut rng) {
+            best = match best {
+                Some(b) if b <= result => Some(b),
+                _ => Some(result),
+            };
+        }
+    }
+
+    best
+}
+
+/// Run `search` once and then restart it up to `max_restarts` times, returning the result of
+/// the first successful attempt.
+pub fn first_success<T, F>(mut search: F, seed: u64, max_restarts: usize) -> Option<T>
+where
+    F: FnMut(&mut Rng) -> Option<T>,
+{
+    let mut rng = Rng::new(seed);
+    (0..=max_restarts).find_map(|_| search(&mut rng))
+}
@@ -0,0 +1,113 @@
+//! Consistent formatting of puzzle answers, with optional copying to the system clipboard.
+//!
+//! Clipboard support requires the `clipboard` feature. It uses the platform's clipboard tool
+//! (`pbcopy` on macOS, `clip` on Windows, `wl-copy`, `xclip` or `xsel` elsewhere), so no
+//! additional dependencies are needed, but one of these tools has to be installed.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::output;
+//! use std::time::Duration;
+//!
+//! let (answer, duration) = output::timed(|| (1..=100).sum::<u32>());
+//! output::print_answer(1, answer, duration);
+//!
+//! assert_eq!(
+//!     "Part 2: 5050 (1.50ms)",
+//!     output::format_answer(2, 5050, Duration::from_micros(1500))
+//! );
+//! ```
+
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Run `f` and measure how long it takes.
+pub fn timed<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+/// Format a duration with a unit that fits its magnitude, e.g. `850ns`, `12.3µs`, `1.50ms` or
+/// `2.01s`.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
+
+/// Format the answer of a puzzle part together with the time it took, e.g.
+/// `Part 1: 42 (1.50ms)`.
+pub fn format_answer<A: Display>(part: u8, answer: A, duration: Duration) -> String {
+    format!("Part {}: {} ({})", part, answer, format_duration(duration))
+}
+
+/// Print the answer of a puzzle part together with the time it took.
+pub fn print_answer<A: Display>(part: u8, answer: A, duration: Duration) {
+    println!("{}", format_answer(part, answer, duration));
+}
+
+/// Copy `text` to the system clipboard.
+///
+/// # Failures
+/// Returns an error if none of the supported clipboard tools can be run successfully.
+#[cfg(feature = "clipboard")]
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use std::io::{self, Write};
+    use std::process::{Command, Stdio};
+
+    let tools: &[(&str, &[&str])] = if cfg!(target_os = "macos") {
+        &[("pbcopy", &[])]
+    } else if cfg!(windows) {
+        &[("clip", &[])]
+    } else {
+        &[
+            ("wl-copy", &[]),
+            ("xclip", &["-selection", "clipboard"]),
+            ("xsel", &["--clipboard", "--input"]),
+        ]
+    };
+
+    for &(tool, args) in tools {
+        let mut child = match Command::new(tool)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+        {
+            Ok(child) => child,
+            Err(_) => continue,
+        };
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(text.as_bytes())?;
+        }
+        if child.wait()?.success() {
+            return Ok(());
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::NotFound,
+        "no working clipboard tool found",
+    ))
+}
+
+/// Print the answer of a puzzle part and copy the answer (without part and duration) to the
+/// system clipboard.
+///
+/// # Failures
+/// Returns an error if the answer cannot be copied. It is printed in any case.
+#[cfg(feature = "clipboard")]
+pub fn print_and_copy<A: Display>(part: u8, answer: A, duration: Duration) -> std::io::Result<()> {
+    let answer = answer.to_string();
+    print_answer(part, &answer, duration);
+    copy_to_clipboard(&answer)
+}
@@ -0,0 +1,151 @@
+//! Particle swarms of the form `p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>` (2017 day 20).
+//!
+//! # Examples
+//! ```
+//! use aoc_util::particles::{closest_long_term, Particle};
+//!
+//! let particles: Vec<Particle> = ["p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>", "p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>"]
+//!     .iter()
+//!     .map(|p| p.parse().unwrap())
+//!     .collect();
+//!
+//! assert_eq!(Some(0), closest_long_term(&particles));
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// A particle with position, velocity and acceleration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Particle {
+    /// Position
+    pub p: [i64; 3],
+    /// Velocity
+    pub v: [i64; 3],
+    /// Acceleration
+    pub a: [i64; 3],
+}
+
+fn manhattan(x: &[i64; 3]) -> u64 {
+    x.iter().map(|c| c.unsigned_abs()).sum()
+}
+
+impl Particle {
+    /// Advance by one tick: the velocity increases by the acceleration, then the position by the
+    /// velocity.
+    pub fn tick(&mut self) {
+        for i in 0..3 {
+            self.v[i] += self.a[i];
+            self.p[i] += self.v[i];
+        }
+    }
+
+    /// Manhattan distance from the origin.
+    pub fn distance(&self) -> u64 {
+        manhattan(&self.p)
+    }
+
+    /// Returns `true` if no coordinate of the particle will ever move towards the origin again,
+    /// i.e. the particle's distance from the origin only grows from now on.
+    fn settled(&self) -> bool {
+        (0..3).all(|i| {
+            let (p, v, a) = (self.p[i], self.v[i], self.a[i]);
+            (a == 0 || v.signum() == a.signum()) && (v == 0 || p == 0 || p.signum() == v.signum())
+        })
+    }
+}
+
+fn parse_vector(s: &str) -> Option<[i64; 3]> {
+    let inner = s.trim().get(2..)?.strip_prefix('<')?.strip_suffix('>')?;
+    let mut values = inner.split(',').map(|v| v.trim().parse().ok());
+    let vector = [values.next()??, values.next()??, values.next()??];
+    match values.next() {
+        None => Some(vector),
+        Some(_) => None,
+    }
+}
+
+impl FromStr for Particle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid particle: {}", s);
+        let parts: Vec<&str> = s.split(">,").collect();
+        if parts.len() != 3 {
+            return Err(invalid());
+        }
+
+        let component = |idx: usize, prefix: &str| {
+            let part = parts[idx].trim();
+            let part = if idx < 2 {
+                format!("{}>", part)
+            } else {
+                part.to_string()
+            };
+            if !part.starts_with(prefix) {
+                return Err(invalid());
+            }
+            parse_vector(&part).ok_or_else(invalid)
+        };
+
+        Ok(Particle {
+            p: component(0, "p=")?,
+            v: component(1, "v=")?,
+            a: component(2, "a=")?,
+        })
+    }
+}
+
+/// Index of the particle that will stay closest to the origin in the long term.
+///
+/// The particle with the smallest acceleration wins. Ties are resolved by simulating the tied
+/// particles until they have all turned towards their final direction and then comparing
+/// velocities and positions.
+pub fn closest_long_term(particles: &[Particle]) -> Option<usize> {
+    let min_acceleration = particles.iter().map(|p| manhattan(&p.a)).min()?;
+    let mut candidates: Vec<(usize, Particle)> = particles
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, p)| manhattan(&p.a) == min_acceleration)
+        .collect();
+
+    while !candidates.iter().all(|(_, p)| p.settled()) {
+        for (_, particle) in candidates.iter_mut() {
+            particle.tick();
+        }
+    }
+
+    candidates
+        .iter()
+        .min_by_key(|(idx, p)| (manhattan(&p.v), p.distance(), *idx))
+        .map(|&(idx, _)| idx)
+}
+
+/// Advance all particles by one tick and remove all particles that collide (share a position)
+/// afterwards. Returns the number of removed particles.
+pub fn tick_with_collisions(particles: &mut Vec<Particle>) -> usize {
+    let mut positions: HashMap<[i64; 3], usize> = HashMap::new();
+    for particle in particles.iter_mut() {
+        particle.tick();
+        *positions.entry(particle.p).or_insert(0) += 1;
+    }
+
+    let before = particles.len();
+    particles.retain(|p| positions[&p.p] == 1);
+    before - particles.len()
+}
+
+/// Simulate with collisions until no collision happened for `quiet_ticks` consecutive ticks.
+/// Returns the remaining particles.
+pub fn resolve_collisions(mut particles: Vec<Particle>, quiet_ticks: usize) -> Vec<Particle> {
+    let mut quiet = 0;
+    while quiet < quiet_ticks {
+        if tick_with_collisions(&mut particles) > 0 {
+            quiet = 0;
+        } else {
+            quiet += 1;
+        }
+    }
+    particles
+}
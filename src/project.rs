@@ -0,0 +1,126 @@
+//! Locating inputs in a (multi-year) Cargo project, independent of the current working directory.
+//!
+//! The project root is the closest ancestor directory whose `Cargo.toml` declares a
+//! `[workspace]`, or else the closest directory containing a `Cargo.toml` at all. Inputs are
+//! expected at `<root>/<inputs dir>/<year>/day<DD>.txt`, where the inputs directory defaults to
+//! `inputs` and can be overridden with the `AOC_INPUTS_DIR` environment variable or
+//! `Project::inputs_dir()`. The known correct answers of a puzzle, if any, are kept next to its
+//! input in `day<DD>.answers.txt`, one line per part.
+//!
+//! # Examples
+//! ```no_run
+//! use aoc_util::input::{FileReader, FromFile};
+//! use aoc_util::project;
+//!
+//! let path = project::input_path(2020, 1).unwrap();
+//! let expenses: Vec<u32> = FileReader::new()
+//!     .split_lines()
+//!     .read_from_file(path)
+//!     .unwrap();
+//! ```
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the inputs directory (absolute or relative to the root).
+pub const INPUTS_DIR_VAR: &str = "AOC_INPUTS_DIR";
+
+/// Default inputs directory, relative to the project root.
+pub const DEFAULT_INPUTS_DIR: &str = "inputs";
+
+/// A located project and its inputs directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    root: PathBuf,
+    inputs_dir: PathBuf,
+}
+
+impl Project {
+    /// Locate the project containing the current working directory.
+    ///
+    /// # Failures
+    /// Returns an error if the current directory cannot be determined or isn't inside a Cargo
+    /// project.
+    pub fn locate() -> io::Result<Self> {
+        Self::from_dir(env::current_dir()?)
+    }
+
+    /// Locate the project containing `dir`. The inputs directory is taken from
+    /// `AOC_INPUTS_DIR` if set.
+    ///
+    /// # Failures
+    /// Returns an error if no ancestor of `dir` contains a `Cargo.toml`.
+    pub fn from_dir<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        let root = find_root(dir).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no Cargo project found at {}", dir.display()),
+            )
+        })?;
+        let inputs_dir = env::var_os(INPUTS_DIR_VAR)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUTS_DIR));
+
+        Ok(Self {
+            inputs_dir: root.join(inputs_dir),
+            root,
+        })
+    }
+
+    /// Use `dir` as inputs directory. Relative paths are resolved against the project root.
+    pub fn inputs_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.inputs_dir = self.root.join(dir);
+        self
+    }
+
+    /// The project root.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The path of the input for the puzzle of the given `year` and `day`.
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.inputs_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// The path of the file with the correct answers for the puzzle of the given `year` and
+    /// `day`.
+    pub fn answers_path(&self, year: u32, day: u32) -> PathBuf {
+        self.inputs_dir
+            .join(year.to_string())
+            .join(format!("day{:02}.answers.txt", day))
+    }
+}
+
+/// The path of the input for the puzzle of the given `year` and `day` in the project containing
+/// the current working directory.
+///
+/// # Failures
+/// Returns an error if the current directory isn't inside a Cargo project.
+pub fn input_path(year: u32, day: u32) -> io::Result<PathBuf> {
+    Ok(Project::locate()?.input_path(year, day))
+}
+
+/// The closest ancestor of `dir` (including itself) that is a workspace root, or else the
+/// closest one containing a `Cargo.toml`.
+fn find_root(dir: &Path) -> Option<PathBuf> {
+    let dir = dir.canonicalize().ok()?;
+    let mut package = None;
+
+    for ancestor in dir.ancestors() {
+        let manifest = ancestor.join("Cargo.toml");
+        if let Ok(content) = fs::read_to_string(&manifest) {
+            if content.lines().any(|line| line.trim() == "[workspace]") {
+                return Some(ancestor.to_path_buf());
+            }
+            package.get_or_insert_with(|| ancestor.to_path_buf());
+        }
+    }
+
+    package
+}
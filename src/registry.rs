@@ -0,0 +1,186 @@
+//! Solutions as trait objects with metadata, so that tooling can discover them.
+//!
+//! Every solution implements `Solver`, usually on a unit struct. `register_solvers!` collects
+//! the solvers of a crate into a `registry()` function at the place where it is invoked; the
+//! registries of several crates of a workspace can then be merged into one.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::answer::Answer;
+//! use aoc_util::registry::Solver;
+//!
+//! struct Day01;
+//!
+//! impl Solver for Day01 {
+//!     fn year(&self) -> u32 {
+//!         2020
+//!     }
+//!
+//!     fn day(&self) -> u32 {
+//!         1
+//!     }
+//!
+//!     fn name(&self) -> &str {
+//!         "Report Repair"
+//!     }
+//!
+//!     fn part1(&self, input: &str) -> Answer {
+//!         input.lines().count().into()
+//!     }
+//!
+//!     fn part2(&self, input: &str) -> Answer {
+//!         input.len().into()
+//!     }
+//! }
+//!
+//! aoc_util::register_solvers!(Day01);
+//!
+//! let solver = registry().get(2020, 1).unwrap();
+//! assert_eq!("Report Repair", solver.name());
+//! assert_eq!(Answer::Integer(2), solver.part1("1721\n979\n"));
+//! ```
+
+use std::fmt;
+
+use crate::answer::Answer;
+
+/// A solution of both parts of a puzzle.
+pub trait Solver: Sync {
+    /// Year of the puzzle.
+    fn year(&self) -> u32;
+
+    /// Day of the puzzle, starting at 1.
+    fn day(&self) -> u32;
+
+    /// Title of the puzzle.
+    fn name(&self) -> &str;
+
+    /// Solve part 1.
+    fn part1(&self, input: &str) -> Answer;
+
+    /// Solve part 2.
+    fn part2(&self, input: &str) -> Answer;
+}
+
+impl fmt::Debug for dyn Solver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Solver")
+            .field("year", &self.year())
+            .field("day", &self.day())
+            .field("name", &self.name())
+            .finish()
+    }
+}
+
+/// A collection of solvers, ordered by year and day, with at most one solver per day.
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    solvers: Vec<&'static dyn Solver>,
+}
+
+impl Registry {
+    /// Create new empty `Registry`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create new `Registry` from a list of solvers.
+    ///
+    /// # Failures
+    /// Returns an error if two solvers are for the same day.
+    pub fn from_solvers<I: IntoIterator<Item = &'static dyn Solver>>(
+        solvers: I,
+    ) -> Result<Self, String> {
+        let mut registry = Self::new();
+        for solver in solvers {
+            registry.register(solver)?;
+        }
+        Ok(registry)
+    }
+
+    /// Add a solver.
+    ///
+    /// # Failures
+    /// Returns an error if there already is a solver for the same day.
+    pub fn register(&mut self, solver: &'static dyn Solver) -> Result<(), String> {
+        let key = (solver.year(), solver.day());
+        match self
+            .solvers
+            .binary_search_by_key(&key, |s| (s.year(), s.day()))
+        {
+            Ok(_) => Err(format!("duplicate solver: {} day {}", key.0, key.1)),
+            Err(idx) => {
+                self.solvers.insert(idx, solver);
+                Ok(())
+            }
+        }
+    }
+
+    /// Add all solvers of `other`, e.g. the registry of another crate of the workspace.
+    ///
+    /// # Failures
+    /// Returns an error if both registries have a solver for the same day.
+    pub fn merge(&mut self, other: &Registry) -> Result<(), String> {
+        for &solver in &other.solvers {
+            self.register(solver)?;
+        }
+        Ok(())
+    }
+
+    /// Number of solvers.
+    pub fn len(&self) -> usize {
+        self.solvers.len()
+    }
+
+    /// Returns `true` if there are no solvers.
+    pub fn is_empty(&self) -> bool {
+        self.solvers.is_empty()
+    }
+
+    /// The solver for the given day.
+    pub fn get(&self, year: u32, day: u32) -> Option<&'static dyn Solver> {
+        self.solvers
+            .binary_search_by_key(&(year, day), |s| (s.year(), s.day()))
+            .ok()
+            .map(|idx| self.solvers[idx])
+    }
+
+    /// All solvers, ordered by year and day.
+    pub fn iter(&self) -> impl Iterator<Item = &'static dyn Solver> + '_ {
+        self.solvers.iter().copied()
+    }
+
+    /// All solvers of one year, ordered by day.
+    pub fn year(&self, year: u32) -> impl Iterator<Item = &'static dyn Solver> + '_ {
+        self.iter().filter(move |s| s.year() == year)
+    }
+
+    /// All years with at least one solver, in ascending order.
+    pub fn years(&self) -> Vec<u32> {
+        let mut years: Vec<u32> = self.iter().map(|s| s.year()).collect();
+        years.dedup();
+        years
+    }
+}
+
+/// Generate a function `registry() -> &'static Registry` returning all given solvers (constant
+/// expressions of types implementing `Solver`, usually unit structs). Other crates can call this
+/// function and merge the registries.
+///
+/// # Panics
+/// The generated function panics if two solvers are for the same day.
+#[macro_export]
+macro_rules! register_solvers {
+    ($($solver:expr),* $(,)?) => {
+        /// All registered solvers of this crate.
+        pub fn registry() -> &'static $crate::registry::Registry {
+            static SOLVERS: &[&dyn $crate::registry::Solver] = &[$(&$solver),*];
+            static REGISTRY: ::std::sync::OnceLock<$crate::registry::Registry> =
+                ::std::sync::OnceLock::new();
+            REGISTRY.get_or_init(|| {
+                $crate::registry::Registry::from_solvers(SOLVERS.iter().copied())
+                    .unwrap_or_else(|e| panic!("{}", e))
+            })
+        }
+    };
+}
@@ -0,0 +1,90 @@
+//! Reindeer that alternate between flying and resting (2015 day 14).
+//!
+//! # Examples
+//! ```
+//! use aoc_util::reindeer::Reindeer;
+//!
+//! let comet: Reindeer = "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 \
+//!     seconds."
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(1120, comet.distance(1000));
+//! ```
+
+use std::str::FromStr;
+
+/// A reindeer flying at `speed` for `fly` seconds, then resting for `rest` seconds, repeatedly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Reindeer {
+    /// Name of the reindeer.
+    pub name: String,
+    /// Distance per second while flying.
+    pub speed: u64,
+    /// Seconds of flying per cycle.
+    pub fly: u64,
+    /// Seconds of resting per cycle.
+    pub rest: u64,
+}
+
+impl Reindeer {
+    /// Distance traveled after `seconds`, computed from the number of complete cycles.
+    pub fn distance(&self, seconds: u64) -> u64 {
+        let cycle = self.fly + self.rest;
+        if cycle == 0 {
+            return 0;
+        }
+        let flying = seconds / cycle * self.fly + (seconds % cycle).min(self.fly);
+        self.speed * flying
+    }
+}
+
+impl FromStr for Reindeer {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid reindeer: {}", s);
+        let (name, rest) = s.split_once(" can fly ").ok_or_else(invalid)?;
+        let (speed, rest) = rest.split_once(" km/s for ").ok_or_else(invalid)?;
+        let (fly, rest) = rest
+            .split_once(" seconds, but then must rest for ")
+            .ok_or_else(invalid)?;
+        let rest = rest
+            .trim_end()
+            .strip_suffix(" seconds.")
+            .ok_or_else(invalid)?;
+
+        Ok(Reindeer {
+            name: name.trim().to_string(),
+            speed: speed.parse().map_err(|_| invalid())?,
+            fly: fly.parse().map_err(|_| invalid())?,
+            rest: rest.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// The distance of the reindeer furthest ahead after `seconds`.
+pub fn winning_distance(reindeer: &[Reindeer], seconds: u64) -> Option<u64> {
+    reindeer.iter().map(|r| r.distance(seconds)).max()
+}
+
+/// Points of every reindeer after `seconds`, where each second every reindeer in the lead (after
+/// that second) gets one point.
+pub fn leader_points(reindeer: &[Reindeer], seconds: u64) -> Vec<u64> {
+    let mut points = vec![0; reindeer.len()];
+    for second in 1..=seconds {
+        let distances: Vec<u64> = reindeer.iter().map(|r| r.distance(second)).collect();
+        let lead = distances.iter().copied().max().unwrap_or(0);
+        for (p, &d) in points.iter_mut().zip(&distances) {
+            if d == lead {
+                *p += 1;
+            }
+        }
+    }
+    points
+}
+
+/// The points of the winning reindeer after `seconds`, see `leader_points()`.
+pub fn winning_points(reindeer: &[Reindeer], seconds: u64) -> Option<u64> {
+    leader_points(reindeer, seconds).into_iter().max()
+}
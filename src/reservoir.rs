@@ -0,0 +1,183 @@
+//! Water falling from a spring and spreading over clay ("Reservoir Research").
+//!
+//! Water falls down through sand until it hits clay or settled water, then spreads sideways. A
+//! level that is bounded by clay on both sides settles, and the water above it spreads again.
+//! Water that reaches an edge falls further down; water below the lowest clay is lost.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::geometry::Point2;
+//! use aoc_util::reservoir::Reservoir;
+//!
+//! let input = "x=495, y=2..7\ny=7, x=495..501\nx=501, y=3..7\nx=498, y=2..4\n\
+//!              x=506, y=1..2\nx=498, y=10..13\nx=504, y=10..13\ny=13, x=498..504";
+//! let mut reservoir: Reservoir = input.parse().unwrap();
+//! reservoir.fill(Point2::new(500, 0));
+//!
+//! assert_eq!(57, reservoir.reached());
+//! assert_eq!(29, reservoir.retained());
+//! ```
+
+use std::str::FromStr;
+
+use crate::geometry::Point2;
+use crate::grid::SparseGrid;
+
+/// Content of a non-sand tile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    /// Clay
+    Clay,
+    /// Water that passed through, but doesn't stay
+    Flowing,
+    /// Water at rest
+    Settled,
+}
+
+/// Underground slice of clay and water.
+#[derive(Debug, Clone)]
+pub struct Reservoir {
+    tiles: SparseGrid<Tile>,
+    min_y: i64,
+    max_y: i64,
+}
+
+impl Reservoir {
+    /// Create new `Reservoir` from the positions of all clay tiles.
+    pub fn new<I: IntoIterator<Item = Point2<i64>>>(clay: I) -> Self {
+        let tiles: SparseGrid<Tile> = clay.into_iter().map(|p| (p, Tile::Clay)).collect();
+        let (min_y, max_y) = match tiles.bounds() {
+            Some((min, max)) => (min.y, max.y),
+            None => (0, -1),
+        };
+
+        Self {
+            tiles,
+            min_y,
+            max_y,
+        }
+    }
+
+    /// All non-sand tiles.
+    pub fn tiles(&self) -> &SparseGrid<Tile> {
+        &self.tiles
+    }
+
+    /// Let water flow from a spring at `spring` until nothing changes anymore.
+    pub fn fill(&mut self, spring: Point2<i64>) {
+        self.drop(spring);
+    }
+
+    /// Number of tiles within the vertical range of the clay that were reached by water.
+    pub fn reached(&self) -> usize {
+        self.count_in_range(|t| t != Tile::Clay)
+    }
+
+    /// Number of tiles within the vertical range of the clay with settled water.
+    pub fn retained(&self) -> usize {
+        self.count_in_range(|t| t == Tile::Settled)
+    }
+
+    fn count_in_range<P: Fn(Tile) -> bool>(&self, predicate: P) -> usize {
+        self.tiles
+            .iter()
+            .filter(|&(p, &t)| p.y >= self.min_y && p.y <= self.max_y && predicate(t))
+            .count()
+    }
+
+    fn is_solid(&self, pos: Point2<i64>) -> bool {
+        matches!(self.tiles.get(pos), Some(Tile::Clay) | Some(Tile::Settled))
+    }
+
+    /// Let water fall from `start`. Returns `true` if the water settled at `start`.
+    fn drop(&mut self, start: Point2<i64>) -> bool {
+        // Falling is iterative; recursion only happens for every edge that water falls off.
+        let mut path = Vec::new();
+        let mut pos = start;
+        loop {
+            if pos.y > self.max_y {
+                return false;
+            }
+            self.tiles.insert(pos, Tile::Flowing);
+            path.push(pos);
+
+            let below = pos + Point2::DOWN;
+            match self.tiles.get(below) {
+                None => pos = below,
+                Some(Tile::Flowing) => return false,
+                Some(_) => break,
+            }
+        }
+
+        while let Some(level) = path.pop() {
+            let (left, left_bounded) = self.spread(level, -1);
+            let (right, right_bounded) = self.spread(level, 1);
+            if !(left_bounded && right_bounded) {
+                return false;
+            }
+            for x in left..=right {
+                self.tiles.insert(Point2::new(x, level.y), Tile::Settled);
+            }
+        }
+
+        true
+    }
+
+    /// Spread water sideways from `pos` in direction `dx`. Returns the last `x` reached and
+    /// whether the water was stopped by clay.
+    fn spread(&mut self, pos: Point2<i64>, dx: i64) -> (i64, bool) {
+        let mut current = pos;
+        loop {
+            let below = current + Point2::DOWN;
+            if !self.is_solid(below) && (self.tiles.get(below).is_some() || !self.drop(below)) {
+                return (current.x, false);
+            }
+
+            let next = current + Point2::new(dx, 0);
+            if self.tiles.get(next) == Some(&Tile::Clay) {
+                return (current.x, true);
+            }
+            self.tiles.insert(next, Tile::Flowing);
+            current = next;
+        }
+    }
+}
+
+impl FromStr for Reservoir {
+    type Err = String;
+
+    /// Parses one vein of clay per line, e.g. `x=495, y=2..7` or `y=7, x=495..501`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut clay = Vec::new();
+
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let err = || format!("invalid clay vein: {}", line);
+            let mut parts = line.splitn(2, ',');
+            let (first, second) = match (parts.next(), parts.next()) {
+                (Some(a), Some(b)) => (a.trim(), b.trim()),
+                _ => return Err(err()),
+            };
+
+            let fixed: i64 = first
+                .get(2..)
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(err)?;
+            let range = second.get(2..).ok_or_else(err)?;
+            let mut bounds = range.splitn(2, "..");
+            let (from, to): (i64, i64) = match (bounds.next(), bounds.next()) {
+                (Some(a), Some(b)) => {
+                    (a.parse().map_err(|_| err())?, b.parse().map_err(|_| err())?)
+                }
+                _ => return Err(err()),
+            };
+
+            match (first.as_bytes().first(), second.as_bytes().first()) {
+                (Some(b'x'), Some(b'y')) => clay.extend((from..=to).map(|y| Point2::new(fixed, y))),
+                (Some(b'y'), Some(b'x')) => clay.extend((from..=to).map(|x| Point2::new(x, fixed))),
+                _ => return Err(err()),
+            }
+        }
+
+        Ok(Reservoir::new(clay))
+    }
+}
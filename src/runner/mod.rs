@@ -0,0 +1,267 @@
+//! Harness for solution binaries: reads the input, runs the parts and prints the answers with
+//! the time each part took.
+//!
+//! The input is read from the file given as argument, or from stdin if there is no argument (or
+//! it is `-`). With `--bench [RUNS]`, every part is run repeatedly (100 times by default, after a
+//! few warm-up runs) and the minimum, median and mean durations are reported instead; `--json`
+//! additionally prints these statistics as JSON.
+//!
+//! For solvers registered with `register_solvers!`, `Report` collects the timings and the
+//! correctness of all answers of a year into a table (or JSON/Markdown), and `report_main()`
+//! turns this into a dashboard binary.
+//!
+//! # Examples
+//! ```no_run
+//! fn part1(input: &str) -> usize {
+//!     input.lines().count()
+//! }
+//!
+//! fn part2(input: &str) -> String {
+//!     input.lines().rev().collect()
+//! }
+//!
+//! aoc_util::aoc_main!(part1, part2);
+//! ```
+
+use std::env;
+use std::fmt::Display;
+use std::io;
+use std::process;
+use std::time::Duration;
+
+use crate::input::{FileReader, FromFileOrStdin};
+use crate::output;
+use crate::registry;
+
+mod report;
+
+pub use self::report::{report_main, DayReport, PartReport, Report, Verdict};
+
+/// A part's solver, with the answer already converted to a string.
+pub type Solver<'a> = &'a dyn Fn(&str) -> String;
+
+/// The answer of one part and the time it took.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    /// Number of the part, starting at 1.
+    pub part: u8,
+    /// The answer, as displayed.
+    pub answer: String,
+    /// Wall-clock time of the solver.
+    pub duration: Duration,
+}
+
+/// Timing statistics of a part, as measured by `bench()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    /// Number of the part, starting at 1.
+    pub part: u8,
+    /// Number of measured runs.
+    pub runs: usize,
+    /// Fastest run.
+    pub min: Duration,
+    /// Median run.
+    pub median: Duration,
+    /// Mean of all runs.
+    pub mean: Duration,
+}
+
+/// Default number of measured runs per part in bench mode.
+pub const DEFAULT_BENCH_RUNS: usize = 100;
+
+/// Command line options of a solution binary.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Path of the input file; read from stdin if `None`.
+    pub input: Option<String>,
+    /// Number of measured runs per part, if in bench mode.
+    pub bench: Option<usize>,
+    /// Print bench results as JSON.
+    pub json: bool,
+}
+
+impl Options {
+    /// Parse the command line arguments (the program name already skipped).
+    ///
+    /// # Failures
+    /// Returns an error for unknown flags, an invalid number of runs or several input paths.
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self, String> {
+        let mut options = Options::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--bench" => {
+                    let runs = match args.peek() {
+                        Some(next) if next.chars().all(|c| c.is_ascii_digit()) => {
+                            let runs = next
+                                .parse()
+                                .map_err(|_| format!("invalid number of runs: {}", next))?;
+                            args.next();
+                            runs
+                        }
+                        _ => DEFAULT_BENCH_RUNS,
+                    };
+                    if runs == 0 {
+                        return Err(String::from("invalid number of runs: 0"));
+                    }
+                    options.bench = Some(runs);
+                }
+                "--json" => options.json = true,
+                flag if flag.starts_with("--") => return Err(format!("unknown flag: {}", flag)),
+                _ if options.input.is_some() => {
+                    return Err(format!("unexpected argument: {}", arg))
+                }
+                _ => options.input = Some(arg),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Wrap a solver returning anything displayable into a `Solver`.
+pub fn solver<A: Display, F: Fn(&str) -> A>(f: F) -> impl Fn(&str) -> String {
+    move |input| f(input).to_string()
+}
+
+/// Read the input from the file given by the first of `args` (the program name already
+/// skipped), or from stdin.
+///
+/// # Failures
+/// Returns an error if the input cannot be read.
+pub fn read_input<I: IntoIterator<Item = String>>(args: I) -> io::Result<String> {
+    FileReader::new().read_from_file_or_stdin(args.into_iter().next())
+}
+
+/// Run all `solvers` on `input`, part 1 first.
+pub fn run(input: &str, solvers: &[Solver<'_>]) -> Vec<PartResult> {
+    solvers
+        .iter()
+        .zip(1..)
+        .map(|(solve, part)| {
+            let (answer, duration) = output::timed(|| solve(input));
+            PartResult {
+                part,
+                answer,
+                duration,
+            }
+        })
+        .collect()
+}
+
+/// Run both parts of a registered `solver` on `input`.
+pub fn run_solver(input: &str, solver: &dyn registry::Solver) -> Vec<PartResult> {
+    let part1 = |input: &str| solver.part1(input).to_string();
+    let part2 = |input: &str| solver.part2(input).to_string();
+    run(input, &[&part1, &part2])
+}
+
+/// Run every solver `runs` times on `input` (after some warm-up runs) and measure the durations.
+///
+/// # Panics
+/// Panics if `runs` is zero.
+pub fn bench(input: &str, solvers: &[Solver<'_>], runs: usize) -> Vec<BenchResult> {
+    assert!(runs > 0, "number of runs must be positive");
+    let warm_up = (runs / 10).clamp(1, 10);
+
+    solvers
+        .iter()
+        .zip(1..)
+        .map(|(solve, part)| {
+            for _ in 0..warm_up {
+                std::hint::black_box(solve(input));
+            }
+            let mut durations: Vec<Duration> = (0..runs)
+                .map(|_| output::timed(|| std::hint::black_box(solve(input))).1)
+                .collect();
+            durations.sort_unstable();
+
+            BenchResult {
+                part,
+                runs,
+                min: durations[0],
+                median: durations[runs / 2],
+                mean: durations.iter().sum::<Duration>() / runs as u32,
+            }
+        })
+        .collect()
+}
+
+/// Bench both parts of a registered `solver` on `input`, like `bench`.
+///
+/// # Panics
+/// Panics if `runs` is zero.
+pub fn bench_solver(input: &str, solver: &dyn registry::Solver, runs: usize) -> Vec<BenchResult> {
+    let part1 = |input: &str| solver.part1(input).to_string();
+    let part2 = |input: &str| solver.part2(input).to_string();
+    bench(input, &[&part1, &part2], runs)
+}
+
+/// Format bench results as a JSON array, with durations in nanoseconds.
+pub fn bench_json(results: &[BenchResult]) -> String {
+    let entries: Vec<String> = results
+        .iter()
+        .map(|r| {
+            format!(
+                "{{\"part\":{},\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{}}}",
+                r.part,
+                r.runs,
+                r.min.as_nanos(),
+                r.median.as_nanos(),
+                r.mean.as_nanos()
+            )
+        })
+        .collect();
+    format!("[{}]", entries.join(","))
+}
+
+/// Entry point of a solution binary: read the input according to the command line, run all
+/// `solvers` and print the answers (or bench results). Exits the process if the arguments are
+/// invalid or the input cannot be read.
+pub fn main(solvers: &[Solver<'_>]) {
+    let options = Options::parse(env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        eprintln!("usage: [--bench [RUNS]] [--json] [INPUT]");
+        process::exit(2);
+    });
+    let input = read_input(options.input).unwrap_or_else(|e| {
+        eprintln!("cannot read input: {}", e);
+        process::exit(1);
+    });
+
+    match options.bench {
+        Some(runs) => {
+            let results = bench(&input, solvers, runs);
+            for r in &results {
+                println!(
+                    "Part {}: min {}, median {}, mean {} ({} runs)",
+                    r.part,
+                    output::format_duration(r.min),
+                    output::format_duration(r.median),
+                    output::format_duration(r.mean),
+                    r.runs
+                );
+            }
+            if options.json {
+                println!("{}", bench_json(&results));
+            }
+        }
+        None => {
+            for result in run(&input, solvers) {
+                output::print_answer(result.part, result.answer, result.duration);
+            }
+        }
+    }
+}
+
+/// Generate the `main` function of a solution binary from its part solvers (functions taking the
+/// input as `&str` and returning anything that implements `Display`).
+#[macro_export]
+macro_rules! aoc_main {
+    ($($part:expr),+ $(,)?) => {
+        fn main() {
+            $crate::runner::main(&[$(&$crate::runner::solver($part)),+]);
+        }
+    };
+}
@@ -0,0 +1,371 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::input::{FileReader, FromFile};
+use crate::output;
+use crate::project::Project;
+use crate::registry::{Registry, Solver};
+
+/// Whether an answer matches the known correct one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The answer is correct.
+    Correct,
+    /// The answer differs from the correct one.
+    Wrong,
+    /// The correct answer isn't known.
+    Unknown,
+}
+
+impl Verdict {
+    fn mark(self) -> char {
+        match self {
+            Verdict::Correct => '✓',
+            Verdict::Wrong => '✗',
+            Verdict::Unknown => '?',
+        }
+    }
+}
+
+/// The answer of one part, the time it took and whether it is correct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    /// Number of the part, starting at 1.
+    pub part: u8,
+    /// The answer.
+    pub answer: Answer,
+    /// Time it took to compute the answer.
+    pub duration: Duration,
+    /// Comparison with the correct answer.
+    pub verdict: Verdict,
+}
+
+/// The results of both parts of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayReport {
+    /// Year of the puzzle.
+    pub year: u32,
+    /// Day of the puzzle.
+    pub day: u32,
+    /// Title of the puzzle.
+    pub name: String,
+    /// Results per part, empty if there is no input.
+    pub parts: Vec<PartReport>,
+}
+
+impl DayReport {
+    /// Number of correct answers.
+    pub fn stars(&self) -> usize {
+        self.parts
+            .iter()
+            .filter(|p| p.verdict == Verdict::Correct)
+            .count()
+    }
+
+    /// Total time of both parts.
+    pub fn duration(&self) -> Duration {
+        self.parts.iter().map(|p| p.duration).sum()
+    }
+}
+
+/// Results of all registered solvers, e.g. for a whole year.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Report {
+    /// Results per day, in the order of the solvers.
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    /// Run both parts of all `solvers` on the inputs returned by `input`, and compare the answers
+    /// with the correct ones returned by `expected` (one per part, as submitted). Days without
+    /// input are included without any parts.
+    pub fn generate<'a, S, I, E>(solvers: S, mut input: I, mut expected: E) -> Self
+    where
+        S: IntoIterator<Item = &'a dyn Solver>,
+        I: FnMut(u32, u32) -> Option<String>,
+        E: FnMut(u32, u32) -> Vec<String>,
+    {
+        let days = solvers
+            .into_iter()
+            .map(|solver| {
+                let (year, day) = (solver.year(), solver.day());
+                let parts = match input(year, day) {
+                    Some(input) => solve(solver, &input, &expected(year, day)),
+                    None => Vec::new(),
+                };
+                DayReport {
+                    year,
+                    day,
+                    name: solver.name().to_string(),
+                    parts,
+                }
+            })
+            .collect();
+
+        Self { days }
+    }
+
+    /// Generate the report with the inputs and correct answers of `project` (see
+    /// `Project::input_path()` and `Project::answers_path()`).
+    pub fn for_project<'a, S>(solvers: S, project: &Project) -> Self
+    where
+        S: IntoIterator<Item = &'a dyn Solver>,
+    {
+        Self::generate(
+            solvers,
+            |year, day| {
+                FileReader::new()
+                    .read_from_file(project.input_path(year, day))
+                    .ok()
+            },
+            |year, day| {
+                fs::read_to_string(project.answers_path(year, day))
+                    .map(|answers| answers.lines().map(|l| l.trim().to_string()).collect())
+                    .unwrap_or_default()
+            },
+        )
+    }
+
+    /// Total number of correct answers.
+    pub fn stars(&self) -> usize {
+        self.days.iter().map(DayReport::stars).sum()
+    }
+
+    /// Total time of all parts.
+    pub fn duration(&self) -> Duration {
+        self.days.iter().map(DayReport::duration).sum()
+    }
+
+    /// Returns `true` if any answer is wrong.
+    pub fn has_wrong_answers(&self) -> bool {
+        self.days
+            .iter()
+            .flat_map(|d| &d.parts)
+            .any(|p| p.verdict == Verdict::Wrong)
+    }
+
+    /// Render the report as a table for the terminal.
+    pub fn render(&self) -> String {
+        let rows = self.rows();
+        let widths: Vec<usize> = (0..rows[0].len())
+            .map(|col| rows.iter().map(|r| r[col].chars().count()).max().unwrap())
+            .collect();
+        let line = |row: &[String]| {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            cells.join("  ").trim_end().to_string()
+        };
+        let separator = "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1));
+
+        let mut table = String::new();
+        let (footer, body) = rows.split_last().unwrap();
+        for (idx, row) in body.iter().enumerate() {
+            table.push_str(&line(row));
+            table.push('\n');
+            if idx == 0 {
+                table.push_str(&separator);
+                table.push('\n');
+            }
+        }
+        table.push_str(&separator);
+        table.push('\n');
+        table.push_str(&line(footer));
+        table
+    }
+
+    /// Render the report as a Markdown table, e.g. for a README.
+    pub fn to_markdown(&self) -> String {
+        let rows = self.rows();
+        let line = |row: &[String]| format!("| {} |", row.join(" | "));
+
+        let mut table = vec![
+            line(&rows[0]),
+            line(&vec![String::from("---"); rows[0].len()]),
+        ];
+        table.extend(rows[1..].iter().map(|row| line(row)));
+        table.join("\n")
+    }
+
+    /// Format the report as JSON, with durations in nanoseconds and `null` for unknown
+    /// correctness.
+    pub fn to_json(&self) -> String {
+        let days: Vec<String> = self
+            .days
+            .iter()
+            .map(|day| {
+                let parts: Vec<String> = day
+                    .parts
+                    .iter()
+                    .map(|p| {
+                        let correct = match p.verdict {
+                            Verdict::Correct => "true",
+                            Verdict::Wrong => "false",
+                            Verdict::Unknown => "null",
+                        };
+                        format!(
+                            "{{\"part\":{},\"answer\":{},\"duration_ns\":{},\"correct\":{}}}",
+                            p.part,
+                            p.answer
+                                .submission()
+                                .map_or_else(|| String::from("null"), |a| json_string(&a)),
+                            p.duration.as_nanos(),
+                            correct
+                        )
+                    })
+                    .collect();
+                format!(
+                    "{{\"year\":{},\"day\":{},\"name\":{},\"stars\":{},\"parts\":[{}]}}",
+                    day.year,
+                    day.day,
+                    json_string(&day.name),
+                    day.stars(),
+                    parts.join(",")
+                )
+            })
+            .collect();
+
+        format!(
+            "{{\"stars\":{},\"duration_ns\":{},\"days\":[{}]}}",
+            self.stars(),
+            self.duration().as_nanos(),
+            days.join(",")
+        )
+    }
+
+    /// Header, one row per day and the totals.
+    fn rows(&self) -> Vec<Vec<String>> {
+        let header = ["Year", "Day", "Name", "Part 1", "Part 2", "Stars"];
+        let mut rows = vec![header.iter().map(|h| h.to_string()).collect()];
+
+        let mut part_totals = [Duration::ZERO; 2];
+        for day in &self.days {
+            let mut row = vec![day.year.to_string(), day.day.to_string(), day.name.clone()];
+            for part in 1..=2 {
+                let cell = match day.parts.iter().find(|p| p.part == part) {
+                    Some(p) => {
+                        part_totals[usize::from(part) - 1] += p.duration;
+                        format!(
+                            "{} {}",
+                            output::format_duration(p.duration),
+                            p.verdict.mark()
+                        )
+                    }
+                    None if day.parts.is_empty() => String::from("no input"),
+                    None => String::from("-"),
+                };
+                row.push(cell);
+            }
+            row.push("*".repeat(day.stars()));
+            rows.push(row);
+        }
+
+        rows.push(vec![
+            String::from("Total"),
+            String::new(),
+            output::format_duration(self.duration()),
+            output::format_duration(part_totals[0]),
+            output::format_duration(part_totals[1]),
+            self.stars().to_string(),
+        ]);
+        rows
+    }
+}
+
+fn solve(solver: &dyn Solver, input: &str, expected: &[String]) -> Vec<PartReport> {
+    let parts: [&dyn Fn(&str) -> Answer; 2] =
+        [&|input| solver.part1(input), &|input| solver.part2(input)];
+
+    parts
+        .iter()
+        .zip(1..)
+        .map(|(solve, part)| {
+            let (answer, duration) = output::timed(|| solve(input));
+            let verdict = match expected.get(usize::from(part) - 1) {
+                Some(expected) if !expected.is_empty() => {
+                    if answer.submission().as_deref() == Some(expected.as_str()) {
+                        Verdict::Correct
+                    } else {
+                        Verdict::Wrong
+                    }
+                }
+                _ => Verdict::Unknown,
+            };
+            PartReport {
+                part,
+                answer,
+                duration,
+                verdict,
+            }
+        })
+        .collect()
+}
+
+fn usage() -> ! {
+    eprintln!("usage: [--json | --markdown] [YEAR]");
+    process::exit(2);
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Entry point of a dashboard binary: run all solvers of `registry` (optionally only those of
+/// the year given on the command line) on the inputs of the current project and print the
+/// report as table, or as JSON/Markdown with `--json`/`--markdown`. Exits the process if the
+/// arguments are invalid or there is no project, and with status 1 if any answer is wrong.
+pub fn report_main(registry: &Registry) {
+    let mut format = None;
+    let mut year = None;
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--json" | "--markdown" if format.is_none() => format = Some(arg),
+            _ if year.is_none() && !arg.starts_with("--") => match arg.parse::<u32>() {
+                Ok(y) => year = Some(y),
+                Err(_) => {
+                    eprintln!("invalid year: {}", arg);
+                    usage();
+                }
+            },
+            _ => {
+                eprintln!("unexpected argument: {}", arg);
+                usage();
+            }
+        }
+    }
+
+    let project = Project::locate().unwrap_or_else(|e| {
+        eprintln!("cannot locate project: {}", e);
+        process::exit(1);
+    });
+    let report = match year {
+        Some(year) => Report::for_project(registry.year(year), &project),
+        None => Report::for_project(registry.iter(), &project),
+    };
+
+    match format.as_deref() {
+        Some("--json") => println!("{}", report.to_json()),
+        Some(_) => println!("{}", report.to_markdown()),
+        None => println!("{}", report.render()),
+    }
+    if report.has_wrong_answers() {
+        process::exit(1);
+    }
+}
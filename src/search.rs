@@ -0,0 +1,337 @@
+//! Generic graph search over implicit graphs, given by a start node and a successor function.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::search;
+//!
+//! // Reach 10 from 1 by doubling or adding one.
+//! let (path, cost) = search::bfs(1u32, |&n| vec![n * 2, n + 1], |&n| n == 10).unwrap();
+//!
+//! assert_eq!(4, cost);
+//! assert_eq!(vec![1, 2, 4, 5, 10], path);
+//!
+//! // Same, but doubling costs 3 and adding one costs 1.
+//! let (path, cost) = search::dijkstra(1u32, |&n| vec![(n * 2, 3), (n + 1, 1)], |&n| n == 10)
+//!     .unwrap();
+//!
+//! assert_eq!(7, cost);
+//! assert_eq!(vec![1, 2, 3, 4, 5, 10], path);
+//! ```
+
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{Ordering, Reverse};
+use core::hash::Hash;
+use core::ops::Add;
+
+use crate::collections::HashTable;
+
+/// Breadth-first search from `start` to the nearest node for which `goal` returns `true`.
+///
+/// Returns the path (including `start` and the goal) and its number of steps, or `None` if no
+/// goal is reachable. Each node is visited at most once, so `successors` may return nodes that
+/// have been seen before.
+pub fn bfs<N, S, I, G>(start: N, mut successors: S, mut goal: G) -> Option<(Vec<N>, usize)>
+where
+    N: Clone + Eq + Hash,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut parents: HashTable<N, Option<N>> = HashTable::new();
+    let mut queue = VecDeque::new();
+    parents.insert(start.clone(), None);
+    queue.push_back(start);
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            let path = reconstruct_path(&parents, node);
+            let cost = path.len() - 1;
+            return Some((path, cost));
+        }
+        for next in successors(&node) {
+            if !parents.contains_key(&next) {
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm from `start` to the cheapest node for which `goal` returns `true`.
+///
+/// `successors` returns the neighbors of a node together with the (non-negative) cost of moving
+/// there. Returns the path (including `start` and the goal) and its total cost, or `None` if no
+/// goal is reachable. `C::default()` must be the zero cost.
+pub fn dijkstra<N, C, S, I, G>(start: N, successors: S, goal: G) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+/// A* search from `start` to the cheapest node for which `goal` returns `true`.
+///
+/// Like `dijkstra()`, but nodes are explored in order of their cost plus `heuristic`, an
+/// estimate of the remaining cost to the goal. The result is optimal as long as the heuristic
+/// never overestimates and is consistent (e.g. the Manhattan distance on a grid with unit
+/// costs).
+pub fn astar<N, C, S, I, H, G>(
+    start: N,
+    mut successors: S,
+    mut heuristic: H,
+    mut goal: G,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut costs: HashTable<N, C> = HashTable::new();
+    let mut parents: HashTable<N, Option<N>> = HashTable::new();
+    let mut queue = BinaryHeap::new();
+
+    costs.insert(start.clone(), C::default());
+    parents.insert(start.clone(), None);
+    queue.push(Candidate {
+        priority: heuristic(&start),
+        cost: C::default(),
+        node: start,
+    });
+
+    while let Some(Candidate { cost, node, .. }) = queue.pop() {
+        if costs.get(&node).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        if goal(&node) {
+            return Some((reconstruct_path(&parents, node), cost));
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), Some(node.clone()));
+                queue.push(Candidate {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// Explore all states reachable from `start`, pruning states that are dominated by another
+/// state with the same `key`.
+///
+/// States with equal keys (e.g. the same position and time) are compared with
+/// `dominates(a, b)`, which returns `true` if `a` is at least as good as `b` in every
+/// resource. A new state dominated by a known one is dropped; known states dominated by a new
+/// one are dropped and not expanded any further. With `dominates` being `==`, this is a plain
+/// exhaustive search with a visited set.
+///
+/// Returns the surviving states, so the best one can be picked afterwards. States are explored
+/// breadth-first.
+///
+/// # Examples
+/// ```
+/// use aoc_util::search;
+///
+/// // Walk on 0..10 with 3 energy, where every step costs one energy and arriving at 3 refills.
+/// let states = search::explore(
+///     (0i32, 3u32),
+///     |&(pos, _)| pos,
+///     |&(pos, energy)| {
+///         let moves = if energy == 0 { vec![] } else { vec![pos - 1, pos + 1] };
+///         moves
+///             .into_iter()
+///             .filter(|next| (0..10).contains(next))
+///             .map(move |next| (next, if next == 3 { 3 } else { energy - 1 }))
+///     },
+///     |a, b| a.1 >= b.1,
+/// );
+///
+/// assert_eq!(Some(6), states.iter().map(|&(pos, _)| pos).max());
+/// assert_eq!(7, states.len());
+/// ```
+pub fn explore<S, K, KF, F, I, D>(
+    start: S,
+    mut key: KF,
+    mut successors: F,
+    mut dominates: D,
+) -> Vec<S>
+where
+    S: Clone + PartialEq,
+    K: Eq + Hash,
+    KF: FnMut(&S) -> K,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    D: FnMut(&S, &S) -> bool,
+{
+    let mut frontier: HashTable<K, Vec<S>> = HashTable::new();
+    let mut queue = VecDeque::new();
+    frontier.insert(key(&start), vec![start.clone()]);
+    queue.push_back(start);
+
+    while let Some(state) = queue.pop_front() {
+        // The state may have been dominated since it was queued.
+        if !frontier
+            .get(&key(&state))
+            .is_some_and(|known| known.contains(&state))
+        {
+            continue;
+        }
+        for next in successors(&state) {
+            let known = frontier.get_or_insert_with(key(&next), Vec::new);
+            if known.iter().any(|other| dominates(other, &next)) {
+                continue;
+            }
+            known.retain(|other| !dominates(&next, other));
+            known.push(next.clone());
+            queue.push_back(next);
+        }
+    }
+
+    frontier.into_values().flatten().collect()
+}
+
+/// Error of `topological_sort()`: the dependencies contain a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle<N> {
+    /// The nodes of one cycle, each depending on the next one (and the last on the first).
+    pub nodes: Vec<N>,
+}
+
+/// Order `nodes` such that every node comes after all of its dependencies.
+///
+/// `dependencies` returns the nodes a node depends on; these are included in the result even if
+/// they are not part of `nodes`. Whenever several nodes are ready, the smallest one comes first,
+/// so the order is unique.
+///
+/// # Failures
+/// Returns one of the cycles if the dependencies are cyclic.
+pub fn topological_sort<N, M, D, I>(nodes: M, mut dependencies: D) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Ord + Hash,
+    M: IntoIterator<Item = N>,
+    D: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut prerequisites: HashTable<N, Vec<N>> = HashTable::new();
+    let mut stack: Vec<N> = nodes.into_iter().collect();
+    while let Some(node) = stack.pop() {
+        if prerequisites.contains_key(&node) {
+            continue;
+        }
+        let deps: Vec<N> = dependencies(&node).into_iter().collect();
+        stack.extend(deps.iter().cloned());
+        prerequisites.insert(node, deps);
+    }
+
+    let mut dependents: HashTable<&N, Vec<&N>> = HashTable::new();
+    let mut missing: HashTable<&N, usize> = HashTable::new();
+    for (node, deps) in &prerequisites {
+        let mut unique: Vec<&N> = deps.iter().collect();
+        unique.sort_unstable();
+        unique.dedup();
+        missing.insert(node, unique.len());
+        for dep in unique {
+            dependents.get_or_insert_with(dep, Vec::new).push(node);
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<&N>> = missing
+        .iter()
+        .filter(|&(_, &count)| count == 0)
+        .map(|(&node, _)| Reverse(node))
+        .collect();
+    let mut order = Vec::with_capacity(prerequisites.len());
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node.clone());
+        for &dependent in dependents.get(node).into_iter().flatten() {
+            let count = missing.get_mut(dependent).unwrap();
+            *count -= 1;
+            if *count == 0 {
+                ready.push(Reverse(dependent));
+            }
+        }
+    }
+
+    if order.len() == prerequisites.len() {
+        return Ok(order);
+    }
+
+    // Every remaining node waits for another remaining node, so following those leads into a
+    // cycle.
+    let blocked = |node: &N| missing[node] > 0;
+    let mut node = missing
+        .keys()
+        .copied()
+        .filter(|n| blocked(n))
+        .min()
+        .unwrap();
+    let mut path: Vec<&N> = Vec::new();
+    let mut seen: HashTable<&N, usize> = HashTable::new();
+    while !seen.contains_key(node) {
+        seen.insert(node, path.len());
+        path.push(node);
+        node = prerequisites[node]
+            .iter()
+            .filter(|n| blocked(n))
+            .min()
+            .unwrap();
+    }
+
+    Err(Cycle {
+        nodes: path[seen[node]..].iter().map(|&n| n.clone()).collect(),
+    })
+}
+
+/// Entry of the priority queue, ordered such that the smallest priority is popped first.
+struct Candidate<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// Follow the parent links from `node` back to the start and return the path from the start.
+fn reconstruct_path<N: Clone + Eq + Hash>(parents: &HashTable<N, Option<N>>, node: N) -> Vec<N> {
+    let mut path = vec![node];
+    while let Some(Some(parent)) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
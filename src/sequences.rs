@@ -0,0 +1,291 @@
+//! Helpers for repeatedly applying a function and for well-known puzzle sequences (look-and-say,
+//! Van Eck, the recipe scoreboard, cumulative sums of repeated deltas).
+//!
+//! # Examples
+//! ```
+//! use aoc_util::sequences::{iterate, iterate_checkpoints, look_and_say};
+//!
+//! assert_eq!("312211", iterate(String::from("1"), |s| look_and_say(s), 5));
+//!
+//! // Report the lengths after 2 and 5 applications in a single run
+//! let lengths = iterate_checkpoints(String::from("1"), |s| look_and_say(s), &[2, 5], |s| s.len());
+//! assert_eq!(vec![2, 6], lengths);
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+/// Apply `f` to `initial` `n` times and return the result.
+pub fn iterate<T, F>(initial: T, mut f: F, n: usize) -> T
+where
+    F: FnMut(&T) -> T,
+{
+    let mut value = initial;
+    for _ in 0..n {
+        value = f(&value);
+    }
+    value
+}
+
+/// Apply `f` to `initial` until `pred` holds. Returns the number of applications and the final
+/// value. `pred` is checked before the first application, so this may return `(0, initial)`.
+pub fn iterate_until<T, F, P>(initial: T, mut f: F, mut pred: P) -> (usize, T)
+where
+    F: FnMut(&T) -> T,
+    P: FnMut(&T) -> bool,
+{
+    let mut value = initial;
+    let mut steps = 0;
+    while !pred(&value) {
+        value = f(&value);
+        steps += 1;
+    }
+    (steps, value)
+}
+
+/// Apply `f` to `initial` repeatedly and call `report` on the values after each number of
+/// applications listed in `checkpoints`. The results are returned in the order of `checkpoints`.
+///
+/// Only as many applications as needed for the largest checkpoint are performed.
+pub fn iterate_checkpoints<T, U, F, R>(
+    initial: T,
+    mut f: F,
+    checkpoints: &[usize],
+    mut report: R,
+) -> Vec<U>
+where
+    F: FnMut(&T) -> T,
+    R: FnMut(&T) -> U,
+{
+    let mut order: Vec<usize> = (0..checkpoints.len()).collect();
+    order.sort_by_key(|&i| checkpoints[i]);
+
+    let mut results: Vec<Option<U>> = (0..checkpoints.len()).map(|_| None).collect();
+    let mut value = initial;
+    let mut steps = 0;
+
+    for i in order {
+        while steps < checkpoints[i] {
+            value = f(&value);
+            steps += 1;
+        }
+        results[i] = Some(report(&value));
+    }
+
+    results.into_iter().map(Option::unwrap).collect()
+}
+
+/// Compute the next term of the look-and-say sequence (e.g. `"1211"` becomes `"111221"`).
+pub fn look_and_say(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() * 2);
+    let mut chars = s.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        result.push_str(&count.to_string());
+        result.push(c);
+    }
+
+    result
+}
+
+/// The `nth` number (1-based) of the memory game (Van Eck's sequence) continuing the `starting`
+/// numbers: each following number is the number of turns since the previous number was last
+/// spoken, or `0` if it was new.
+///
+/// Uses a flat last-seen table, so the 30 millionth number takes about 120 MB and well below a
+/// second in release mode.
+///
+/// # Panics
+/// Panics if `starting` is empty or `nth` is zero.
+pub fn van_eck(starting: &[u32], nth: usize) -> u32 {
+    assert!(!starting.is_empty(), "starting numbers must not be empty");
+    assert!(nth > 0, "turns are counted from 1");
+    if nth <= starting.len() {
+        return starting[nth - 1];
+    }
+
+    // last_seen[n] holds the turn (1-based) in which `n` was last spoken, 0 if never.
+    let max_start = *starting.iter().max().unwrap() as usize;
+    let mut last_seen = vec![0u32; nth.max(max_start + 1)];
+    for (turn, &n) in starting[..starting.len() - 1].iter().enumerate() {
+        last_seen[n as usize] = turn as u32 + 1;
+    }
+
+    let mut current = starting[starting.len() - 1];
+    for turn in starting.len() as u32..nth as u32 {
+        let seen = last_seen[current as usize];
+        last_seen[current as usize] = turn;
+        current = if seen == 0 { 0 } else { turn - seen };
+    }
+
+    current
+}
+
+/// First cumulative sum (starting at `0`) that is reached twice when applying `deltas` over and
+/// over again.
+///
+/// Remembers every sum in a `HashSet`, so the number of passes over `deltas` must be moderate.
+/// Returns `None` if `deltas` is empty; runs forever if no sum ever repeats, see
+/// `first_repeated_cumulative_sum_modular()` for a variant that detects this.
+pub fn first_repeated_cumulative_sum(deltas: &[i64]) -> Option<i64> {
+    if deltas.is_empty() {
+        return None;
+    }
+
+    let mut seen = HashSet::new();
+    let mut sum = 0;
+    seen.insert(sum);
+    for delta in deltas.iter().cycle() {
+        sum += delta;
+        if !seen.insert(sum) {
+            return Some(sum);
+        }
+    }
+    unreachable!()
+}
+
+/// Same as `first_repeated_cumulative_sum()`, but in `O(n log n)` regardless of how many passes
+/// it takes, and returning `None` if no sum ever repeats.
+///
+/// The sums of pass `k` are the sums of the first pass shifted by `k` times the total drift, so a
+/// later sum can only hit a first-pass sum with the same remainder modulo the drift. Within each
+/// such group, every sum reaches its next neighbor (in drift direction) first.
+pub fn first_repeated_cumulative_sum_modular(deltas: &[i64]) -> Option<i64> {
+    // Sums before each step of the first pass; a repeat within the pass is the earliest one.
+    let mut prefix = Vec::with_capacity(deltas.len());
+    let mut seen = HashSet::new();
+    let mut sum = 0;
+    for delta in deltas {
+        if !seen.insert(sum) {
+            return Some(sum);
+        }
+        prefix.push(sum);
+        sum += delta;
+    }
+
+    let drift = sum;
+    if drift == 0 {
+        // The second pass starts at 0 again.
+        return if deltas.is_empty() { None } else { Some(0) };
+    }
+
+    let mut groups: HashMap<i64, Vec<(i64, usize)>> = HashMap::new();
+    for (i, &p) in prefix.iter().enumerate() {
+        groups
+            .entry(p.rem_euclid(drift.abs()))
+            .or_default()
+            .push((p * drift.signum(), i));
+    }
+
+    let n = deltas.len() as i64;
+    let mut best: Option<(i64, i64)> = None;
+    for group in groups.values_mut() {
+        group.sort_unstable();
+        for pair in group.windows(2) {
+            let ((from, i), (to, _)) = (pair[0], pair[1]);
+            // `from` reaches `to` after this many passes, at step `i` of that pass.
+            let passes = (to - from) / drift.abs();
+            let time = passes * n + i as i64;
+            if best.is_none_or(|(t, _)| time < t) {
+                best = Some((time, to * drift.signum()));
+            }
+        }
+    }
+
+    best.map(|(_, value)| value)
+}
+
+/// The hot chocolate recipe scoreboard of "Chocolate Charts": two elves repeatedly combine
+/// their current recipes, append the digits of the sum, and move forward by one plus the score
+/// of their recipe.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scoreboard {
+    scores: Vec<u8>,
+    elves: [usize; 2],
+}
+
+impl Default for Scoreboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Scoreboard {
+    /// Create new `Scoreboard` with the initial recipes `3` and `7`.
+    pub fn new() -> Self {
+        Self {
+            scores: vec![3, 7],
+            elves: [0, 1],
+        }
+    }
+
+    /// All scores so far.
+    pub fn scores(&self) -> &[u8] {
+        &self.scores
+    }
+
+    /// Create new recipes and move the elves. Returns the number of recipes added (1 or 2).
+    pub fn step(&mut self) -> usize {
+        let [a, b] = self.elves;
+        let sum = self.scores[a] + self.scores[b];
+        let added = if sum >= 10 {
+            self.scores.push(sum / 10);
+            self.scores.push(sum % 10);
+            2
+        } else {
+            self.scores.push(sum);
+            1
+        };
+
+        let len = self.scores.len();
+        for elf in self.elves.iter_mut() {
+            *elf = (*elf + 1 + self.scores[*elf] as usize) % len;
+        }
+        added
+    }
+
+    /// The `count` scores after the first `n` recipes.
+    pub fn scores_after(&mut self, n: usize, count: usize) -> &[u8] {
+        self.scores
+            .reserve(n + count + 1 - self.scores.len().min(n + count));
+        while self.scores.len() < n + count {
+            self.step();
+        }
+        &self.scores[n..n + count]
+    }
+
+    /// Number of recipes to the left of the first occurrence of `pattern`. An empty pattern is
+    /// found at `0`.
+    ///
+    /// Runs forever if the pattern never appears.
+    pub fn find(&mut self, pattern: &[u8]) -> usize {
+        if pattern.is_empty() {
+            return 0;
+        }
+        let matches_at = |scores: &[u8], end: usize| {
+            end >= pattern.len() && &scores[end - pattern.len()..end] == pattern
+        };
+
+        // The pattern might already be present.
+        if let Some(start) = self
+            .scores
+            .windows(pattern.len())
+            .position(|w| w == pattern)
+        {
+            return start;
+        }
+        loop {
+            let added = self.step();
+            let len = self.scores.len();
+            for end in len + 1 - added..=len {
+                if matches_at(&self.scores, end) {
+                    return end - pattern.len();
+                }
+            }
+        }
+    }
+}
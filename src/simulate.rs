@@ -0,0 +1,136 @@
+//! Discrete event simulation, for puzzles where entities act at sparse points in time.
+//!
+//! Instead of advancing a clock tick by tick, events are scheduled at the time they happen and
+//! processed in order, jumping over the quiet periods in between.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::simulate::EventQueue;
+//!
+//! let mut queue = EventQueue::new();
+//! queue.schedule(10, "stop");
+//! let bus = queue.schedule(7, "bus");
+//! queue.schedule_in(3, "start");
+//! queue.cancel(bus);
+//!
+//! assert_eq!(Some((3, "start")), queue.pop());
+//! assert_eq!(Some((10, "stop")), queue.pop());
+//! assert_eq!(10, queue.now());
+//! assert_eq!(None, queue.pop());
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::collections::FastHashMap;
+
+/// Handle of a scheduled event, used to cancel it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EventId(u64);
+
+/// Events ordered by the time they happen. Events scheduled at the same time are delivered in
+/// the order they were scheduled.
+#[derive(Debug, Clone)]
+pub struct EventQueue<E> {
+    now: u64,
+    next_id: u64,
+    times: BinaryHeap<Reverse<(u64, u64)>>,
+    events: FastHashMap<u64, E>,
+}
+
+impl<E> Default for EventQueue<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> EventQueue<E> {
+    /// Create new, empty `EventQueue` at time `0`.
+    pub fn new() -> Self {
+        Self {
+            now: 0,
+            next_id: 0,
+            times: BinaryHeap::new(),
+            events: FastHashMap::default(),
+        }
+    }
+
+    /// The current time: the time of the last event popped.
+    pub fn now(&self) -> u64 {
+        self.now
+    }
+
+    /// Number of pending (not cancelled) events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns `true` if there are no pending events.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Schedule `event` at the absolute `time`.
+    ///
+    /// # Panics
+    /// Panics if `time` is before `now()`.
+    pub fn schedule(&mut self, time: u64, event: E) -> EventId {
+        assert!(time >= self.now, "cannot schedule an event in the past");
+        let id = self.next_id;
+        self.next_id += 1;
+        self.times.push(Reverse((time, id)));
+        self.events.insert(id, event);
+        EventId(id)
+    }
+
+    /// Schedule `event` at `delay` after `now()`.
+    pub fn schedule_in(&mut self, delay: u64, event: E) -> EventId {
+        self.schedule(self.now + delay, event)
+    }
+
+    /// Cancel a pending event. Returns the event, or `None` if it already happened or was
+    /// cancelled before.
+    pub fn cancel(&mut self, id: EventId) -> Option<E> {
+        self.events.remove(&id.0)
+    }
+
+    /// The time of the next pending event.
+    pub fn peek_time(&mut self) -> Option<u64> {
+        self.skip_cancelled();
+        self.times.peek().map(|&Reverse((time, _))| time)
+    }
+
+    /// Remove the next pending event and advance `now()` to its time.
+    pub fn pop(&mut self) -> Option<(u64, E)> {
+        self.skip_cancelled();
+        let Reverse((time, id)) = self.times.pop()?;
+        self.now = time;
+        Some((time, self.events.remove(&id).unwrap()))
+    }
+
+    /// Like `pop()`, but only if the next event happens no later than `limit`. Otherwise, `now()`
+    /// is advanced to `limit` and `None` is returned.
+    ///
+    /// # Panics
+    /// Panics if `limit` is before `now()`.
+    pub fn pop_until(&mut self, limit: u64) -> Option<(u64, E)> {
+        assert!(limit >= self.now, "cannot go back in time");
+        match self.peek_time() {
+            Some(time) if time <= limit => self.pop(),
+            _ => {
+                self.now = limit;
+                None
+            }
+        }
+    }
+
+    /// Drop cancelled events from the top of the heap.
+    fn skip_cancelled(&mut self) {
+        while let Some(&Reverse((_, id))) = self.times.peek() {
+            if self.events.contains_key(&id) {
+                break;
+            }
+            self.times.pop();
+        }
+    }
+}
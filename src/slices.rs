@@ -0,0 +1,181 @@
+//! Searches over slices of numbers, e.g. "find the entries that sum to 2020" or "find the
+//! contiguous range that sums to the invalid number".
+//!
+//! # Examples
+//! ```
+//! use aoc_util::slices::{find_k_with_sum, find_pair_with_sum};
+//!
+//! let entries = [1721, 979, 366, 299, 675, 1456];
+//!
+//! assert_eq!(Some((299, 1721)), find_pair_with_sum(&entries, 2020));
+//! assert_eq!(Some(vec![366, 675, 979]), find_k_with_sum(&entries, 3, 2020));
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::{Add, Sub};
+
+use crate::validate::{StreamChecker, SumOfTwo};
+
+/// Find two distinct entries that sum to `target`. The smaller entry is returned first.
+pub fn find_pair_with_sum<T>(v: &[T], target: T) -> Option<(T, T)>
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    let mut sorted = v.to_vec();
+    sorted.sort_unstable();
+    pair_in_sorted(&sorted, target)
+}
+
+pub(crate) fn pair_in_sorted<T>(sorted: &[T], target: T) -> Option<(T, T)>
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    if sorted.len() < 2 {
+        return None;
+    }
+
+    let (mut lo, mut hi) = (0, sorted.len() - 1);
+    while lo < hi {
+        match (sorted[lo] + sorted[hi]).cmp(&target) {
+            Ordering::Equal => return Some((sorted[lo], sorted[hi])),
+            Ordering::Less => lo += 1,
+            Ordering::Greater => hi -= 1,
+        }
+    }
+    None
+}
+
+/// Find `k` distinct entries that sum to `target`. The entries are returned in ascending order.
+/// Returns `None` if `k` is zero.
+///
+/// Runs in `O(n^(k-1))` using a two-pointer search for the innermost pair.
+pub fn find_k_with_sum<T>(v: &[T], k: usize, target: T) -> Option<Vec<T>>
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    let mut sorted = v.to_vec();
+    sorted.sort_unstable();
+    k_in_sorted(&sorted, k, target, &mut Vec::with_capacity(k))
+}
+
+fn k_in_sorted<T>(sorted: &[T], k: usize, target: T, chosen: &mut Vec<T>) -> Option<Vec<T>>
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    let total = |chosen: &[T], last: T| chosen.iter().fold(last, |acc, &x| acc + x);
+
+    match k {
+        0 => None,
+        1 => sorted
+            .iter()
+            .find(|&&x| total(chosen, x) == target)
+            .map(|&x| {
+                let mut result = chosen.clone();
+                result.push(x);
+                result
+            }),
+        2 => {
+            // Find a pair in `sorted` such that `chosen + a + b == target`.
+            let (mut lo, mut hi) = (0, sorted.len().checked_sub(1)?);
+            while lo < hi {
+                match total(chosen, sorted[lo] + sorted[hi]).cmp(&target) {
+                    Ordering::Equal => {
+                        let mut result = chosen.clone();
+                        result.push(sorted[lo]);
+                        result.push(sorted[hi]);
+                        return Some(result);
+                    }
+                    Ordering::Less => lo += 1,
+                    Ordering::Greater => hi -= 1,
+                }
+            }
+            None
+        }
+        _ => {
+            for i in 0..sorted.len() {
+                chosen.push(sorted[i]);
+                let result = k_in_sorted(&sorted[i + 1..], k - 1, target, chosen);
+                chosen.pop();
+                if result.is_some() {
+                    return result;
+                }
+            }
+            None
+        }
+    }
+}
+
+/// Find the first entry (after the first `n` entries) that is not the sum of two entries with
+/// different values among the `n` entries immediately before it.
+pub fn first_not_sum_of_window<T>(v: &[T], n: usize) -> Option<T>
+where
+    T: Copy + Ord + Add<Output = T>,
+{
+    StreamChecker::new(n, SumOfTwo).first_invalid(v.iter().copied())
+}
+
+/// Find a contiguous range of at least two entries that sums to `target`.
+///
+/// Uses a sliding window, so all entries must be non-negative.
+pub fn contiguous_range_with_sum<T>(v: &[T], target: T) -> Option<&[T]>
+where
+    T: Copy + Ord + Default + Add<Output = T> + Sub<Output = T>,
+{
+    let (mut start, mut sum) = (0, T::default());
+    for end in 0..v.len() {
+        sum = sum + v[end];
+        while sum > target && start < end {
+            sum = sum - v[start];
+            start += 1;
+        }
+        // A single entry equal to the target doesn't count as a range.
+        if sum == target && end > start {
+            return Some(&v[start..=end]);
+        }
+    }
+    None
+}
+
+/// Number of ways to go from the smallest to the largest entry, visiting entries in ascending
+/// order, where each step between two visited entries is at most `max_gap`. The entries don't
+/// need to be sorted.
+///
+/// For the joltage adapter puzzle, include the outlet (`0`) and the device and use a gap of `3`.
+pub fn count_chains<T>(v: &[T], max_gap: T) -> u64
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    let mut sorted = v.to_vec();
+    sorted.sort_unstable();
+    if sorted.is_empty() {
+        return 0;
+    }
+
+    let mut ways = vec![0u64; sorted.len()];
+    ways[0] = 1;
+    for i in 1..sorted.len() {
+        ways[i] = (0..i)
+            .rev()
+            .take_while(|&j| sorted[i] - sorted[j] <= max_gap)
+            .map(|j| ways[j])
+            .sum();
+    }
+
+    ways[sorted.len() - 1]
+}
+
+/// Histogram of the differences between consecutive entries after sorting.
+pub fn gap_counts<T>(v: &[T]) -> BTreeMap<T, usize>
+where
+    T: Copy + Ord + Sub<Output = T>,
+{
+    let mut sorted = v.to_vec();
+    sorted.sort_unstable();
+
+    let mut counts = BTreeMap::new();
+    for w in sorted.windows(2) {
+        *counts.entry(w[1] - w[0]).or_insert(0) += 1;
+    }
+    counts
+}
@@ -0,0 +1,113 @@
+//! Points moving with constant velocities that briefly align into a message ("The Stars
+//! Align").
+//!
+//! The message appears when the points are closest together, i.e. when the area of their
+//! bounding box is minimal.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::stars::{render, Star};
+//!
+//! let stars: Vec<Star> = "position=< 0,  2> velocity=< 1, -1>\nposition=< 4,  3> velocity=<-2, -2>"
+//!     .lines()
+//!     .map(|l| l.parse().unwrap())
+//!     .collect();
+//! let (time, points) = aoc_util::stars::converge(&stars);
+//!
+//! assert_eq!(1, time);
+//! assert_eq!("##", render(&points));
+//! ```
+
+use std::str::FromStr;
+
+use crate::geometry::{bounding_box, Point2};
+use crate::ocr;
+
+/// A point of light with a constant velocity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Star {
+    /// Position at time `0`.
+    pub position: Point2<i64>,
+    /// Change of position per second.
+    pub velocity: Point2<i64>,
+}
+
+impl Star {
+    /// Position at time `t`.
+    pub fn at(&self, t: i64) -> Point2<i64> {
+        self.position + self.velocity * t
+    }
+}
+
+impl FromStr for Star {
+    type Err = String;
+
+    /// Parses `position=< 9,  1> velocity=< 0,  2>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid star: {}", s);
+        let numbers: Vec<i64> = s
+            .split(|c: char| c != '-' && !c.is_ascii_digit())
+            .filter(|n| !n.is_empty())
+            .map(|n| n.parse().map_err(|_| err()))
+            .collect::<Result<_, _>>()?;
+
+        match numbers.as_slice() {
+            &[px, py, vx, vy] if s.contains("position=") && s.contains("velocity=") => Ok(Star {
+                position: Point2::new(px, py),
+                velocity: Point2::new(vx, vy),
+            }),
+            _ => Err(err()),
+        }
+    }
+}
+
+fn area(points: &[Point2<i64>]) -> i64 {
+    bounding_box(points).map_or(0, |(min, max)| (max.x - min.x + 1) * (max.y - min.y + 1))
+}
+
+/// Positions of all stars at time `t`.
+pub fn positions_at(stars: &[Star], t: i64) -> Vec<Point2<i64>> {
+    stars.iter().map(|s| s.at(t)).collect()
+}
+
+/// Find the earliest time `t >= 0` at which the bounding box of all stars has minimal area,
+/// assuming the area shrinks until then and grows afterwards. Returns the time and the star
+/// positions at that time.
+pub fn converge(stars: &[Star]) -> (i64, Vec<Point2<i64>>) {
+    let mut t = 0;
+    let mut current = positions_at(stars, 0);
+    loop {
+        let next = positions_at(stars, t + 1);
+        if area(&next) >= area(&current) {
+            return (t, current);
+        }
+        t += 1;
+        current = next;
+    }
+}
+
+/// Render points as rows of `#` (point) and `.` (no point), covering their bounding box.
+pub fn render(points: &[Point2<i64>]) -> String {
+    let (min, max) = match bounding_box(points) {
+        Some(b) => b,
+        None => return String::new(),
+    };
+    let width = (max.x - min.x + 1) as usize;
+    let height = (max.y - min.y + 1) as usize;
+
+    let mut rows = vec![vec!['.'; width]; height];
+    for p in points {
+        rows[(p.y - min.y) as usize][(p.x - min.x) as usize] = '#';
+    }
+    rows.into_iter()
+        .map(|r| r.into_iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Find the time at which the stars align and decode the message they form. Returns `None` if
+/// the aligned stars don't form known letters.
+pub fn message(stars: &[Star]) -> Option<(i64, String)> {
+    let (t, points) = converge(stars);
+    ocr::decode(&render(&points)).map(|text| (t, text))
+}
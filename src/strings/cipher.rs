@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+/// Rotate all ASCII letters by `shift` positions in the alphabet, preserving case. All other
+/// characters are left unchanged.
+pub fn caesar_shift(s: &str, shift: u32) -> String {
+    let shift = (shift % 26) as u8;
+    s.chars()
+        .map(|c| match c {
+            'a'..='z' => ((c as u8 - b'a' + shift) % 26 + b'a') as char,
+            'A'..='Z' => ((c as u8 - b'A' + shift) % 26 + b'A') as char,
+            _ => c,
+        })
+        .collect()
+}
+
+/// The `n` most common lowercase ASCII letters of the string, ordered by frequency. Ties are
+/// broken alphabetically. All other characters are ignored.
+pub fn frequency_checksum(s: &str, n: usize) -> String {
+    let mut counts = [0usize; 26];
+    for c in s.chars().filter(char::is_ascii_lowercase) {
+        counts[(c as u8 - b'a') as usize] += 1;
+    }
+
+    let mut letters: Vec<(usize, char)> = counts
+        .iter()
+        .enumerate()
+        .filter(|&(_, &count)| count > 0)
+        .map(|(idx, &count)| (count, (b'a' + idx as u8) as char))
+        .collect();
+    letters.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    letters.into_iter().take(n).map(|(_, c)| c).collect()
+}
+
+/// An encrypted room name of the form `aaaaa-bbb-z-y-x-123[abxyz]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Room {
+    /// Dash-separated encrypted name.
+    pub name: String,
+    /// Sector ID, which is also the shift used for encryption.
+    pub sector: u32,
+    /// Checksum in brackets.
+    pub checksum: String,
+}
+
+impl Room {
+    /// Returns `true` if the checksum consists of the five most common letters of the name.
+    pub fn is_real(&self) -> bool {
+        frequency_checksum(&self.name, 5) == self.checksum
+    }
+
+    /// Decrypt the name by shifting each letter by the sector ID. Dashes become spaces.
+    pub fn decrypt(&self) -> String {
+        caesar_shift(&self.name, self.sector).replace('-', " ")
+    }
+}
+
+impl FromStr for Room {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let invalid = || format!("invalid room: {}", s);
+
+        let open = s.find('[').ok_or_else(invalid)?;
+        let checksum = s[open + 1..].strip_suffix(']').ok_or_else(invalid)?;
+        let dash = s[..open].rfind('-').ok_or_else(invalid)?;
+        let sector = s[dash + 1..open].parse().map_err(|_| invalid())?;
+
+        Ok(Room {
+            name: s[..dash].to_string(),
+            sector,
+            checksum: checksum.to_string(),
+        })
+    }
+}
@@ -0,0 +1,13 @@
+//! String helpers: multi-pattern matching, rule predicates for "nice string" puzzles and simple
+//! ciphers.
+
+mod cipher;
+mod nice;
+mod patterns;
+
+pub use self::cipher::{caesar_shift, frequency_checksum, Room};
+pub use self::nice::{
+    contains_forbidden, find_abas, has_aba_pattern, has_abba, has_double_letter, has_n_vowels,
+    has_pair_appearing_twice, split_brackets, supports_ssl, supports_tls,
+};
+pub use self::patterns::{Match, MultiPattern};
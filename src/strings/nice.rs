@@ -0,0 +1,107 @@
+/// Returns `true` if the string contains at least `n` vowels (`aeiou`).
+pub fn has_n_vowels(s: &str, n: usize) -> bool {
+    s.chars().filter(|c| "aeiou".contains(*c)).count() >= n
+}
+
+/// Returns `true` if the string contains a letter that appears twice in a row (e.g. `xx`).
+pub fn has_double_letter(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.windows(2).any(|w| w[0] == w[1])
+}
+
+/// Returns `true` if the string contains a pair of letters that appears at least twice without
+/// overlapping (e.g. `xyxy` or `aabcdefaa`, but not `aaa`).
+pub fn has_pair_appearing_twice(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    (0..bytes.len().saturating_sub(1)).any(|i| {
+        let pair = &bytes[i..i + 2];
+        bytes[i + 2..].windows(2).any(|w| w == pair)
+    })
+}
+
+/// Returns `true` if the string contains a letter which repeats with exactly one letter in
+/// between (e.g. `xyx` or `aaa`).
+pub fn has_aba_pattern(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.windows(3).any(|w| w[0] == w[2])
+}
+
+/// Returns `true` if the string contains any of the forbidden substrings.
+pub fn contains_forbidden(s: &str, forbidden: &[&str]) -> bool {
+    forbidden.iter().any(|f| s.contains(f))
+}
+
+/// Returns `true` if the string contains an ABBA sequence: a pair of two different characters
+/// followed by the reverse of that pair (e.g. `xyyx`, but not `aaaa`).
+pub fn has_abba(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes
+        .windows(4)
+        .any(|w| w[0] != w[1] && w[0] == w[3] && w[1] == w[2])
+}
+
+/// All ABA sequences (two different characters in the pattern `aba`) in the string, as
+/// `(a, b)` pairs in order of appearance.
+pub fn find_abas(s: &str) -> Vec<(char, char)> {
+    let chars: Vec<char> = s.chars().collect();
+    chars
+        .windows(3)
+        .filter(|w| w[0] != w[1] && w[0] == w[2])
+        .map(|w| (w[0], w[1]))
+        .collect()
+}
+
+/// Split a string into the sections outside and inside square brackets, e.g.
+/// `abba[mnop]qrst` is split into `(["abba", "qrst"], ["mnop"])`. Empty sections are omitted.
+pub fn split_brackets(s: &str) -> (Vec<&str>, Vec<&str>) {
+    let mut outside = Vec::new();
+    let mut inside = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+
+    for (idx, c) in s.char_indices() {
+        if c == '[' || c == ']' {
+            let section = &s[start..idx];
+            if !section.is_empty() {
+                if depth == 0 {
+                    outside.push(section);
+                } else {
+                    inside.push(section);
+                }
+            }
+            if c == '[' {
+                depth += 1;
+            } else {
+                depth = depth.saturating_sub(1);
+            }
+            start = idx + 1;
+        }
+    }
+    let section = &s[start..];
+    if !section.is_empty() {
+        if depth == 0 {
+            outside.push(section);
+        } else {
+            inside.push(section);
+        }
+    }
+
+    (outside, inside)
+}
+
+/// Returns `true` if an IPv7 address has an ABBA outside of all bracket sections, but none
+/// inside.
+pub fn supports_tls(address: &str) -> bool {
+    let (outside, inside) = split_brackets(address);
+    outside.iter().any(|s| has_abba(s)) && !inside.iter().any(|s| has_abba(s))
+}
+
+/// Returns `true` if an IPv7 address has an ABA outside of all bracket sections with a
+/// corresponding BAB inside a bracket section.
+pub fn supports_ssl(address: &str) -> bool {
+    let (outside, inside) = split_brackets(address);
+    outside.iter().flat_map(|s| find_abas(s)).any(|(a, b)| {
+        let bab: String = [b, a, b].iter().collect();
+        inside.iter().any(|s| s.contains(&bab))
+    })
+}
@@ -0,0 +1,147 @@
+use std::collections::VecDeque;
+
+/// A single occurrence of a pattern inside a text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    /// Index of the matched pattern (in the order passed to `MultiPattern::new()`).
+    pub pattern: usize,
+    /// Byte offset of the start of the match.
+    pub start: usize,
+    /// Byte offset one past the end of the match.
+    pub end: usize,
+}
+
+/// Scanner that finds all occurrences of a set of patterns in a single pass over the text
+/// (Aho–Corasick automaton).
+///
+/// Matches may overlap. Empty patterns are ignored.
+///
+/// # Examples
+/// ```
+/// use aoc_util::strings::MultiPattern;
+///
+/// let scanner = MultiPattern::new(["ab", "cd", "pq", "xy"]);
+///
+/// assert!(scanner.is_match("haegwjzuvuyypxyu"));
+/// assert_eq!(0, scanner.count("ugknbfddgicrmopn"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MultiPattern {
+    transitions: Vec<[u32; 256]>,
+    outputs: Vec<Vec<usize>>,
+    lengths: Vec<usize>,
+}
+
+impl MultiPattern {
+    /// Build the scanner from a list of patterns.
+    pub fn new<I, S>(patterns: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut transitions: Vec<[u32; 256]> = vec![[0; 256]];
+        let mut has_child: Vec<[bool; 256]> = vec![[false; 256]];
+        let mut outputs: Vec<Vec<usize>> = vec![Vec::new()];
+        let mut lengths = Vec::new();
+
+        for (id, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref().as_bytes();
+            lengths.push(pattern.len());
+            if pattern.is_empty() {
+                continue;
+            }
+
+            let mut node = 0;
+            for &b in pattern {
+                if !has_child[node][b as usize] {
+                    transitions.push([0; 256]);
+                    has_child.push([false; 256]);
+                    outputs.push(Vec::new());
+                    transitions[node][b as usize] = (transitions.len() - 1) as u32;
+                    has_child[node][b as usize] = true;
+                }
+                node = transitions[node][b as usize] as usize;
+            }
+            outputs[node].push(id);
+        }
+
+        // Breadth-first construction of failure links, turning the trie into a full automaton.
+        let mut fail = vec![0usize; transitions.len()];
+        let mut queue = VecDeque::new();
+        for b in 0..256 {
+            if has_child[0][b] {
+                queue.push_back(transitions[0][b] as usize);
+            }
+        }
+        while let Some(node) = queue.pop_front() {
+            let inherited = outputs[fail[node]].clone();
+            outputs[node].extend(inherited);
+
+            for b in 0..256 {
+                if has_child[node][b] {
+                    let child = transitions[node][b] as usize;
+                    fail[child] = transitions[fail[node]][b] as usize;
+                    queue.push_back(child);
+                } else {
+                    transitions[node][b] = transitions[fail[node]][b];
+                }
+            }
+        }
+
+        Self {
+            transitions,
+            outputs,
+            lengths,
+        }
+    }
+
+    /// Number of patterns the scanner was built from.
+    pub fn pattern_count(&self) -> usize {
+        self.lengths.len()
+    }
+
+    fn scan<F: FnMut(Match)>(&self, text: &str, mut f: F) {
+        let mut node = 0;
+        for (idx, &b) in text.as_bytes().iter().enumerate() {
+            node = self.transitions[node][b as usize] as usize;
+            for &pattern in &self.outputs[node] {
+                let end = idx + 1;
+                f(Match {
+                    pattern,
+                    start: end - self.lengths[pattern],
+                    end,
+                });
+            }
+        }
+    }
+
+    /// All (possibly overlapping) matches, ordered by end position.
+    pub fn find_all(&self, text: &str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        self.scan(text, |m| matches.push(m));
+        matches
+    }
+
+    /// Total number of (possibly overlapping) matches.
+    pub fn count(&self, text: &str) -> usize {
+        let mut count = 0;
+        self.scan(text, |_| count += 1);
+        count
+    }
+
+    /// Number of matches per pattern, indexed by pattern.
+    pub fn count_per_pattern(&self, text: &str) -> Vec<usize> {
+        let mut counts = vec![0; self.lengths.len()];
+        self.scan(text, |m| counts[m.pattern] += 1);
+        counts
+    }
+
+    /// Returns `true` if any pattern occurs in the text.
+    pub fn is_match(&self, text: &str) -> bool {
+        let mut node = 0;
+        text.as_bytes().iter().any(|&b| {
+            node = self.transitions[node][b as usize] as usize;
+            !self.outputs[node].is_empty()
+        })
+    }
+}
@@ -0,0 +1,121 @@
+use std::fmt::{Debug, Display};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+use crate::optimize::Rng;
+
+/// A grid of `height` rows and `width` columns with cells created by `cell`.
+pub fn random_grid<T, F>(rng: &mut Rng, height: usize, width: usize, mut cell: F) -> Grid<T>
+where
+    F: FnMut(&mut Rng) -> T,
+{
+    let cells = (0..height * width).map(|_| cell(&mut *rng)).collect();
+    Grid::from_vec(width, cells).expect("grid has height * width cells")
+}
+
+/// `len` random integers in `range`.
+pub fn random_ints(rng: &mut Rng, len: usize, range: RangeInclusive<i64>) -> Vec<i64> {
+    (0..len).map(|_| rng.int_in(range.clone())).collect()
+}
+
+/// A balanced sequence of `pairs` bracket pairs of the kinds `()`, `[]`, `{}` and `<>`, like the
+/// navigation lines of 2021 day 10.
+pub fn random_brackets(rng: &mut Rng, pairs: usize) -> String {
+    const KINDS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    let mut out = String::with_capacity(2 * pairs);
+    let mut open: Vec<char> = Vec::new();
+    let mut remaining = pairs;
+    while remaining > 0 || !open.is_empty() {
+        if remaining > 0 && (open.is_empty() || rng.chance(0.5)) {
+            let &(opening, closing) = rng.choose(&KINDS);
+            out.push(opening);
+            open.push(closing);
+            remaining -= 1;
+        } else if let Some(closing) = open.pop() {
+            out.push(closing);
+        }
+    }
+    out
+}
+
+/// A nested list like `[1,[2,[]],3]` (as in 2021 day 18 or 2022 day 13) of at most `max_depth`
+/// levels, with up to `max_len` elements per list and numbers up to `99`.
+pub fn random_nested_list(rng: &mut Rng, max_depth: usize, max_len: usize) -> String {
+    let mut out = String::new();
+    push_list(rng, max_depth.max(1), max_len, &mut out);
+    out
+}
+
+fn push_list(rng: &mut Rng, depth: usize, max_len: usize, out: &mut String) {
+    out.push('[');
+    for i in 0..rng.below(max_len as u64 + 1) {
+        if i > 0 {
+            out.push(',');
+        }
+        if depth > 1 && rng.chance(0.3) {
+            push_list(rng, depth - 1, max_len, out);
+        } else {
+            out.push_str(&rng.below(100).to_string());
+        }
+    }
+    out.push(']');
+}
+
+/// Asserts that rendering `value` with `render` and parsing it back with `parse` gives `value`.
+pub fn assert_roundtrip_with<T, E, R, P>(value: &T, render: R, parse: P)
+where
+    T: PartialEq + Debug,
+    E: Debug,
+    R: FnOnce(&T) -> String,
+    P: FnOnce(&str) -> Result<T, E>,
+{
+    let rendered = render(value);
+    match parse(&rendered) {
+        Ok(parsed) => assert_eq!(
+            *value, parsed,
+            "roundtrip changed the value, rendered as {:?}",
+            rendered
+        ),
+        Err(e) => panic!(
+            "cannot parse {:?} rendered from {:?}: {:?}",
+            rendered, value, e
+        ),
+    }
+}
+
+/// Asserts that formatting `value` with `Display` and parsing it back with `FromStr` gives
+/// `value`.
+pub fn assert_roundtrip<T>(value: &T)
+where
+    T: PartialEq + Debug + Display + FromStr,
+    T::Err: Debug,
+{
+    assert_roundtrip_with(value, T::to_string, str::parse::<T>);
+}
+
+/// Run `check` on `cases` values created by `generate` from a generator seeded with `seed`.
+/// On failure, the panic message names the failing case and the seed to reproduce it.
+pub fn fuzz<T, G, C>(seed: u64, cases: usize, mut generate: G, mut check: C)
+where
+    T: Debug,
+    G: FnMut(&mut Rng) -> T,
+    C: FnMut(&T),
+{
+    let mut rng = Rng::new(seed);
+    for case in 0..cases {
+        let value = generate(&mut rng);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| check(&value)));
+        if let Err(payload) = result {
+            let message = payload
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| payload.downcast_ref::<&str>().copied())
+                .unwrap_or("");
+            panic!(
+                "fuzz case {} (seed {}) failed for {:?}: {}",
+                case, seed, value, message
+            );
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Assertion macros for checking puzzle solutions against examples, and helpers for fuzzing
+//! parsers.
+//!
+//! A solver is anything callable with the puzzle input as `&str`. `assert_solves_file!` embeds
+//! the input at compile time, with the path resolved relative to the file invoking the macro
+//! (just like `include_str!`). `aoc_test!` instead generates a whole `#[test]` that reads the
+//! input through `FileReader` at run time, with the path relative to the package root.
+//!
+//! For property-style tests, `Rng` (re-exported from `optimize`) and the `random_*` generators
+//! create reproducible random inputs, `assert_roundtrip()` checks that rendering and parsing
+//! agree, and `fuzz()` runs a check on many generated cases.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::assert_solves;
+//!
+//! fn part1(input: &str) -> usize {
+//!     input.lines().count()
+//! }
+//!
+//! assert_solves!(part1, "a\nb\nc", 3);
+//! assert_solves!(|input: &str| input.len(), "abc", 3);
+//! ```
+
+mod fuzz;
+
+pub use self::fuzz::{
+    assert_roundtrip, assert_roundtrip_with, fuzz, random_brackets, random_grid, random_ints,
+    random_nested_list,
+};
+pub use crate::optimize::Rng;
+
+/// Asserts that `solver(input)` equals `expected`.
+///
+/// On failure, the input is included in the panic message.
+#[macro_export]
+macro_rules! assert_solves {
+    ($solver:expr, $input:expr, $expected:expr $(,)?) => {{
+        let input: &str = $input;
+        assert_eq!(
+            $expected,
+            ($solver)(input),
+            "solver returned wrong answer for input {:?}",
+            input
+        );
+    }};
+}
+
+/// Asserts that `solver(input)` equals `expected`, where `input` is the content of the file at
+/// `path`, relative to the file invoking the macro.
+///
+/// On failure, the path is included in the panic message.
+#[macro_export]
+macro_rules! assert_solves_file {
+    ($solver:expr, $path:literal, $expected:expr $(,)?) => {{
+        let input: &str = include_str!($path);
+        assert_eq!(
+            $expected,
+            ($solver)(input),
+            "solver returned wrong answer for input file {}",
+            $path
+        );
+    }};
+}
+
+/// Generates a `#[test]` checking that `solver(input)` equals `expected`, where `input` is read
+/// through `FileReader` from the file at `path`, relative to the root of the calling package.
+///
+/// With just a solver name, the test is `<solver>::example`; prefix the arguments with
+/// `name:` to generate a test function `name` instead (e.g. for several examples of the same
+/// part).
+///
+/// # Examples
+/// ```no_run
+/// use aoc_util::aoc_test;
+///
+/// fn part1(input: &str) -> usize {
+///     input.lines().count()
+/// }
+///
+/// aoc_test!(part1, "examples/day01.txt", 3);
+/// aoc_test!(part1_larger: part1, "examples/day01_larger.txt", 10);
+/// ```
+#[macro_export]
+macro_rules! aoc_test {
+    ($name:ident : $solver:expr, $path:literal, $expected:expr $(,)?) => {
+        #[test]
+        fn $name() {
+            use $crate::input::FromFile;
+
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/", $path);
+            let input: String = $crate::input::FileReader::new()
+                .read_from_file(path)
+                .unwrap_or_else(|e| panic!("cannot read example {}: {}", $path, e));
+            assert_eq!(
+                $expected,
+                ($solver)(input.as_str()),
+                "solver returned wrong answer for example {}",
+                $path
+            );
+        }
+    };
+    ($solver:ident, $path:literal, $expected:expr $(,)?) => {
+        #[cfg(test)]
+        mod $solver {
+            $crate::aoc_test!(example: super::$solver, $path, $expected);
+        }
+    };
+}
@@ -0,0 +1,177 @@
+//! Ticket notes: field rules with valid ranges, your ticket and nearby tickets.
+//!
+//! # Examples
+//! ```no_run
+//! use aoc_util::input::{FileReader, FromFile};
+//! use aoc_util::tickets::Notes;
+//!
+//! let input: String = FileReader::new().read_from_file("tickets.txt").unwrap();
+//! let notes: Notes = input.parse().unwrap();
+//!
+//! println!("{}", notes.error_rate());
+//!
+//! let columns = notes.field_columns().unwrap();
+//! let product: u64 = columns
+//!     .iter()
+//!     .filter(|(name, _)| name.starts_with("departure"))
+//!     .map(|(_, &col)| notes.own[col])
+//!     .product();
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::matching;
+
+/// A ticket field with its valid ranges, e.g. `class: 1-3 or 5-7`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    /// Name of the field.
+    pub name: String,
+    /// Valid ranges (inclusive).
+    pub ranges: Vec<RangeInclusive<u64>>,
+}
+
+impl Field {
+    /// Returns `true` if the value lies in any of the field's ranges.
+    pub fn accepts(&self, value: u64) -> bool {
+        self.ranges.iter().any(|r| r.contains(&value))
+    }
+}
+
+impl FromStr for Field {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid field: {}", s);
+        let colon = s.find(':').ok_or_else(invalid)?;
+
+        let ranges = s[colon + 1..]
+            .split(" or ")
+            .map(|range| {
+                let mut bounds = range.trim().splitn(2, '-');
+                match (bounds.next(), bounds.next()) {
+                    (Some(lo), Some(hi)) => {
+                        let lo = lo.parse().map_err(|_| invalid())?;
+                        let hi = hi.parse().map_err(|_| invalid())?;
+                        Ok(lo..=hi)
+                    }
+                    _ => Err(invalid()),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Field {
+            name: s[..colon].trim().to_string(),
+            ranges,
+        })
+    }
+}
+
+/// The full ticket notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notes {
+    /// Rules for all fields.
+    pub fields: Vec<Field>,
+    /// Values of your own ticket.
+    pub own: Vec<u64>,
+    /// Values of all nearby tickets.
+    pub nearby: Vec<Vec<u64>>,
+}
+
+impl Notes {
+    fn accepted_by_any(&self, value: u64) -> bool {
+        self.fields.iter().any(|f| f.accepts(value))
+    }
+
+    /// Sum of all values on nearby tickets that are not valid for any field.
+    pub fn error_rate(&self) -> u64 {
+        self.nearby
+            .iter()
+            .flatten()
+            .filter(|&&v| !self.accepted_by_any(v))
+            .sum()
+    }
+
+    /// Nearby tickets on which every value is valid for at least one field.
+    pub fn valid_tickets(&self) -> Vec<&[u64]> {
+        self.nearby
+            .iter()
+            .filter(|t| t.iter().all(|&v| self.accepted_by_any(v)))
+            .map(|t| &t[..])
+            .collect()
+    }
+
+    /// Deduce which column corresponds to which field, discarding invalid tickets first. Your own
+    /// ticket is taken into account as well.
+    ///
+    /// Returns `None` if no unique assignment can be deduced by elimination.
+    pub fn field_columns(&self) -> Option<HashMap<&str, usize>> {
+        let mut tickets = self.valid_tickets();
+        tickets.push(&self.own);
+
+        let columns = self.own.len();
+        let candidates: Vec<BTreeSet<usize>> = self
+            .fields
+            .iter()
+            .map(|field| {
+                (0..columns)
+                    .filter(|&col| {
+                        tickets
+                            .iter()
+                            .all(|t| t.get(col).is_some_and(|&v| field.accepts(v)))
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let assignment = matching::eliminate(candidates)?;
+        Some(
+            self.fields
+                .iter()
+                .map(|f| f.name.as_str())
+                .zip(assignment)
+                .collect(),
+        )
+    }
+}
+
+fn parse_ticket(line: &str) -> Result<Vec<u64>, String> {
+    line.split(',')
+        .map(|v| {
+            v.trim()
+                .parse()
+                .map_err(|_| format!("invalid ticket value: {}", v))
+        })
+        .collect()
+}
+
+impl FromStr for Notes {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = Vec::new();
+        let mut own = None;
+        let mut nearby = Vec::new();
+        let mut section = 0;
+
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            match line {
+                "your ticket:" => section = 1,
+                "nearby tickets:" => section = 2,
+                _ => match section {
+                    0 => fields.push(line.parse()?),
+                    1 => own = Some(parse_ticket(line)?),
+                    _ => nearby.push(parse_ticket(line)?),
+                },
+            }
+        }
+
+        Ok(Notes {
+            fields,
+            own: own.ok_or_else(|| String::from("missing own ticket"))?,
+            nearby,
+        })
+    }
+}
@@ -0,0 +1,126 @@
+//! Elimination tournaments: neighbors are paired up and each pair is reduced to one element,
+//! round after round, until a single element remains.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::tournament;
+//!
+//! let bracket = tournament::knockout(vec![3, 9, 4, 1, 7], |a, b| a > b);
+//!
+//! assert_eq!(Some(&9), bracket.winner());
+//! assert_eq!(&[vec![3, 9, 4, 1, 7], vec![9, 4, 7], vec![9, 7], vec![9]], bracket.rounds());
+//! ```
+
+/// The history of a tournament: every round's remaining elements, starting with all entrants
+/// and ending with the winner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bracket<T> {
+    rounds: Vec<Vec<T>>,
+}
+
+impl<T> Bracket<T> {
+    /// The remaining elements of every round, starting with the entrants. Empty if there were
+    /// no entrants.
+    pub fn rounds(&self) -> &[Vec<T>] {
+        &self.rounds
+    }
+
+    /// The last remaining element, or `None` if there were no entrants.
+    pub fn winner(&self) -> Option<&T> {
+        self.rounds.last().and_then(|round| round.first())
+    }
+
+    /// Take the last remaining element, or `None` if there were no entrants.
+    pub fn into_winner(self) -> Option<T> {
+        self.rounds
+            .into_iter()
+            .last()
+            .and_then(|round| round.into_iter().next())
+    }
+}
+
+/// Play a tournament where each pair of neighbors `(a, b)` is replaced by `reduce(a, b)`. With
+/// an odd number of elements, the last one advances to the next round unchanged.
+pub fn play<T: Clone, F: FnMut(&T, &T) -> T>(entrants: Vec<T>, mut reduce: F) -> Bracket<T> {
+    let mut rounds = Vec::new();
+    if entrants.is_empty() {
+        return Bracket { rounds };
+    }
+
+    rounds.push(entrants);
+    while let Some(round) = rounds.last().filter(|round| round.len() > 1) {
+        let next = round
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => reduce(a, b),
+                [bye] => bye.clone(),
+                _ => unreachable!(),
+            })
+            .collect();
+        rounds.push(next);
+    }
+
+    Bracket { rounds }
+}
+
+/// Play a tournament where of each pair of neighbors `(a, b)`, `a` advances if `first_wins(a, b)`
+/// and `b` otherwise.
+pub fn knockout<T: Clone, F: FnMut(&T, &T) -> bool>(
+    entrants: Vec<T>,
+    mut first_wins: F,
+) -> Bracket<T> {
+    play(entrants, |a, b| {
+        if first_wins(a, b) {
+            a.clone()
+        } else {
+            b.clone()
+        }
+    })
+}
+
+/// Reduce `items` pairwise like `play()`, without keeping the history.
+pub fn reduce<T, F: FnMut(T, T) -> T>(mut items: Vec<T>, mut f: F) -> Option<T> {
+    while items.len() > 1 {
+        let mut next = Vec::with_capacity(items.len().div_ceil(2));
+        let mut iter = items.into_iter();
+        while let Some(a) = iter.next() {
+            next.push(match iter.next() {
+                Some(b) => f(a, b),
+                None => a,
+            });
+        }
+        items = next;
+    }
+    items.pop()
+}
+
+/// Position (starting at 1) of the last one remaining of `n` in a circle, where in turn everyone
+/// eliminates their left neighbor (2016 day 19, part one).
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn last_remaining(n: u64) -> u64 {
+    assert!(n > 0, "circle must not be empty");
+    let highest = 1 << (63 - n.leading_zeros());
+    2 * (n - highest) + 1
+}
+
+/// Position (starting at 1) of the last one remaining of `n` in a circle, where in turn everyone
+/// eliminates the one directly across (the left one of two) (2016 day 19, part two).
+///
+/// # Panics
+/// Panics if `n` is zero.
+pub fn last_remaining_across(n: u64) -> u64 {
+    assert!(n > 0, "circle must not be empty");
+    let mut power = 1;
+    while power * 3 <= n {
+        power *= 3;
+    }
+    if n == power {
+        n
+    } else if n - power <= power {
+        n - power
+    } else {
+        2 * n - 3 * power
+    }
+}
@@ -0,0 +1,72 @@
+//! Trees encoded as a flat stream of numbers ("Memory Maneuver").
+//!
+//! Every node consists of a header (number of children, number of metadata entries), followed by
+//! its children and then its metadata entries.
+
+use super::{NodeId, Tree};
+
+/// Parse a license tree from whitespace-separated numbers. The value of each node is its list of
+/// metadata entries; the first node is the root.
+///
+/// # Failures
+/// Returns an error if the input is not a number stream encoding exactly one tree.
+pub fn parse_license(input: &str) -> Result<Tree<Vec<u32>>, String> {
+    let numbers: Vec<u32> = input
+        .split_whitespace()
+        .map(|n| n.parse().map_err(|_| format!("invalid number: {}", n)))
+        .collect::<Result<_, _>>()?;
+
+    let mut tree = Tree::new();
+    let mut rest = numbers.as_slice();
+    parse_node(&mut tree, &mut rest)?;
+    if !rest.is_empty() {
+        return Err(format!(
+            "{} trailing numbers after the root node",
+            rest.len()
+        ));
+    }
+
+    Ok(tree)
+}
+
+fn parse_node(tree: &mut Tree<Vec<u32>>, rest: &mut &[u32]) -> Result<NodeId, String> {
+    let (children, metadata) = match *rest {
+        [children, metadata, ..] => (*children, *metadata as usize),
+        _ => return Err(String::from("unexpected end of input in node header")),
+    };
+    *rest = &rest[2..];
+
+    let node = tree.add_node(Vec::new());
+    for _ in 0..children {
+        let child = parse_node(tree, rest)?;
+        tree.add_child(node, child);
+    }
+
+    if rest.len() < metadata {
+        return Err(String::from("unexpected end of input in metadata"));
+    }
+    tree.value_mut(node).extend_from_slice(&rest[..metadata]);
+    *rest = &rest[metadata..];
+
+    Ok(node)
+}
+
+/// Sum of all metadata entries in the subtree of `node`.
+pub fn metadata_sum(tree: &Tree<Vec<u32>>, node: NodeId) -> u64 {
+    tree.subtree_sum(node, |m| m.iter().map(|&v| u64::from(v)).sum())
+}
+
+/// Value of `node`: the sum of its metadata entries if it has no children, otherwise the sum of
+/// the values of the children referenced (1-based) by its metadata entries. References to
+/// missing children are skipped.
+pub fn license_value(tree: &Tree<Vec<u32>>, node: NodeId) -> u64 {
+    let children = tree.children(node);
+    if children.is_empty() {
+        return tree.value(node).iter().map(|&v| u64::from(v)).sum();
+    }
+    tree.value(node)
+        .iter()
+        .filter_map(|&i| (i as usize).checked_sub(1).and_then(|i| children.get(i)))
+        .map(|&child| license_value(tree, child))
+        .sum()
+}
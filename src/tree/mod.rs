@@ -0,0 +1,155 @@
+//! Rooted trees stored in an arena, plus puzzle-specific tree analyses.
+//!
+//! # Examples
+//! ```
+//! use aoc_util::tree::Tree;
+//!
+//! let mut tree = Tree::new();
+//! let root = tree.add_node(1);
+//! let child = tree.add_node(2);
+//! tree.add_child(root, child);
+//!
+//! assert_eq!(Some(root), tree.root());
+//! assert_eq!(3, tree.subtree_sum(root, |&v| v));
+//! ```
+
+mod license;
+pub mod tower;
+
+pub use self::license::{license_value, metadata_sum, parse_license};
+
+/// Handle of a node in a `Tree`.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<T> {
+    value: T,
+    parent: Option<NodeId>,
+    children: Vec<NodeId>,
+}
+
+/// A rooted tree (or forest) whose nodes are addressed by `NodeId`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tree<T> {
+    nodes: Vec<Node<T>>,
+}
+
+impl<T> Default for Tree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Tree<T> {
+    /// Create new, empty `Tree`.
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+
+    /// Number of nodes.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the tree has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Add a node without parent. Returns its handle.
+    pub fn add_node(&mut self, value: T) -> NodeId {
+        self.nodes.push(Node {
+            value,
+            parent: None,
+            children: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Make `child` a child of `parent`.
+    ///
+    /// # Panics
+    /// Panics if either handle is invalid or `child` already has a parent.
+    pub fn add_child(&mut self, parent: NodeId, child: NodeId) {
+        assert!(parent < self.nodes.len(), "invalid parent handle");
+        assert!(
+            self.nodes[child].parent.is_none(),
+            "node already has a parent"
+        );
+        self.nodes[child].parent = Some(parent);
+        self.nodes[parent].children.push(child);
+    }
+
+    /// The value of a node.
+    pub fn value(&self, id: NodeId) -> &T {
+        &self.nodes[id].value
+    }
+
+    /// Mutable reference to the value of a node.
+    pub fn value_mut(&mut self, id: NodeId) -> &mut T {
+        &mut self.nodes[id].value
+    }
+
+    /// The parent of a node, if any.
+    pub fn parent(&self, id: NodeId) -> Option<NodeId> {
+        self.nodes[id].parent
+    }
+
+    /// The children of a node, in insertion order.
+    pub fn children(&self, id: NodeId) -> &[NodeId] {
+        &self.nodes[id].children
+    }
+
+    /// All nodes without parent.
+    pub fn roots(&self) -> Vec<NodeId> {
+        (0..self.nodes.len())
+            .filter(|&id| self.nodes[id].parent.is_none())
+            .collect()
+    }
+
+    /// The root, if the tree has exactly one node without parent.
+    pub fn root(&self) -> Option<NodeId> {
+        match self.roots().as_slice() {
+            [root] => Some(*root),
+            _ => None,
+        }
+    }
+
+    /// Number of edges between the node and its root.
+    pub fn depth(&self, mut id: NodeId) -> usize {
+        let mut depth = 0;
+        while let Some(parent) = self.nodes[id].parent {
+            id = parent;
+            depth += 1;
+        }
+        depth
+    }
+
+    /// The sum of `f` over all nodes in the subtree rooted at `id`.
+    pub fn subtree_sum<F: Fn(&T) -> u64>(&self, id: NodeId, f: F) -> u64 {
+        let mut sum = 0;
+        let mut stack = vec![id];
+        while let Some(node) = stack.pop() {
+            sum += f(&self.nodes[node].value);
+            stack.extend_from_slice(&self.nodes[node].children);
+        }
+        sum
+    }
+
+    /// All nodes of the subtree rooted at `id` such that every node comes after its children.
+    pub fn post_order(&self, id: NodeId) -> Vec<NodeId> {
+        let mut order = Vec::new();
+        let mut stack = vec![(id, false)];
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                order.push(node);
+            } else {
+                stack.push((node, true));
+                for &child in self.nodes[node].children.iter().rev() {
+                    stack.push((child, false));
+                }
+            }
+        }
+        order
+    }
+}
@@ -0,0 +1,142 @@
+//! Program towers of the form `fwft (72) -> ktlj, cntj, xhth` (2017 day 7).
+//!
+//! # Examples
+//! ```
+//! use aoc_util::tree::tower::Tower;
+//!
+//! let tower: Tower = "pbga (66)\nxhth (57)\nebii (61)\nhavc (66)\nktlj (57)\n\
+//!                     fwft (72) -> ktlj, cntj, xhth\nqoyq (66)\npadx (45) -> pbga, havc, qoyq\n\
+//!                     tknk (41) -> ugml, padx, fwft\njptl (61)\nugml (68) -> gyxo, ebii, jptl\n\
+//!                     gyxo (61)\ncntj (57)"
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(Some("tknk"), tower.root_name());
+//! assert_eq!(Some(("ugml", 60)), tower.correct_weight());
+//! ```
+
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use super::{NodeId, Tree};
+
+/// A program in the tower.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    /// Name of the program.
+    pub name: String,
+    /// Weight of the program itself (without the programs it holds).
+    pub weight: u64,
+}
+
+/// A tower of programs, each holding the programs above it.
+#[derive(Debug, Clone)]
+pub struct Tower {
+    tree: Tree<Program>,
+}
+
+impl Tower {
+    /// The underlying tree.
+    pub fn tree(&self) -> &Tree<Program> {
+        &self.tree
+    }
+
+    /// Name of the bottom program.
+    pub fn root_name(&self) -> Option<&str> {
+        self.tree.root().map(|id| self.tree.value(id).name.as_str())
+    }
+
+    /// Total weight of a program and all programs it holds.
+    pub fn total_weight(&self, id: NodeId) -> u64 {
+        self.tree.subtree_sum(id, |p| p.weight)
+    }
+
+    /// Find the single program with the wrong weight. Returns its name and the weight it would
+    /// need for the tower to be balanced.
+    ///
+    /// Returns `None` if the tower is balanced or the odd program cannot be determined (e.g.
+    /// because the unbalanced program holds only two programs).
+    pub fn correct_weight(&self) -> Option<(&str, u64)> {
+        let root = self.tree.root()?;
+        let mut totals = vec![0; self.tree.len()];
+
+        // Children come first in post order, so the first unbalanced node is the deepest one.
+        for id in self.tree.post_order(root) {
+            let children = self.tree.children(id);
+            totals[id] =
+                self.tree.value(id).weight + children.iter().map(|&c| totals[c]).sum::<u64>();
+
+            if let Some((odd, expected)) = odd_one_out(children, &totals) {
+                let delta = expected as i64 - totals[odd] as i64;
+                let program = self.tree.value(odd);
+                return Some((&program.name, (program.weight as i64 + delta) as u64));
+            }
+        }
+        None
+    }
+}
+
+/// Find the child whose total differs from all others. Returns the child and the common total.
+fn odd_one_out(children: &[NodeId], totals: &[u64]) -> Option<(NodeId, u64)> {
+    if children.len() < 3 {
+        return None;
+    }
+    let common = if totals[children[0]] == totals[children[1]] {
+        totals[children[0]]
+    } else {
+        totals[children[2]]
+    };
+    children
+        .iter()
+        .find(|&&c| totals[c] != common)
+        .map(|&c| (c, common))
+}
+
+impl FromStr for Tower {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tree = Tree::new();
+        let mut ids = HashMap::new();
+        let mut edges = Vec::new();
+
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let invalid = || format!("invalid program: {}", line);
+            let mut parts = line.splitn(2, "->");
+            let program = parts.next().ok_or_else(invalid)?.trim();
+
+            let open = program.find('(').ok_or_else(invalid)?;
+            let name = program[..open].trim().to_string();
+            let weight = program[open + 1..]
+                .trim_end_matches(')')
+                .trim()
+                .parse()
+                .map_err(|_| invalid())?;
+
+            if let Some(children) = parts.next() {
+                for child in children.split(',') {
+                    edges.push((name.clone(), child.trim().to_string()));
+                }
+            }
+            let id = tree.add_node(Program {
+                name: name.clone(),
+                weight,
+            });
+            if ids.insert(name, id).is_some() {
+                return Err(format!("duplicate program: {}", line));
+            }
+        }
+
+        for (parent, child) in edges {
+            let child_id = *ids
+                .get(&child)
+                .ok_or_else(|| format!("unknown program: {}", child))?;
+            if tree.parent(child_id).is_some() {
+                return Err(format!("program held twice: {}", child));
+            }
+            tree.add_child(ids[&parent], child_id);
+        }
+
+        Ok(Tower { tree })
+    }
+}
@@ -0,0 +1,130 @@
+//! Streaming validation, where every element of a sequence is checked against a sliding window
+//! of the elements before it (e.g. the XMAS preamble check of 2020 day 9).
+//!
+//! # Examples
+//! ```
+//! use aoc_util::validate::{StreamChecker, SumOfTwo};
+//!
+//! let numbers = [35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219];
+//! let mut checker = StreamChecker::new(5, SumOfTwo);
+//!
+//! assert_eq!(Some(127), checker.first_invalid(numbers.iter().copied()));
+//! ```
+
+use std::collections::VecDeque;
+use std::ops::Add;
+
+use crate::slices::pair_in_sorted;
+
+/// A rule checking a new element against the window of elements before it.
+///
+/// Closures `Fn(&[T], &T) -> bool` are rules as well.
+pub trait Validator<T> {
+    /// Returns `true` if `value` is valid, given the `window` of previous elements (oldest
+    /// first).
+    fn validate(&self, window: &[T], value: &T) -> bool;
+}
+
+impl<T, F: Fn(&[T], &T) -> bool> Validator<T> for F {
+    fn validate(&self, window: &[T], value: &T) -> bool {
+        self(window, value)
+    }
+}
+
+/// Valid elements are the sum of two elements of the window with different values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SumOfTwo;
+
+impl<T: Copy + Ord + Add<Output = T>> Validator<T> for SumOfTwo {
+    fn validate(&self, window: &[T], value: &T) -> bool {
+        let mut sorted = window.to_vec();
+        sorted.sort_unstable();
+        sorted.dedup();
+        pair_in_sorted(&sorted, *value).is_some()
+    }
+}
+
+/// Valid elements differ from all elements of the window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Distinct;
+
+impl<T: PartialEq> Validator<T> for Distinct {
+    fn validate(&self, window: &[T], value: &T) -> bool {
+        !window.contains(value)
+    }
+}
+
+/// Result of checking one element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Check {
+    /// The window wasn't full yet, so the element wasn't checked.
+    Preamble,
+    /// The element passed the rule.
+    Valid,
+    /// The element failed the rule.
+    Invalid,
+}
+
+/// Checks a stream of elements against a window of the `size` elements before each one.
+///
+/// The first `size` elements form the preamble and are not checked. Every element (valid or
+/// not) becomes part of the window afterwards.
+#[derive(Debug, Clone)]
+pub struct StreamChecker<T, V> {
+    size: usize,
+    window: VecDeque<T>,
+    validator: V,
+}
+
+impl<T: Clone, V: Validator<T>> StreamChecker<T, V> {
+    /// Create new `StreamChecker` with a window of `size` elements.
+    pub fn new(size: usize, validator: V) -> Self {
+        Self {
+            size,
+            window: VecDeque::with_capacity(size + 1),
+            validator,
+        }
+    }
+
+    /// The current window, oldest element first.
+    pub fn window(&mut self) -> &[T] {
+        self.window.make_contiguous()
+    }
+
+    /// Check the next element of the stream.
+    pub fn push(&mut self, value: T) -> Check {
+        let check = if self.window.len() < self.size {
+            Check::Preamble
+        } else if self
+            .validator
+            .validate(self.window.make_contiguous(), &value)
+        {
+            Check::Valid
+        } else {
+            Check::Invalid
+        };
+
+        self.window.push_back(value);
+        if self.window.len() > self.size {
+            self.window.pop_front();
+        }
+        check
+    }
+
+    /// Check the elements of `values` until one is invalid, and return that one.
+    pub fn first_invalid<I: IntoIterator<Item = T>>(&mut self, values: I) -> Option<T> {
+        values
+            .into_iter()
+            .find(|value| self.push(value.clone()) == Check::Invalid)
+    }
+
+    /// Check all elements of `values` and return the positions (in `values`) of the invalid ones.
+    pub fn invalid_positions<I: IntoIterator<Item = T>>(&mut self, values: I) -> Vec<usize> {
+        values
+            .into_iter()
+            .enumerate()
+            .filter(|(_, value)| self.push(value.clone()) == Check::Invalid)
+            .map(|(i, _)| i)
+            .collect()
+    }
+}
@@ -0,0 +1,104 @@
+//! Areas of Manhattan-distance regions around a set of points ("Chronal Coordinates").
+//!
+//! # Examples
+//! ```
+//! use aoc_util::geometry::Point2;
+//! use aoc_util::voronoi;
+//!
+//! let points: Vec<Point2<i64>> = vec![(1, 1), (1, 6), (8, 3), (3, 4), (5, 5), (8, 9)]
+//!     .into_iter()
+//!     .map(Point2::from)
+//!     .collect();
+//!
+//! assert_eq!(Some(17), voronoi::largest_finite_area(&points));
+//! assert_eq!(16, voronoi::count_within_total_distance(&points, 32));
+//! ```
+
+use crate::geometry::{bounding_box, Point2};
+
+/// Index of the unique closest point to `cell`, or `None` on a tie.
+fn closest(points: &[Point2<i64>], cell: Point2<i64>) -> Option<usize> {
+    let mut best = None;
+    let mut best_distance = u64::MAX;
+    let mut tie = false;
+    for (i, &p) in points.iter().enumerate() {
+        let d = p.manhattan(cell);
+        if d < best_distance {
+            best = Some(i);
+            best_distance = d;
+            tie = false;
+        } else if d == best_distance {
+            tie = true;
+        }
+    }
+    if tie {
+        None
+    } else {
+        best
+    }
+}
+
+/// Number of cells closest to each point (cells with ties belong to no point), or `None` for
+/// points whose region is infinite.
+///
+/// Only the bounding box of the points has to be scanned: a region is infinite exactly if it
+/// reaches the border of the bounding box, since moving outwards from there never brings any
+/// other point closer.
+pub fn areas(points: &[Point2<i64>]) -> Vec<Option<usize>> {
+    let (min, max) = match bounding_box(points) {
+        Some(b) => b,
+        None => return Vec::new(),
+    };
+
+    let mut areas = vec![Some(0); points.len()];
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            if let Some(i) = closest(points, Point2::new(x, y)) {
+                let border = x == min.x || x == max.x || y == min.y || y == max.y;
+                areas[i] = if border {
+                    None
+                } else {
+                    areas[i].map(|a| a + 1)
+                };
+            }
+        }
+    }
+    areas
+}
+
+/// Size of the largest finite region.
+pub fn largest_finite_area(points: &[Point2<i64>]) -> Option<usize> {
+    areas(points).into_iter().flatten().max()
+}
+
+/// Sum of the distances from `v` to all `values`, for every `v` in `from..=to`.
+fn distance_sums(values: &[i64], from: i64, to: i64) -> Vec<i64> {
+    (from..=to)
+        .map(|v| values.iter().map(|&w| (v - w).abs()).sum())
+        .collect()
+}
+
+/// Number of cells whose total distance to all points is less than `limit`.
+///
+/// The total distance separates into a horizontal and a vertical part, so both are computed
+/// per column and per row, and the pairs below the limit are counted without visiting every
+/// cell.
+pub fn count_within_total_distance(points: &[Point2<i64>], limit: i64) -> usize {
+    let (min, max) = match bounding_box(points) {
+        Some(b) => b,
+        None => return 0,
+    };
+    // Beyond this margin, the distance from every point alone exceeds the limit.
+    let margin = limit / points.len() as i64 + 1;
+
+    let xs: Vec<i64> = points.iter().map(|p| p.x).collect();
+    let ys: Vec<i64> = points.iter().map(|p| p.y).collect();
+    let columns = distance_sums(&xs, min.x - margin, max.x + margin);
+    let mut rows = distance_sums(&ys, min.y - margin, max.y + margin);
+    rows.sort_unstable();
+
+    columns
+        .iter()
+        .map(|&dx| rows.partition_point(|&dy| dx + dy < limit))
+        .sum()
+}
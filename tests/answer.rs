@@ -0,0 +1,37 @@
+use aoc_util::answer::Answer;
+use aoc_util::grid::Grid;
+use aoc_util::ocr::FONT_6X10;
+
+#[test]
+fn from_common_types() {
+    assert_eq!(Answer::Integer(-3), Answer::from(-3i64));
+    assert_eq!(Answer::Integer(7), Answer::from(7usize));
+    assert_eq!(Answer::Integer(u64::MAX as i128), Answer::from(u64::MAX));
+    assert_eq!(
+        Answer::Text(String::from("xyz")),
+        Answer::from(String::from("xyz"))
+    );
+    assert_eq!(Answer::Text(String::from("q")), Answer::from('q'));
+}
+
+#[test]
+fn display_and_submission() {
+    assert_eq!("1234", Answer::from(1234).to_string());
+    assert_eq!(Some(String::from("1234")), Answer::from(1234).submission());
+    let grid = Answer::Grid(String::from("#.\n.#"));
+    assert_eq!("\n#.\n.#", grid.to_string());
+    assert_eq!(None, grid.submission());
+}
+
+#[test]
+fn decode_grid_letters() {
+    let rendered = FONT_6X10.render("HZ").unwrap();
+    let grid = Grid::parse(&rendered, |c| c == '#').unwrap();
+    assert_eq!(Answer::Text(String::from("HZ")), Answer::from(grid));
+
+    let noise = Grid::parse("#.#\n.#.\n#.#", |c| c == '#').unwrap();
+    assert_eq!(
+        Answer::Grid(String::from("#.#\n.#.\n#.#")),
+        Answer::from(&noise)
+    );
+}
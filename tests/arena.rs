@@ -0,0 +1,67 @@
+use aoc_util::collections::{Arena, Id};
+
+/// A nested packet like `[1,[2,3]]`.
+enum Packet {
+    Number(u32),
+    List(Vec<Id<Packet>>),
+}
+
+fn parse(arena: &mut Arena<Packet>, s: &mut std::iter::Peekable<std::str::Chars>) -> Id<Packet> {
+    if s.peek() == Some(&'[') {
+        s.next();
+        let mut items = Vec::new();
+        while s.peek() != Some(&']') {
+            items.push(parse(arena, s));
+            if s.peek() == Some(&',') {
+                s.next();
+            }
+        }
+        s.next();
+        arena.alloc(Packet::List(items))
+    } else {
+        let mut n = 0;
+        while let Some(d) = s.peek().and_then(|c| c.to_digit(10)) {
+            n = n * 10 + d;
+            s.next();
+        }
+        arena.alloc(Packet::Number(n))
+    }
+}
+
+fn sum(arena: &Arena<Packet>, id: Id<Packet>) -> u32 {
+    match &arena[id] {
+        Packet::Number(n) => *n,
+        Packet::List(items) => items.iter().map(|&item| sum(arena, item)).sum(),
+    }
+}
+
+#[test]
+fn build_nested_structure() {
+    let mut arena = Arena::new();
+    let root = parse(&mut arena, &mut "[1,[2,3],[[10]],[]]".chars().peekable());
+    assert_eq!(16, sum(&arena, root));
+    assert_eq!(9, arena.len());
+    assert_eq!(arena.len() - 1, root.index());
+}
+
+#[test]
+fn access_by_handle() {
+    let mut arena = Arena::with_capacity(2);
+    assert!(arena.is_empty());
+    let a = arena.alloc(String::from("a"));
+    let b = arena.alloc(String::from("b"));
+    arena[a].push('!');
+    arena.get_mut(b).unwrap().push('?');
+    assert_eq!(Some(&String::from("a!")), arena.get(a));
+    assert_eq!(
+        vec![(a, "a!"), (b, "b?")],
+        arena
+            .iter()
+            .map(|(id, s)| (id, s.as_str()))
+            .collect::<Vec<_>>()
+    );
+    assert!(a < b);
+
+    let other: Arena<String> = Arena::new();
+    assert_eq!(None, other.get(a));
+}
@@ -0,0 +1,129 @@
+extern crate aoc_util;
+
+use std::collections::{HashMap, HashSet};
+
+use aoc_util::automaton::{
+    count_in_rows, elementary_rows, evolved_virus_rule, life_rule, parse_nodes, parse_row,
+    step_sparse, virus_rule, Node, Pots, Turn, Walker, RULE_90,
+};
+use aoc_util::geometry::Point2;
+use aoc_util::input::{FileReader, FromFile};
+
+#[test]
+fn sparse_glider_moves() {
+    let neighbors = |&(x, y): &(i64, i64)| {
+        (-1..=1)
+            .flat_map(move |dx| (-1..=1).map(move |dy| (x + dx, y + dy)))
+            .filter(move |&p| p != (x, y))
+    };
+    let glider: HashSet<(i64, i64)> = vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+        .into_iter()
+        .collect();
+
+    let mut cells = glider.clone();
+    for _ in 0..4 {
+        cells = step_sparse(&cells, neighbors, life_rule(&[3], &[2, 3]));
+    }
+    let moved: HashSet<(i64, i64)> = glider.iter().map(|&(x, y)| (x + 1, y + 1)).collect();
+    assert_eq!(moved, cells);
+}
+
+#[test]
+fn trap_rows() {
+    let rows: Vec<String> = elementary_rows(parse_row("..^^.", '^'), RULE_90)
+        .take(3)
+        .map(|row| row.iter().map(|&t| if t { '^' } else { '.' }).collect())
+        .collect();
+    assert_eq!(vec!["..^^.", ".^^^^", "^^..^"], rows);
+}
+
+#[test]
+fn safe_tiles() {
+    let first = parse_row(".^^.^.^^^^", '^');
+    assert_eq!(38, count_in_rows(first, RULE_90, 10, false));
+}
+
+fn virus_map() -> HashMap<Point2<i64>, Node> {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/virus.txt")
+        .unwrap();
+    parse_nodes(&input)
+}
+
+#[test]
+fn virus_carrier() {
+    let up = Point2::new(0, -1);
+    let mut walker = Walker::new(virus_map(), Point2::new(0, 0), up, virus_rule);
+    assert_eq!(5, walker.run(7, &Node::Infected));
+    assert_eq!(41, walker.run(63, &Node::Infected) + 5);
+    assert_eq!(Point2::new(1, -1), walker.position());
+
+    let mut walker = Walker::new(virus_map(), Point2::new(0, 0), up, virus_rule);
+    assert_eq!(5587, walker.run(10_000, &Node::Infected));
+}
+
+#[test]
+fn evolved_virus_carrier() {
+    let up = Point2::new(0, -1);
+    let mut walker = Walker::new(virus_map(), Point2::new(0, 0), up, evolved_virus_rule);
+    assert_eq!(26, walker.run(100, &Node::Infected));
+}
+
+#[test]
+fn langtons_ant() {
+    let rule = |&black: &bool| {
+        if black {
+            (Turn::Left, false)
+        } else {
+            (Turn::Right, true)
+        }
+    };
+    let mut ant = Walker::new(HashMap::new(), Point2::new(0, 0), Point2::new(0, -1), rule);
+    ant.run(4, &true);
+    assert_eq!(Point2::new(0, 0), ant.position());
+    assert!(ant.state(Point2::new(1, 1)));
+    assert!(!ant.state(Point2::new(-1, 0)));
+}
+
+fn pots() -> Pots {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/pots.txt")
+        .unwrap();
+    input.parse().unwrap()
+}
+
+#[test]
+fn pot_generations() {
+    let mut pots = pots();
+    pots.step();
+    // Generation 1: ...#...#....#.....#..#..#..#...........
+    assert_eq!(0, pots.offset());
+    assert_eq!(7, pots.count());
+    assert!(pots.has_plant(4) && !pots.has_plant(3));
+
+    pots.run(19);
+    assert_eq!(20, pots.generation());
+    assert_eq!(-2, pots.offset());
+    assert_eq!(325, pots.sum());
+}
+
+#[test]
+fn pot_drift_extrapolation() {
+    let mut simulated = pots();
+    simulated.run(500);
+
+    let mut extrapolated = pots();
+    assert_eq!(simulated.sum(), extrapolated.sum_after(500));
+    assert_eq!(simulated.offset(), extrapolated.offset());
+    assert_eq!(500, extrapolated.generation());
+
+    // The example settles into a glider moving one pot per generation.
+    assert_eq!(simulated.sum() + 20 * 9_500, pots().sum_after(10_000));
+}
+
+#[test]
+fn pots_invalid_rules() {
+    assert!("initial state: #..#\n\n..... => #".parse::<Pots>().is_err());
+    assert!("initial state: #..#\n\n.... => #".parse::<Pots>().is_err());
+    assert!("#..#\n\n...## => #".parse::<Pots>().is_err());
+}
@@ -0,0 +1,75 @@
+use aoc_util::bench::{self, cached_input};
+use aoc_util::bench_solvers;
+
+/// Minimal stand-in for criterion's `Criterion`, recording benchmark names and results.
+#[derive(Default)]
+struct Harness {
+    results: Vec<(String, u64)>,
+}
+
+struct Bencher {
+    result: Option<u64>,
+}
+
+impl Bencher {
+    fn iter<F: FnMut() -> u64>(&mut self, mut routine: F) {
+        for _ in 0..3 {
+            self.result = Some(routine());
+        }
+    }
+}
+
+impl Harness {
+    fn bench_function<S: Into<String>, F: FnMut(&mut Bencher)>(&mut self, name: S, mut f: F) {
+        let mut bencher = Bencher { result: None };
+        f(&mut bencher);
+        self.results.push((name.into(), bencher.result.unwrap()));
+    }
+}
+
+fn parse(input: &str) -> Vec<u64> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn sum(numbers: &[u64]) -> u64 {
+    numbers.iter().sum()
+}
+
+fn max(numbers: &[u64]) -> u64 {
+    *numbers.iter().max().unwrap()
+}
+
+#[test]
+fn cache_inputs() {
+    let first = cached_input("tests/inputs/newline_delimited.txt").unwrap();
+    let second = cached_input("tests/inputs/newline_delimited.txt").unwrap();
+    assert!(std::ptr::eq(first, second));
+    assert!(cached_input("tests/inputs/does_not_exist.txt").is_err());
+}
+
+#[test]
+fn run_solver_routine() {
+    let mut routine = bench::solver("abc", str::len);
+    assert_eq!(3, routine());
+    assert_eq!(3, routine());
+}
+
+#[test]
+fn register_benchmarks() {
+    let mut harness = Harness::default();
+    bench_solvers!(
+        harness,
+        "day01",
+        "tests/inputs/newline_delimited.txt",
+        parse,
+        sum,
+        max
+    );
+    assert_eq!(
+        vec![
+            (String::from("day01 sum"), 108),
+            (String::from("day01 max"), 42)
+        ],
+        harness.results
+    );
+}
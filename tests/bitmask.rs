@@ -0,0 +1,47 @@
+extern crate aoc_util;
+
+use aoc_util::bitmask::{run_address_masks, run_value_masks, Instruction, Mask};
+use aoc_util::input::{FileReader, FromFile};
+
+#[test]
+fn apply_value_mask() {
+    let mask: Mask = "XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X".parse().unwrap();
+    assert_eq!(36, mask.width());
+    assert_eq!(73, mask.apply(11));
+    assert_eq!(101, mask.apply(101));
+    assert_eq!(64, mask.apply(0));
+    assert!("XX2".parse::<Mask>().is_err());
+}
+
+#[test]
+fn floating_addresses() {
+    let mask: Mask = "00000000000000000000000000000000X0XX".parse().unwrap();
+    let addresses = mask.addresses(26);
+    assert_eq!(8, addresses.size_hint().0);
+    assert_eq!(
+        vec![16, 17, 18, 19, 24, 25, 26, 27],
+        addresses.collect::<Vec<u64>>()
+    );
+
+    let mask: Mask = "0000".parse().unwrap();
+    assert_eq!(vec![5], mask.addresses(5).collect::<Vec<u64>>());
+}
+
+#[test]
+fn run_program() {
+    let program: Vec<Instruction> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/bitmask.txt")
+        .unwrap();
+    assert_eq!(208, run_address_masks(&program));
+
+    let program: Vec<Instruction> = vec![
+        "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X"
+            .parse()
+            .unwrap(),
+        "mem[8] = 11".parse().unwrap(),
+        "mem[7] = 101".parse().unwrap(),
+        "mem[8] = 0".parse().unwrap(),
+    ];
+    assert_eq!(165, run_value_masks(&program));
+}
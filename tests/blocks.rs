@@ -0,0 +1,36 @@
+extern crate aoc_util;
+
+use aoc_util::blocks::{intersection, intersection_counts, split_blocks, union, union_counts};
+use aoc_util::input::{FileReader, FromFile};
+
+fn customs() -> String {
+    FileReader::new()
+        .read_from_file("tests/inputs/customs.txt")
+        .unwrap()
+}
+
+#[test]
+fn split_into_blocks() {
+    let input = customs();
+    let blocks = split_blocks(&input);
+    assert_eq!(5, blocks.len());
+    assert_eq!(vec!["ab", "ac"], blocks[2]);
+
+    assert_eq!(2, split_blocks("\n\na\n \n\n\nb\n\n").len());
+    assert!(split_blocks("").is_empty());
+}
+
+#[test]
+fn anyone_answered() {
+    assert_eq!(vec![3, 3, 3, 1, 1], union_counts(&customs()));
+    assert_eq!(
+        vec!['a', 'b', 'c'],
+        union(&["ab", "ac"]).into_iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn everyone_answered() {
+    assert_eq!(vec![3, 0, 1, 1, 1], intersection_counts(&customs()));
+    assert!(intersection(&[]).is_empty());
+}
@@ -0,0 +1,48 @@
+use aoc_util::bridge::{
+    best_chain, for_each_chain, longest_strongest, strongest, Chain, Component,
+};
+
+fn example() -> Vec<Component> {
+    "0/2\n2/2\n2/3\n3/4\n3/5\n0/1\n10/1\n9/10"
+        .lines()
+        .map(|l| l.parse().unwrap())
+        .collect()
+}
+
+#[test]
+fn parse_component() {
+    assert_eq!(Ok(Component::new(3, 5)), " 3/5".parse());
+    assert!("3-5".parse::<Component>().is_err());
+    assert!("3/x".parse::<Component>().is_err());
+}
+
+#[test]
+fn strongest_bridge() {
+    assert_eq!(31, strongest(&example(), 0));
+}
+
+#[test]
+fn longest_bridge() {
+    let components = example();
+    assert_eq!(19, longest_strongest(&components, 0));
+    assert_eq!(
+        Chain {
+            length: 4,
+            strength: 19
+        },
+        best_chain(&components, 0, |c| (c.length, c.strength))
+    );
+}
+
+#[test]
+fn all_chains_visited() {
+    let mut count = 0;
+    for_each_chain(&example(), 0, |_| count += 1);
+    // The empty chain plus the 11 chains listed in the puzzle.
+    assert_eq!(12, count);
+}
+
+#[test]
+fn no_matching_start() {
+    assert_eq!(0, strongest(&example(), 7));
+}
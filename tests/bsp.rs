@@ -0,0 +1,30 @@
+extern crate aoc_util;
+
+use aoc_util::bsp::{find_missing_id, Decoder};
+use aoc_util::input::{FileReader, FromFile};
+
+#[test]
+fn decode_boarding_passes() {
+    let passes: Vec<String> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/boarding_passes.txt")
+        .unwrap();
+    let decoder = Decoder::boarding_pass();
+    let ids: Vec<u64> = passes.iter().map(|p| decoder.decode(p).unwrap()).collect();
+    assert_eq!(vec![567, 119, 820], ids);
+}
+
+#[test]
+fn custom_symbols() {
+    let decoder = Decoder::new(".", "#");
+    assert_eq!(Ok(5), decoder.decode("#.#"));
+    assert_eq!(Ok(0), decoder.decode(""));
+    assert!(decoder.decode("#x#").is_err());
+}
+
+#[test]
+fn missing_id() {
+    assert_eq!(Some(12), find_missing_id(&[14, 10, 11, 13, 15]));
+    assert_eq!(None, find_missing_id(&[1, 2, 3]));
+    assert_eq!(None, find_missing_id(&[]));
+}
@@ -0,0 +1,61 @@
+extern crate aoc_util;
+
+use aoc_util::bus::{parse_buses, Schedule};
+
+fn aligned(buses: &str) -> Option<u64> {
+    Schedule {
+        earliest: 0,
+        buses: parse_buses(buses).unwrap(),
+    }
+    .earliest_aligned()
+}
+
+#[test]
+fn parse_schedule() {
+    let schedule: Schedule = "939\n7,13,x,x,59,x,31,19\n".parse().unwrap();
+    assert_eq!(939, schedule.earliest);
+    assert_eq!(
+        vec![
+            Some(7),
+            Some(13),
+            None,
+            None,
+            Some(59),
+            None,
+            Some(31),
+            Some(19)
+        ],
+        schedule.buses
+    );
+    assert!("939".parse::<Schedule>().is_err());
+    assert!("939\n7,y,13".parse::<Schedule>().is_err());
+    assert!(parse_buses("7,0").is_err());
+}
+
+#[test]
+fn earliest_bus() {
+    let schedule: Schedule = "939\n7,13,x,x,59,x,31,19".parse().unwrap();
+    let (id, departure) = schedule.earliest_bus().unwrap();
+    assert_eq!(295, id * (departure - schedule.earliest));
+
+    let schedule: Schedule = "14\n7,x".parse().unwrap();
+    assert_eq!(Some((7, 14)), schedule.earliest_bus());
+}
+
+#[test]
+fn congruences() {
+    let schedule: Schedule = "0\n17,x,13,19".parse().unwrap();
+    assert_eq!(vec![(0, 17), (11, 13), (16, 19)], schedule.congruences());
+}
+
+#[test]
+fn aligned_departures() {
+    assert_eq!(Some(3417), aligned("17,x,13,19"));
+    assert_eq!(Some(754018), aligned("67,7,59,61"));
+    assert_eq!(Some(779210), aligned("67,x,7,59,61"));
+    assert_eq!(Some(1261476), aligned("67,7,x,59,61"));
+    assert_eq!(Some(1202161486), aligned("1789,37,47,1889"));
+
+    assert_eq!(Some(4), aligned("4,x,6"));
+    assert_eq!(None, aligned("4,6"));
+}
@@ -0,0 +1,41 @@
+use aoc_util::carts::Tracks;
+use aoc_util::geometry::Point2;
+use aoc_util::input::{FileReader, FromFile};
+
+fn tracks(file: &str) -> Tracks {
+    let input: String = FileReader::new().read_from_file(file).unwrap();
+    input.parse().unwrap()
+}
+
+#[test]
+fn first_collision() {
+    let tracks = tracks("tests/inputs/carts.txt");
+    assert_eq!(2, tracks.carts().len());
+    assert_eq!(Some(Point2::new(7, 3)), tracks.first_collision());
+}
+
+#[test]
+fn last_remaining_cart() {
+    let mut tracks = tracks("tests/inputs/carts2.txt");
+    assert_eq!(9, tracks.carts().len());
+    assert_eq!(Some(Point2::new(6, 4)), tracks.last_cart());
+
+    let crashes = tracks.tick();
+    assert_eq!(
+        vec![Point2::new(2, 0), Point2::new(2, 4), Point2::new(6, 4)],
+        crashes
+    );
+    assert_eq!(3, tracks.carts().len());
+}
+
+#[test]
+fn no_collision() {
+    let tracks: Tracks = "/>-\\\n|  |\n\\--/".parse().unwrap();
+    assert_eq!(None, tracks.first_collision());
+    assert_eq!(Some(Point2::new(1, 0)), tracks.last_cart());
+}
+
+#[test]
+fn invalid_track() {
+    assert!("|\nx\n|".parse::<Tracks>().is_err());
+}
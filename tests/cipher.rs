@@ -0,0 +1,38 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::strings::{caesar_shift, frequency_checksum, Room};
+
+#[test]
+fn shift_letters() {
+    assert_eq!(
+        "very encrypted name",
+        caesar_shift("qzmt zixmtkozy ivhz", 343)
+    );
+    assert_eq!("Bcd-A", caesar_shift("Abc-Z", 1));
+    assert_eq!("abc", caesar_shift("abc", 26));
+}
+
+#[test]
+fn checksum_with_ties() {
+    assert_eq!("abxyz", frequency_checksum("aaaaa-bbb-z-y-x", 5));
+    assert_eq!("abcde", frequency_checksum("a-b-c-d-e-f-g-h", 5));
+    assert_eq!("ab", frequency_checksum("b-a", 10));
+}
+
+#[test]
+fn real_rooms() {
+    let rooms: Vec<Room> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/rooms.txt")
+        .unwrap();
+    let sum: u32 = rooms.iter().filter(|r| r.is_real()).map(|r| r.sector).sum();
+    assert_eq!(1514, sum);
+}
+
+#[test]
+fn decrypt_room() {
+    let room: Room = "qzmt-zixmtkozy-ivhz-343[zimth]".parse().unwrap();
+    assert_eq!("very encrypted name", room.decrypt());
+    assert!("qzmt-zixmtkozy-ivhz[zimth]".parse::<Room>().is_err());
+}
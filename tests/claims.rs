@@ -0,0 +1,39 @@
+use aoc_util::claims::{intact_claim, overlap_area, Claim};
+
+fn claims(input: &str) -> Vec<Claim> {
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+#[test]
+fn parse_claim() {
+    assert_eq!(
+        Ok(Claim {
+            id: 123,
+            left: 3,
+            top: 2,
+            width: 5,
+            height: 4
+        }),
+        "#123 @ 3,2: 5x4".parse()
+    );
+    assert!("123 @ 3,2: 5x4".parse::<Claim>().is_err());
+    assert!("#123 @ 3,2: 5".parse::<Claim>().is_err());
+}
+
+#[test]
+fn overlapping_claims() {
+    let claims = claims("#1 @ 1,3: 4x4\n#2 @ 3,1: 4x4\n#3 @ 5,5: 2x2");
+    assert!(claims[0].overlaps(&claims[1]));
+    assert!(!claims[0].overlaps(&claims[2]));
+    assert!(!claims[1].overlaps(&claims[2]));
+    assert_eq!(4, overlap_area(&claims));
+    assert_eq!(Some(3), intact_claim(&claims));
+}
+
+#[test]
+fn triple_overlap_counted_once() {
+    let claims = claims("#1 @ 0,0: 2x2\n#2 @ 1,1: 2x2\n#3 @ 1,0: 1x3");
+    assert_eq!(3, overlap_area(&claims));
+    assert_eq!(None, intact_claim(&claims));
+    assert_eq!(0, overlap_area(&[]));
+}
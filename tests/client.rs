@@ -0,0 +1,77 @@
+use std::time::{Duration, Instant};
+
+use aoc_util::client::{is_retryable, Client};
+
+fn fast_client() -> Client {
+    Client::new("test@example.com")
+        .unwrap()
+        .min_interval(Duration::from_millis(0))
+        .initial_backoff(Duration::from_millis(0))
+}
+
+#[test]
+fn requires_contact() {
+    assert!(Client::new("  ").is_err());
+    let client = Client::new("https://github.com/df5602/aoc-util").unwrap();
+    assert!(client
+        .user_agent()
+        .ends_with("(https://github.com/df5602/aoc-util)"));
+}
+
+#[test]
+fn retryable_status() {
+    assert!(is_retryable(429));
+    assert!(is_retryable(502));
+    assert!(!is_retryable(404));
+    assert!(!is_retryable(200));
+}
+
+#[test]
+fn exponential_backoff() {
+    let client = Client::new("me")
+        .unwrap()
+        .initial_backoff(Duration::from_secs(1))
+        .max_backoff(Duration::from_secs(10));
+    assert_eq!(Duration::from_secs(1), client.backoff(0));
+    assert_eq!(Duration::from_secs(8), client.backoff(3));
+    assert_eq!(Duration::from_secs(10), client.backoff(4));
+    assert_eq!(Duration::from_secs(10), client.backoff(100));
+}
+
+#[test]
+fn retries_until_success_or_limit() {
+    let mut client = fast_client().max_retries(2);
+    let mut attempts = 0;
+    let result = client.send(|_| {
+        attempts += 1;
+        Ok((429, ()))
+    });
+    assert!(result.is_err());
+    assert_eq!(3, attempts);
+
+    attempts = 0;
+    let result = client.send(|_| {
+        attempts += 1;
+        Ok((404, ()))
+    });
+    assert!(result.is_err());
+    assert_eq!(1, attempts);
+}
+
+#[test]
+fn sends_user_agent() {
+    let mut client = fast_client();
+    let user_agent = client.user_agent().to_string();
+    let sent = client.send(|ua| Ok((200, ua.to_string()))).unwrap();
+    assert_eq!(user_agent, sent);
+}
+
+#[test]
+fn spaces_requests() {
+    let mut client = fast_client().min_interval(Duration::from_millis(30));
+    let start = Instant::now();
+    for _ in 0..3 {
+        client.send(|_| Ok((200, ()))).unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(60));
+}
@@ -0,0 +1,101 @@
+extern crate aoc_util;
+
+use aoc_util::cycles::{
+    extrapolate, find_cycle, find_cycle_brent, redistribute, redistribution_cycle, state_after,
+    Cycle,
+};
+
+#[test]
+fn cycle_with_prefix() {
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    let cycle = find_cycle(0, |&x| if x == 4 { 2 } else { x + 1 });
+    assert_eq!(
+        Cycle {
+            start: 2,
+            length: 3
+        },
+        cycle
+    );
+    assert_eq!(5, cycle.first_repeat());
+}
+
+#[test]
+fn redistribution_steps() {
+    let mut banks = vec![0, 2, 7, 0];
+    redistribute(&mut banks);
+    assert_eq!(vec![2, 4, 1, 2], banks);
+    redistribute(&mut banks);
+    assert_eq!(vec![3, 1, 2, 3], banks);
+    redistribute(&mut banks);
+    assert_eq!(vec![0, 2, 3, 4], banks);
+    redistribute(&mut banks);
+    assert_eq!(vec![1, 3, 4, 1], banks);
+}
+
+#[test]
+fn redistribution_loop() {
+    let cycle = redistribution_cycle(&[0, 2, 7, 0]);
+    assert_eq!(5, cycle.first_repeat());
+    assert_eq!(4, cycle.length);
+}
+
+#[test]
+fn brent_matches_hashing() {
+    let next = |&x: &u32| if x == 4 { 2 } else { x + 1 };
+    assert_eq!(find_cycle(0, next), find_cycle_brent(0, next));
+
+    for seed in 1..30u64 {
+        let next = |&x: &u64| (x * x + seed) % 1009;
+        assert_eq!(find_cycle(seed, next), find_cycle_brent(seed, next));
+    }
+}
+
+#[test]
+fn cycle_index() {
+    let cycle = Cycle {
+        start: 2,
+        length: 3,
+    };
+    let indices: Vec<usize> = (0..9).map(|n| cycle.index(n)).collect();
+    assert_eq!(vec![0, 1, 2, 3, 4, 2, 3, 4, 2], indices);
+    assert_eq!(4, cycle.index(1_000_000_000_000_000_000));
+}
+
+#[test]
+fn skip_ahead() {
+    let next = |&x: &u64| if x == 4 { 2 } else { x + 1 };
+    for n in 0..20 {
+        let mut state = 0;
+        for _ in 0..n {
+            state = next(&state);
+        }
+        assert_eq!(state, state_after(0, n, next), "after {} steps", n);
+    }
+    // 10^18 - 2 leaves a remainder of 2 when divided by the cycle length 3
+    assert_eq!(4, state_after(0, 1_000_000_000_000_000_000, next));
+}
+
+#[test]
+fn extrapolate_growing_value() {
+    // A counter that grows by the current position in a cycle of 0, 1, 2, 3, 4, 2, 3, 4, ...
+    let next = |&(pos, total): &(u64, i64)| {
+        let pos = if pos == 4 { 2 } else { pos + 1 };
+        (pos, total + pos as i64)
+    };
+    let simulate = |n: u64| (0..n).fold((0, 0), |state, _| next(&state)).1;
+    for n in 0..30 {
+        assert_eq!(
+            simulate(n),
+            extrapolate((0, 0), n, next, |s| s.0, |s| s.1),
+            "after {} steps",
+            n
+        );
+    }
+
+    // The first two steps add 1 + 2, then every full cycle adds 3 + 4 + 2 and one step is left
+    let n = 1_000_000_000_002u64;
+    assert_eq!(
+        1 + 2 + 9 * 333_333_333_333 + 3,
+        extrapolate((0, 0), n, next, |s| s.0, |s| s.1)
+    );
+}
@@ -0,0 +1,60 @@
+extern crate aoc_util;
+
+use aoc_util::debug::{render_grid, render_path};
+use aoc_util::grid::Grid;
+use aoc_util::search::bfs;
+use aoc_util::{dbg_grid, dbg_path};
+
+#[test]
+fn highlight_points() {
+    let grid = Grid::parse("#..\n.#.", |c| c == '#').unwrap();
+    assert_eq!("#..\n.#.", render_grid(&grid, None, false));
+    assert_eq!(
+        "#O.\n.#O",
+        render_grid(&grid, vec![(0, 1), (1, 2), (5, 5)], false)
+    );
+    assert_eq!(
+        "\x1b[1;31m#\x1b[0m..\n.#.",
+        render_grid(&grid, Some((0, 0)), true)
+    );
+}
+
+#[test]
+fn overlay_path() {
+    let grid = Grid::parse("S.#.\n.##.\n...E", |c| c).unwrap();
+    let (path, _) = bfs(
+        (0, 0),
+        |&(r, c)| {
+            grid.neighbors4(r, c)
+                .filter(|&(nr, nc)| grid[(nr, nc)] != '#')
+                .collect::<Vec<_>>()
+        },
+        |&(r, c)| grid[(r, c)] == 'E',
+    )
+    .unwrap();
+    assert_eq!("v.#.\nv##.\n>>>E", render_path(&grid, &path, false));
+    assert_eq!(
+        "\x1b[1;31m>\x1b[0m\x1b[1;31mE\x1b[0m",
+        render_path(&Grid::parse("..", |c| c).unwrap(), &[(0, 0), (0, 1)], true)
+    );
+    assert_eq!(
+        "*.\n.E",
+        render_path(
+            &Grid::parse("..\n..", |c| c).unwrap(),
+            &[(0, 0), (1, 1)],
+            false
+        )
+    );
+    assert_eq!(
+        "ab",
+        render_path(&Grid::parse("ab", |c| c as u8).unwrap(), &[], false)
+    );
+}
+
+#[test]
+fn print_to_stderr() {
+    let grid = Grid::parse("#.\n.#", |c| c == '#').unwrap();
+    dbg_grid!(grid);
+    dbg_grid!(&grid, vec![(0, 1)]);
+    dbg_path!(grid, [(0, 0), (0, 1)]);
+}
@@ -0,0 +1,25 @@
+extern crate aoc_util;
+
+use aoc_util::defrag::Disk;
+use aoc_util::hash::knot_hash;
+
+#[test]
+fn knot_hashes() {
+    let hex = |bytes: [u8; 16]| -> String { bytes.iter().map(|b| format!("{:02x}", b)).collect() };
+    assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", hex(knot_hash("")));
+    assert_eq!(
+        "33efeb34ea91902bb2f59c9920caa6cd",
+        hex(knot_hash("AoC 2017"))
+    );
+    assert_eq!("3efbe78a8d82f29979031a4aa0b16a9d", hex(knot_hash("1,2,3")));
+    assert_eq!("63960835bcdc130f0b66d7ff4f6a5a8e", hex(knot_hash("1,2,4")));
+}
+
+#[test]
+fn disk_usage() {
+    let disk = Disk::new("flqrgnkx");
+    assert_eq!(Some(&true), disk.grid().get(0, 0));
+    assert_eq!(Some(&false), disk.grid().get(0, 2));
+    assert_eq!(8108, disk.used_squares());
+    assert_eq!(1242, disk.regions());
+}
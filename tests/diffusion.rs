@@ -0,0 +1,56 @@
+extern crate aoc_util;
+
+use aoc_util::diffusion::{parse_points, Diffusion, Rule};
+use aoc_util::input::{FileReader, FromFile};
+
+fn example() -> Diffusion {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/diffusion.txt")
+        .unwrap();
+    Diffusion::unstable_diffusion(parse_points(&input, '#'))
+}
+
+#[test]
+fn empty_tiles_after_ten_rounds() {
+    let mut diffusion = example();
+    diffusion.run(10);
+    assert_eq!(10, diffusion.round());
+    assert_eq!(110, diffusion.empty_in_bounding_box());
+}
+
+#[test]
+fn first_round_without_movement() {
+    let mut diffusion = example();
+    assert_eq!(20, diffusion.run_until_stable());
+}
+
+#[test]
+fn conflicting_proposals_stay() {
+    // The two inner points both want to move onto (1, 0) and therefore stay in place.
+    let rules = vec![
+        Rule::new(vec![(1, 0)], (1, 0)),
+        Rule::new(vec![(-1, 0)], (-1, 0)),
+    ];
+    let mut diffusion = Diffusion::new(vec![(-1, 0), (0, 0), (2, 0), (3, 0)], rules);
+    assert_eq!(2, diffusion.step());
+    assert!(diffusion.points().contains(&(0, 0)));
+    assert!(diffusion.points().contains(&(2, 0)));
+    assert!(diffusion.points().contains(&(-2, 0)));
+    assert!(diffusion.points().contains(&(4, 0)));
+}
+
+#[test]
+fn chained_moves_are_simultaneous() {
+    // Every point moves right, partly onto a cell that another point leaves in the same round.
+    let rules = vec![
+        Rule::new(vec![(0, 1)], (1, 0)),
+        Rule::new(vec![(0, -1)], (1, 0)),
+    ];
+    for _ in 0..50 {
+        let mut diffusion = Diffusion::new(vec![(0, 0), (1, 0), (0, -1), (1, -1)], rules.clone());
+        assert_eq!(4, diffusion.step());
+        let mut points: Vec<_> = diffusion.points().iter().copied().collect();
+        points.sort_unstable();
+        assert_eq!(vec![(1, -1), (1, 0), (2, -1), (2, 0)], points);
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_util::collections::DisjointSet;
+
+#[test]
+fn union_and_find() {
+    let mut sets = DisjointSet::new(6);
+    assert_eq!(6, sets.len());
+    assert_eq!(6, sets.count_sets());
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 1));
+    assert!(!sets.union(0, 2));
+    assert!(sets.union(4, 5));
+    assert_eq!(3, sets.count_sets());
+    assert_eq!(sets.find(0), sets.find(2));
+    assert!(sets.same_set(5, 4));
+    assert!(!sets.same_set(3, 4));
+    assert_eq!(3, sets.set_size(1));
+    assert_eq!(1, sets.set_size(3));
+}
+
+#[test]
+fn long_chain() {
+    let mut sets = DisjointSet::new(1000);
+    for i in 1..1000 {
+        sets.union(i - 1, i);
+    }
+    assert_eq!(1, sets.count_sets());
+    assert_eq!(1000, sets.set_size(500));
+    assert!(sets.same_set(0, 999));
+}
+
+#[test]
+fn empty() {
+    let sets = DisjointSet::new(0);
+    assert!(sets.is_empty());
+    assert_eq!(0, sets.count_sets());
+}
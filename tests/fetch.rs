@@ -0,0 +1,143 @@
+#![cfg(feature = "network")]
+
+use std::env;
+use std::fs;
+use std::time::{Duration, Instant};
+
+use aoc_util::client::Client;
+use aoc_util::fetch::{self, cached_input, Verdict};
+use aoc_util::project::Project;
+
+#[test]
+fn input_urls() {
+    assert_eq!(
+        "https://adventofcode.com/2023/day/5/input",
+        fetch::input_url(2023, 5)
+    );
+}
+
+#[test]
+fn cached_inputs_are_not_downloaded() {
+    let root = env::temp_dir().join(format!("aoc-util-fetch-{}", std::process::id()));
+    fs::create_dir_all(root.join("inputs/2020")).unwrap();
+    fs::write(root.join("Cargo.toml"), "[package]\nname = \"cached\"\n").unwrap();
+    fs::write(root.join("inputs/2020/day01.txt"), "1721\n979\n").unwrap();
+
+    let project = Project::from_dir(&root).unwrap().inputs_dir("inputs");
+    let path = cached_input(&project, 2020, 1).unwrap();
+    assert_eq!("1721\n979\n", fs::read_to_string(path).unwrap());
+
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn submission_verdicts() {
+    let wrong = "<article><p>That's not the right answer; your answer is too low. \
+        If you're stuck, ...</p></article>";
+    assert_eq!(Verdict::TooLow, fetch::parse_verdict(wrong));
+    assert_eq!(
+        Verdict::TooHigh,
+        fetch::parse_verdict(&wrong.replace("too low", "too high"))
+    );
+    assert_eq!(
+        Verdict::Incorrect,
+        fetch::parse_verdict("<p>That's not the right answer. If you're stuck</p>")
+    );
+    assert_eq!(
+        Verdict::Correct,
+        fetch::parse_verdict("<p>That's the right answer! You are one gold star closer.</p>")
+    );
+    assert_eq!(
+        Verdict::RateLimited(Some(Duration::from_secs(65))),
+        fetch::parse_verdict(
+            "<p>You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 5s left to wait.</p>"
+        )
+    );
+    assert_eq!(
+        Verdict::WrongLevel,
+        fetch::parse_verdict("<p>You don't seem to be solving the right level.</p>")
+    );
+    assert_eq!(Verdict::Unknown, fetch::parse_verdict("<html></html>"));
+}
+
+#[test]
+fn requests_share_one_client() {
+    let interval = Duration::from_millis(200);
+    fetch::set_client(
+        Client::new("test@example.com")
+            .unwrap()
+            .min_interval(interval),
+    );
+
+    let request = || fetch::with_client(|client| client.send(|_| Ok((200, Instant::now()))));
+    let first = request().unwrap();
+    let second = request().unwrap();
+    assert!(second - first >= interval);
+}
+
+#[cfg(feature = "async")]
+mod asynchronous {
+    use std::env;
+    use std::fs;
+    use std::future::Future;
+    use std::io;
+    use std::panic;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread::{self, Thread};
+
+    use aoc_util::answer::Answer;
+    use aoc_util::fetch::{download_input_async, submit_answer_async, Blocking};
+    use aoc_util::project::Project;
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Minimal executor: poll on the current thread, parking it until woken.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Arc::new(Unpark(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    #[test]
+    fn cached_input_without_blocking() {
+        let root = env::temp_dir().join(format!("aoc-util-fetch-async-{}", std::process::id()));
+        fs::create_dir_all(root.join("inputs/2020")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[package]\nname = \"cached\"\n").unwrap();
+        fs::write(root.join("inputs/2020/day02.txt"), "1-3 a: abcde\n").unwrap();
+
+        let project = Project::from_dir(&root).unwrap().inputs_dir("inputs");
+        let path = block_on(download_input_async(project, 2020, 2)).unwrap();
+        assert_eq!("1-3 a: abcde\n", fs::read_to_string(path).unwrap());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn unsubmittable_answer() {
+        let answer = Answer::Grid(String::from("#.\n.#"));
+        let error = block_on(submit_answer_async(2020, 1, 1, answer)).unwrap_err();
+        assert_eq!(io::ErrorKind::InvalidInput, error.kind());
+    }
+
+    #[test]
+    fn panicking_task_wakes_caller() {
+        let result = panic::catch_unwind(|| block_on(Blocking::new(|| -> u32 { panic!("boom") })));
+        let payload = result.unwrap_err();
+        assert_eq!(Some(&"boom"), payload.downcast_ref::<&str>());
+    }
+}
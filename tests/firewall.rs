@@ -0,0 +1,36 @@
+extern crate aoc_util;
+
+use aoc_util::firewall::{min_delay, passes, severity, Scanner};
+use aoc_util::input::{FileReader, FromFile};
+
+fn scanners() -> Vec<Scanner> {
+    FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/firewall.txt")
+        .unwrap()
+}
+
+#[test]
+fn scanner_positions() {
+    let scanner = Scanner { depth: 0, range: 4 };
+    assert_eq!(6, scanner.period());
+    let positions: Vec<u64> = (0..8).map(|t| scanner.position(t)).collect();
+    assert_eq!(vec![0, 1, 2, 3, 2, 1, 0, 1], positions);
+    assert_eq!(0, Scanner { depth: 0, range: 1 }.position(5));
+}
+
+#[test]
+fn trip_severity() {
+    let scanners = scanners();
+    assert_eq!(24, severity(&scanners, 0));
+    assert!(!passes(&scanners, 4));
+    // Caught only by the scanner at depth 0, which has severity 0
+    assert_eq!(0, severity(&scanners, 4));
+}
+
+#[test]
+fn smallest_delay() {
+    let scanners = scanners();
+    assert_eq!(10, min_delay(&scanners));
+    assert!(passes(&scanners, 10));
+}
@@ -0,0 +1,94 @@
+extern crate aoc_util;
+
+use aoc_util::fractal::{parse_pattern, Enhancer, START};
+use aoc_util::grid::Grid;
+
+fn to_pattern(bits: u32, size: usize) -> String {
+    (0..size)
+        .map(|r| {
+            (0..size)
+                .map(|c| {
+                    if bits >> (r * size + c) & 1 == 1 {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A complete (if arbitrary) rule book covering all 2x2 and 3x3 inputs.
+fn complete_rules() -> Enhancer {
+    let mut rules = String::new();
+    for bits in 0..16u32 {
+        let output = bits.wrapping_mul(2654435761) >> 5 & 0x1FF;
+        rules += &format!("{} => {}\n", to_pattern(bits, 2), to_pattern(output, 3));
+    }
+    for bits in 0..512u32 {
+        let output = bits.wrapping_mul(40503) ^ (bits << 3) & 0xFFFF;
+        rules += &format!("{} => {}\n", to_pattern(bits, 3), to_pattern(output, 4));
+    }
+    rules.parse().unwrap()
+}
+
+#[test]
+fn parse_and_orient() {
+    let pattern = parse_pattern(".#./..#/###").unwrap();
+    assert_eq!(3, pattern.width());
+    assert_eq!(Some(&true), pattern.get(1, 2));
+    assert_eq!(8, pattern.orientations().len());
+    assert!(parse_pattern("../.").is_err());
+    assert!(parse_pattern("ab/cd").is_err());
+}
+
+#[test]
+fn example_enhancement() {
+    let enhancer: Enhancer = "../.# => ##./#../...\n.#./..#/### => #..#/..../..../#..#"
+        .parse()
+        .unwrap();
+    let grid = enhancer
+        .enhance_n(&parse_pattern(START).unwrap(), 2)
+        .unwrap();
+    assert_eq!(
+        parse_pattern("##.##./#..#../....../##.##./#..#../......"),
+        Ok(grid)
+    );
+    assert_eq!(12, enhancer.count_on(START, 2).unwrap());
+    assert!(enhancer.count_on(START, 3).is_err());
+}
+
+#[test]
+fn block_decomposition_matches_simulation() {
+    let enhancer = complete_rules();
+    let start = parse_pattern(START).unwrap();
+    for iterations in 0..8 {
+        let grid = enhancer.enhance_n(&start, iterations).unwrap();
+        let expected = (0..grid.height())
+            .flat_map(|r| (0..grid.width()).map(move |c| (r, c)))
+            .filter(|&(r, c)| grid.get(r, c) == Some(&true))
+            .count();
+        assert_eq!(expected, enhancer.count_on(START, iterations).unwrap());
+    }
+    assert!(enhancer.count_on(START, 18).is_ok());
+}
+
+#[test]
+fn grid_blocks() {
+    let grid = Grid::from_vec(4, (0..16).collect()).unwrap();
+    let blocks: Vec<Grid<i32>> = (0..4)
+        .map(|i| grid.subgrid((i / 2) * 2, (i % 2) * 2, 2, 2).unwrap())
+        .collect();
+    assert_eq!(
+        Grid::from_vec(2, vec![10, 11, 14, 15]),
+        Some(blocks[3].clone())
+    );
+    assert_eq!(grid, Grid::from_blocks(&blocks, 2));
+    assert_eq!(None, grid.subgrid(3, 3, 2, 2));
+    assert_eq!(
+        Grid::from_vec(2, vec![2, 0, 3, 1]),
+        Some(Grid::from_vec(2, vec![0, 1, 2, 3]).unwrap().rotate_right())
+    );
+}
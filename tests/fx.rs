@@ -0,0 +1,32 @@
+use std::hash::{BuildHasher, Hash, Hasher};
+
+use aoc_util::collections::{FastHashMap, FastHashSet, FxBuildHasher, FxHasher};
+
+fn hash<T: Hash>(value: &T) -> u64 {
+    let mut hasher = FxHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn deterministic_hashes() {
+    assert_eq!(hash(&(3, 4)), hash(&(3, 4)));
+    assert_ne!(hash(&(3, 4)), hash(&(4, 3)));
+    assert_ne!(hash(&"abcdefghij"), hash(&"abcdefghik"));
+    assert_eq!(
+        FxBuildHasher::default().hash_one(42u64),
+        FxBuildHasher::default().hash_one(42u64)
+    );
+}
+
+#[test]
+fn fast_collections() {
+    let mut map: FastHashMap<(i64, i64), u32> = FastHashMap::default();
+    for x in 0..100 {
+        *map.entry((x % 10, x / 10)).or_default() += 1;
+    }
+    assert_eq!(100, map.len());
+
+    let set: FastHashSet<&str> = "a b a c".split(' ').collect();
+    assert_eq!(3, set.len());
+}
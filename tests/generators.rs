@@ -0,0 +1,42 @@
+extern crate aoc_util;
+
+use aoc_util::generators::{count_matches, Generator};
+
+#[test]
+fn generator_values() {
+    let a: Vec<u64> = Generator::a(65).take(5).collect();
+    assert_eq!(
+        vec![1092455, 1181022009, 245556042, 1744312007, 1352636452],
+        a
+    );
+    let b: Vec<u64> = Generator::b(8921).take(5).collect();
+    assert_eq!(
+        vec![430625591, 1233683848, 1431495498, 137874439, 285222916],
+        b
+    );
+}
+
+#[test]
+fn generator_criteria() {
+    let a: Vec<u64> = Generator::a(65).multiples_of(4).take(5).collect();
+    assert_eq!(
+        vec![1352636452, 1992081072, 530830436, 1980017072, 740335192],
+        a
+    );
+    let b: Vec<u64> = Generator::b(8921).multiples_of(8).take(5).collect();
+    assert_eq!(
+        vec![1233683848, 862516352, 1159784568, 1616057672, 412269392],
+        b
+    );
+    let c: Vec<u64> = Generator::a(65).multiples_of(3).take(3).collect();
+    assert!(c.iter().all(|v| v % 3 == 0));
+}
+
+#[test]
+fn judge() {
+    assert_eq!(1, count_matches(Generator::a(65), Generator::b(8921), 5));
+    let a = Generator::a(65).multiples_of(4);
+    let b = Generator::b(8921).multiples_of(8);
+    assert_eq!(0, count_matches(a.clone(), b.clone(), 1055));
+    assert_eq!(1, count_matches(a, b, 1056));
+}
@@ -0,0 +1,89 @@
+extern crate aoc_util;
+
+use aoc_util::geometry::{bounding_box, Direction, Direction8, Point2};
+
+#[test]
+fn rotations() {
+    let p = Point2::new(10, -4);
+    assert_eq!(Point2::new(-4, -10), p.rotate_left());
+    assert_eq!(Point2::new(4, 10), p.rotate_right());
+    assert_eq!(p, p.rotate_left().rotate_right());
+    assert_eq!(p, p.rotate_left().rotate_left().rotate_left().rotate_left());
+}
+
+#[test]
+fn arithmetic() {
+    let mut p = Point2::new(1, 2) + Point2::new(3, -4);
+    assert_eq!(Point2::new(4, -2), p);
+    p += Point2::from((1, 1));
+    assert_eq!(Point2::new(15, -3), p * 3);
+    assert_eq!(6, p.manhattan(Point2::new(0, 0)));
+}
+
+#[test]
+fn bounding_box_of_points() {
+    let points = vec![Point2::new(3, -1), Point2::new(-2, 4), Point2::new(0, 0)];
+    assert_eq!(
+        Some((Point2::new(-2, -1), Point2::new(3, 4))),
+        bounding_box(&points)
+    );
+    assert_eq!(None, bounding_box(&[]));
+}
+
+#[test]
+fn subtraction_and_negation() {
+    let mut p = Point2::new(5, 3) - Point2::new(7, -1);
+    assert_eq!(Point2::new(-2, 4), p);
+    p -= Point2::new(1, 1);
+    assert_eq!(Point2::new(3, -3), -p);
+}
+
+#[test]
+fn unit_vectors() {
+    assert_eq!(Point2::new(0, -1), Point2::UP);
+    assert_eq!(Point2::DOWN, -Point2::UP);
+    assert_eq!(Point2::LEFT, Point2::UP.rotate_left());
+    assert_eq!(Point2::RIGHT, Point2::UP.rotate_right());
+    assert_eq!(Point2::new(3, 2), Point2::RIGHT * 3 + Point2::DOWN * 2);
+}
+
+#[test]
+fn turn_directions() {
+    assert_eq!(Direction::West, Direction::North.turn_left());
+    assert_eq!(Direction::East, Direction::North.turn_right());
+    assert_eq!(Direction::North, Direction::South.reverse());
+    for &dir in &Direction::ALL {
+        assert_eq!(dir, dir.turn_right().turn_left());
+        assert_eq!(dir.delta().rotate_right(), dir.turn_right().delta());
+        assert_eq!(-dir.delta(), dir.reverse().delta());
+    }
+    assert_eq!(Some(Direction::South), Direction::from_char('v'));
+    assert_eq!(None, Direction::from_char('x'));
+}
+
+#[test]
+fn diagonal_directions() {
+    assert_eq!(Direction8::NorthWest, Direction8::North.turn_left());
+    assert_eq!(Direction8::SouthWest, Direction8::NorthEast.reverse());
+    assert_eq!(Point2::new(1, 1), Direction8::SouthEast.delta());
+    assert_eq!(Direction8::West, Direction8::from(Direction::West));
+    for &dir in &Direction8::ALL {
+        assert_eq!(dir, dir.turn_left().turn_right());
+    }
+}
+
+#[test]
+fn walk_in_directions() {
+    let mut p = Point2::new(0, 0);
+    let mut dir = Direction::North;
+    for turn in "RRLL".chars() {
+        dir = if turn == 'R' {
+            dir.turn_right()
+        } else {
+            dir.turn_left()
+        };
+        p += dir;
+    }
+    assert_eq!(Point2::new(2, 0), p);
+    assert_eq!(Point2::new(1, -1), p + Direction8::NorthWest);
+}
@@ -0,0 +1,242 @@
+extern crate aoc_util;
+
+use aoc_util::geometry::{Direction, Point2};
+use aoc_util::grid::{Grid, SparseGrid};
+use aoc_util::input::{Error, FileReader, FromFile, FromReader};
+
+fn toboggan_map() -> Grid<bool> {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/toboggan.txt")
+        .unwrap();
+    Grid::parse(&input, |c| c == '#').unwrap()
+}
+
+#[test]
+fn parse_grid() {
+    let grid = toboggan_map();
+    assert_eq!(11, grid.width());
+    assert_eq!(11, grid.height());
+    assert_eq!(Some(&true), grid.get(0, 2));
+    assert_eq!(Some(&false), grid.get(10, 0));
+    assert_eq!(None, grid.get(11, 0));
+    assert_eq!(None, grid.get(0, 11));
+    assert!(Grid::parse("ab\nc", |c| c).is_err());
+}
+
+#[test]
+fn from_vec() {
+    let grid = Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(2, grid.height());
+    assert_eq!(Some(&6), grid.get(1, 2));
+    assert!(Grid::from_vec(4, vec![1, 2, 3, 4, 5, 6]).is_none());
+    assert!(Grid::<u8>::from_vec(0, vec![]).is_some());
+}
+
+#[test]
+fn trees_on_slopes() {
+    let grid = toboggan_map();
+    assert_eq!(7, grid.count_on_slope(3, 1, |&tree| tree));
+    assert_eq!(2, grid.count_on_slope(1, 2, |&tree| tree));
+    let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+    assert_eq!(336, grid.slope_product(&slopes, |&tree| tree));
+}
+
+#[test]
+fn regions() {
+    let grid = Grid::parse("##..#\n#..##\n..#..\n##...", |c| c == '#').unwrap();
+    assert_eq!(4, grid.count_regions(|&c| c));
+    assert_eq!(2, grid.count_regions(|&c| !c));
+
+    let mut region = grid.flood_fill(1, 3, |&c| c);
+    region.sort_unstable();
+    assert_eq!(vec![(0, 4), (1, 3), (1, 4)], region);
+    assert!(grid.flood_fill(0, 2, |&c| c).is_empty());
+}
+
+#[test]
+fn distance_fields() {
+    let grid = Grid::parse("S.#.\n.##.\n....\n#.#S", |c| c).unwrap();
+    let sources = [(0, 0), (3, 3)];
+    let field = grid.distance_field(sources.iter().copied(), |&c| c != '#');
+
+    let expected = Grid::parse("01#3\n1##2\n2321\n#4#0", |c| c.to_digit(10)).unwrap();
+    assert_eq!(expected, field);
+
+    let field = grid.distance_field(Some((0, 3)), |&c| c == '.');
+    assert_eq!(Some(&Some(0)), field.get(0, 3));
+    assert_eq!(Some(&Some(5)), field.get(2, 0));
+    assert_eq!(Some(&None), field.get(0, 0));
+    assert_eq!(Some(&None), field.get(3, 3));
+}
+
+#[test]
+fn sparse_grid() {
+    let mut grid: SparseGrid<char> = vec![(Point2::new(-2, 3), 'a'), (Point2::new(4, -1), 'b')]
+        .into_iter()
+        .collect();
+    assert_eq!(2, grid.len());
+    assert_eq!(Some(&'a'), grid.get(Point2::new(-2, 3)));
+    assert_eq!(None, grid.get(Point2::new(0, 0)));
+    assert_eq!(
+        Some((Point2::new(-2, -1), Point2::new(4, 3))),
+        grid.bounds()
+    );
+
+    assert_eq!(Some('b'), grid.insert(Point2::new(4, -1), 'c'));
+    assert_eq!(1, grid.count(|&c| c == 'c'));
+    assert_eq!(Some('a'), grid.remove(Point2::new(-2, 3)));
+    assert!(!grid.contains(Point2::new(-2, 3)));
+    assert_eq!(
+        Some((Point2::new(4, -1), Point2::new(4, -1))),
+        grid.bounds()
+    );
+
+    assert_eq!(None, SparseGrid::<char>::new().bounds());
+}
+
+fn fuel_cells(serial: i64) -> Grid<i64> {
+    let cells = (1..=300)
+        .flat_map(|y| (1..=300).map(move |x| (x, y)))
+        .map(|(x, y)| {
+            let rack = x + 10;
+            (rack * y + serial) * rack / 100 % 10 - 5
+        })
+        .collect();
+    Grid::from_vec(300, cells).unwrap()
+}
+
+#[test]
+fn summed_area_table() {
+    let grid = Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]).unwrap();
+    let table = grid.summed_area();
+    assert_eq!(Some(45), table.sum(0, 0, 3, 3));
+    assert_eq!(Some(5 + 6 + 8 + 9), table.sum(1, 1, 2, 2));
+    assert_eq!(Some(8), table.sum(2, 1, 1, 1));
+    assert_eq!(Some(0), table.sum(3, 3, 0, 0));
+    assert_eq!(None, table.sum(2, 2, 2, 1));
+}
+
+#[test]
+fn max_square_fixed_size() {
+    let best = fuel_cells(18).max_square_sum(3..=3).unwrap();
+    assert_eq!(
+        (45 - 1, 33 - 1, 3, 29),
+        (best.row, best.col, best.size, best.sum)
+    );
+    let best = fuel_cells(42).max_square_sum(Some(3)).unwrap();
+    assert_eq!((61 - 1, 21 - 1, 30), (best.row, best.col, best.sum));
+}
+
+#[test]
+fn max_square_any_size() {
+    let best = fuel_cells(18).max_square_sum(1..=300).unwrap();
+    assert_eq!(
+        (269 - 1, 90 - 1, 16, 113),
+        (best.row, best.col, best.size, best.sum)
+    );
+    let best = fuel_cells(42).max_square_sum(1..=300).unwrap();
+    assert_eq!(
+        (251 - 1, 232 - 1, 12, 119),
+        (best.row, best.col, best.size, best.sum)
+    );
+
+    let grid = Grid::from_vec(2, vec![1, 1, 1, 1]).unwrap();
+    assert_eq!(None, grid.max_square_sum(vec![0, 3]));
+}
+
+#[test]
+fn read_as_grid() {
+    let grid: Grid<bool> = FileReader::new()
+        .as_grid(|c| c == '#')
+        .read_from_file("tests/inputs/toboggan.txt")
+        .unwrap();
+    assert_eq!(toboggan_map(), grid);
+
+    let ragged: Result<Grid<char>, _> = FileReader::new()
+        .as_grid(|c| c)
+        .read_from_reader("ab\nc\n".as_bytes());
+    assert!(matches!(ragged, Err(Error::FormatError(_))));
+
+    let trimmed: Grid<char> = FileReader::new()
+        .trim()
+        .as_grid(|c| c)
+        .read_from_reader("  ab\ncd  \n".as_bytes())
+        .unwrap();
+    assert_eq!(Grid::parse("ab\ncd", |c| c).unwrap(), trimmed);
+}
+
+#[test]
+fn index_and_mutate() {
+    let mut grid = Grid::parse("abc\ndef", |c| c).unwrap();
+    assert_eq!('f', grid[(1, 2)]);
+    grid[(0, 0)] = 'x';
+    *grid.get_mut(1, 1).unwrap() = 'y';
+    assert_eq!(None, grid.get_mut(2, 0));
+    assert_eq!(Some(&['x', 'b', 'c'][..]), grid.row(0));
+    assert_eq!(None, grid.row(2));
+
+    let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+    assert_eq!(vec!["xbc", "dyf"], rows);
+    let columns: Vec<String> = grid.columns().map(|col| col.collect()).collect();
+    assert_eq!(vec!["xd", "by", "cf"], columns);
+    assert_eq!(0, grid.column(3).count());
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn index_out_of_bounds() {
+    let grid = Grid::parse("ab", |c| c).unwrap();
+    let _ = grid[(0, 2)];
+}
+
+#[test]
+fn neighbors_within_bounds() {
+    let grid = Grid::filled(3, 4, 0);
+    assert_eq!(
+        vec![(0, 2), (1, 1), (1, 3), (2, 2)],
+        grid.neighbors4(1, 2).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(0, 1), (1, 0)],
+        grid.neighbors4(0, 0).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        vec![(1, 2), (1, 3), (2, 2)],
+        grid.neighbors8(2, 3).collect::<Vec<_>>()
+    );
+    assert_eq!(8, grid.neighbors8(1, 1).count());
+    assert_eq!(0, grid.neighbors4(3, 0).count());
+}
+
+#[test]
+fn neighbors_wrapping() {
+    let grid = Grid::filled(3, 4, 0);
+    assert_eq!(
+        vec![(2, 0), (0, 3), (0, 1), (1, 0)],
+        grid.neighbors4_wrapping(0, 0).collect::<Vec<_>>()
+    );
+    let mut neighbors: Vec<_> = grid.neighbors8_wrapping(2, 3).collect();
+    neighbors.sort_unstable();
+    assert_eq!(
+        vec![
+            (0, 0),
+            (0, 2),
+            (0, 3),
+            (1, 0),
+            (1, 2),
+            (1, 3),
+            (2, 0),
+            (2, 2)
+        ],
+        neighbors
+    );
+}
+
+#[test]
+fn step_in_direction() {
+    let grid = Grid::filled(2, 3, 0);
+    assert_eq!(Some((1, 2)), grid.step(0, 2, Direction::South));
+    assert_eq!(None, grid.step(0, 2, Direction::North));
+    assert_eq!(None, grid.step(0, 2, Direction::East));
+    assert_eq!(Some((0, 1)), grid.step(0, 2, Direction::West));
+}
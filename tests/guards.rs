@@ -0,0 +1,64 @@
+use aoc_util::guards::{Event, Record, SleepLog, Timestamp};
+use aoc_util::input::{FileReader, FromFile};
+
+fn example() -> SleepLog {
+    let records: Vec<Record> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/guards.txt")
+        .unwrap();
+    SleepLog::new(records).unwrap()
+}
+
+#[test]
+fn parse_records() {
+    assert_eq!(
+        Ok(Record {
+            time: Timestamp {
+                year: 1518,
+                month: 11,
+                day: 1,
+                hour: 23,
+                minute: 58
+            },
+            event: Event::BeginShift(99)
+        }),
+        "[1518-11-01 23:58] Guard #99 begins shift".parse()
+    );
+    assert_eq!(
+        Ok(Event::WakesUp),
+        "[1518-11-01 00:25] wakes up"
+            .parse::<Record>()
+            .map(|r| r.event)
+    );
+    assert!("[1518-11-01 00:25] sleeps".parse::<Record>().is_err());
+    assert!("1518-11-01 00:25 wakes up".parse::<Record>().is_err());
+    assert!("[1518-11-01] wakes up".parse::<Record>().is_err());
+}
+
+#[test]
+fn sleep_histograms() {
+    let log = example();
+    assert_eq!(50, log.total_asleep(10));
+    assert_eq!(30, log.total_asleep(99));
+    assert_eq!(Some((24, 2)), log.sleepiest_minute(10));
+    assert_eq!(Some((45, 3)), log.sleepiest_minute(99));
+    assert_eq!(None, log.minutes(7));
+}
+
+#[test]
+fn sleep_strategies() {
+    let log = example();
+    assert_eq!(Some((10, 24)), log.sleepiest_guard());
+    assert_eq!(Some((99, 45)), log.most_regular_sleeper());
+}
+
+#[test]
+fn inconsistent_records() {
+    let record = |s: &str| s.parse::<Record>().unwrap();
+    assert!(SleepLog::new(vec![record("[1518-11-01 00:05] falls asleep")]).is_err());
+    assert!(SleepLog::new(vec![
+        record("[1518-11-01 00:00] Guard #10 begins shift"),
+        record("[1518-11-01 00:05] wakes up"),
+    ])
+    .is_err());
+}
@@ -0,0 +1,84 @@
+use aoc_util::hash::{
+    dense_hash, has_leading_zeros, knot_hash, knot_hash_hex, md5, md5_hex, md5_search, sparse_hash,
+};
+
+#[test]
+fn single_round() {
+    let list = sparse_hash(5, &[3, 4, 1, 5], 1);
+    assert_eq!(12, list[0] as u32 * list[1] as u32);
+    assert_eq!((0..=255).collect::<Vec<u8>>(), sparse_hash(256, &[], 1));
+}
+
+#[test]
+fn hex_digests() {
+    assert_eq!("a2582a3a0e66e6e86e3812dcb672a272", knot_hash_hex(""));
+    assert_eq!(
+        "33efeb34ea91902bb2f59c9920caa6cd",
+        knot_hash_hex("AoC 2017")
+    );
+    assert_eq!("3efbe78a8d82f29979031a4aa0b16a9d", knot_hash_hex("1,2,3"));
+    assert_eq!("63960835bcdc130f0b66d7ff4f6a5a8e", knot_hash_hex("1,2,4"));
+}
+
+#[test]
+fn dense_from_sparse() {
+    let sparse = [65, 27, 9, 1, 4, 3, 40, 50, 91, 7, 6, 0, 2, 5, 68, 22].repeat(16);
+    let dense = dense_hash(&sparse);
+    assert_eq!([64; 16], dense);
+    assert_eq!(0xa2, knot_hash("")[0]);
+}
+
+#[test]
+#[should_panic(expected = "length exceeds list size")]
+fn length_too_large() {
+    sparse_hash(5, &[6], 1);
+}
+
+#[test]
+fn md5_digests() {
+    assert_eq!("d41d8cd98f00b204e9800998ecf8427e", md5_hex(b""));
+    assert_eq!("900150983cd24fb0d6963f7d28e17f72", md5_hex(b"abc"));
+    assert_eq!(
+        "9e107d9d372bb6826bd81d3542a419d6",
+        md5_hex(b"The quick brown fox jumps over the lazy dog")
+    );
+    assert_eq!(
+        "57edf4a22be3c955ac49da2e2107b67a",
+        md5_hex(
+            b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+        )
+    );
+    assert_eq!("e3c4dd21a9171fd39d208efa09bf7883", md5_hex(&[0; 56]));
+    assert_eq!("3b5d3c7d207e37dceeedd301e35e2e58", md5_hex(&[0; 64]));
+}
+
+#[test]
+fn leading_zeros() {
+    let digest = md5(b"abcdef609043");
+    assert!(has_leading_zeros(&digest, 5));
+    assert!(!has_leading_zeros(&digest, 6));
+    assert!(has_leading_zeros(&[0; 16], 32));
+    assert!(has_leading_zeros(&[0xff; 16], 0));
+}
+
+#[test]
+fn search_counters() {
+    let zeros = |digest: &[u8; 16]| has_leading_zeros(digest, 5);
+    let (n, digest) = md5_search("abcdef", zeros)
+        .starting_at(600_000)
+        .next()
+        .unwrap();
+    assert_eq!(609043, n);
+    assert_eq!("000001dbbfa", &md5_hex(b"abcdef609043")[..11]);
+    assert_eq!(md5(b"abcdef609043"), digest);
+
+    // 2016 day 5: the sixth hex digit of each match is the next password character.
+    let (n, digest) = md5_search("abc", zeros)
+        .starting_at(3_200_000)
+        .threads(4)
+        .next()
+        .unwrap();
+    assert_eq!(3231929, n);
+    assert_eq!('1', md5_hex(b"abc3231929").chars().nth(5).unwrap());
+    assert_eq!(md5(b"abc3231929"), digest);
+}
@@ -0,0 +1,55 @@
+extern crate aoc_util;
+
+use aoc_util::hex::{flip_daily, flip_tiles, parse_path, Hex, HexDirection};
+use aoc_util::input::{FileReader, FromFile};
+
+fn example_paths() -> Vec<Vec<HexDirection>> {
+    let lines: Vec<String> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/hex_tiles.txt")
+        .unwrap();
+    lines.iter().map(|l| parse_path(l).unwrap()).collect()
+}
+
+#[test]
+fn parse_and_walk() {
+    let path = parse_path("esew").unwrap();
+    assert_eq!(
+        vec![
+            HexDirection::East,
+            HexDirection::SouthEast,
+            HexDirection::West
+        ],
+        path
+    );
+    assert_eq!(Hex::new(0, 1), Hex::walk(&path));
+    assert!(parse_path("nx").is_err());
+    assert!(parse_path("s").is_err());
+}
+
+#[test]
+fn distances() {
+    let origin = Hex::default();
+    let distance = |path: &str| origin.distance(Hex::walk(&parse_path(path).unwrap()));
+    assert_eq!(3, distance("nenene"));
+    assert_eq!(1, distance("nenesw"));
+    assert_eq!(2, distance("sesew"));
+    assert_eq!(0, distance("ewnwse"));
+    assert!(origin.neighbors().iter().all(|n| origin.distance(*n) == 1));
+}
+
+#[test]
+fn lobby_layout() {
+    let mut black = flip_tiles(&example_paths());
+    assert_eq!(10, black.len());
+
+    let expected = [15, 12, 25, 14, 23, 28, 41, 37, 49, 37];
+    for &count in &expected {
+        black = flip_daily(&black);
+        assert_eq!(count, black.len());
+    }
+    for _ in 10..100 {
+        black = flip_daily(&black);
+    }
+    assert_eq!(2208, black.len());
+}
@@ -1,6 +1,10 @@
 extern crate aoc_util;
 
-use aoc_util::input::{FileReader, FromFile};
+use std::io::{self, Read};
+
+use aoc_util::input::{
+    from_callback, Error, FileReader, Format, FromFile, FromProvider, FromReader, Lenient,
+};
 
 #[test]
 fn to_string() {
@@ -85,3 +89,319 @@ fn comma_separated_no_trim() {
         input
     );
 }
+
+/// Reader that returns its data in chunks of at most three bytes, then fails if `fail` is set.
+struct Chunked<'a> {
+    data: &'a [u8],
+    fail: bool,
+}
+
+impl Read for Chunked<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.data.is_empty() && self.fail {
+            return Err(io::Error::other("connection reset"));
+        }
+        let n = self.data.len().min(buf.len()).min(3);
+        buf[..n].copy_from_slice(&self.data[..n]);
+        self.data = &self.data[n..];
+        Ok(n)
+    }
+}
+
+#[test]
+fn from_in_memory_reader() {
+    let input: String = FileReader::new()
+        .trim()
+        .read_from_reader("  This is a string.\n".as_bytes())
+        .unwrap();
+    assert_eq!("This is a string.", input);
+
+    let input: Vec<u32> = FileReader::new()
+        .split_whitespace()
+        .read_from_reader(&b"4 8\n15 16\t23 42"[..])
+        .unwrap();
+    assert_eq!(vec![4, 8, 15, 16, 23, 42], input);
+
+    let input: Vec<String> = FileReader::new()
+        .split_char(',')
+        .trim()
+        .read_from_reader("a, b ,c".as_bytes())
+        .unwrap();
+    assert_eq!(vec!["a", "b", "c"], input);
+}
+
+#[test]
+fn from_chunked_reader() {
+    let reader = Chunked {
+        data: b"4\n8\n15\n16\n23\n42\n",
+        fail: false,
+    };
+    let input: Vec<u32> = FileReader::new()
+        .split_lines()
+        .read_from_reader(reader)
+        .unwrap();
+    assert_eq!(vec![4, 8, 15, 16, 23, 42], input);
+}
+
+#[test]
+fn reader_errors() {
+    let reader = Chunked {
+        data: b"4\n8\n",
+        fail: true,
+    };
+    let input: Result<Vec<u32>, _> = FileReader::new().split_lines().read_from_reader(reader);
+    assert!(matches!(input, Err(Error::IoError(_))));
+
+    let input: Result<Vec<u32>, _> = FileReader::new()
+        .split_char(',')
+        .read_from_reader("4,x".as_bytes());
+    assert!(matches!(input, Err(Error::ParseError(_))));
+
+    let input: io::Result<String> = FileReader::new().read_from_reader(&[0xff, 0xfe][..]);
+    assert!(input.is_err());
+}
+
+#[test]
+fn blank_line_groups() {
+    let input: Vec<Vec<u32>> = FileReader::new()
+        .split_groups()
+        .read_from_file("tests/inputs/groups.txt")
+        .unwrap();
+    assert_eq!(
+        vec![
+            vec![1000, 2000, 3000],
+            vec![4000],
+            vec![5000, 6000],
+            vec![7000, 8000, 9000],
+            vec![10000]
+        ],
+        input
+    );
+
+    let input: Vec<String> = FileReader::new()
+        .split_groups()
+        .read_from_file("tests/inputs/groups.txt")
+        .unwrap();
+    assert_eq!(5, input.len());
+    assert_eq!("5000\n6000", input[2]);
+}
+
+#[test]
+fn blank_line_groups_trim() {
+    let text = "\n\n abc\n\n a \nb\n c\n";
+    let input: Vec<String> = FileReader::new()
+        .split_groups()
+        .read_from_reader(text.as_bytes())
+        .unwrap();
+    assert_eq!(vec![" abc", " a \nb\n c"], input);
+
+    let input: Vec<Vec<String>> = FileReader::new()
+        .split_groups()
+        .trim()
+        .read_from_reader(text.as_bytes())
+        .unwrap();
+    assert_eq!(vec![vec!["abc"], vec!["a", "b", "c"]], input);
+
+    let input: Result<Vec<Vec<u32>>, _> = FileReader::new()
+        .split_groups()
+        .read_from_reader("1\n\n2\nx".as_bytes());
+    assert!(matches!(input, Err(Error::ParseError(_))));
+}
+
+#[test]
+fn skip_unparseable_lines() {
+    let input: Vec<i32> = FileReader::new()
+        .split_lines()
+        .skip_unparseable()
+        .read_from_file("tests/inputs/partially_malformed.txt")
+        .unwrap();
+    assert_eq!(vec![12, 7, -3], input);
+}
+
+#[test]
+fn collect_line_errors() {
+    let input: Lenient<i32, _> = FileReader::new()
+        .split_lines()
+        .collect_errors()
+        .read_from_file("tests/inputs/partially_malformed.txt")
+        .unwrap();
+    assert_eq!(vec![12, 7, -3], input.values);
+    let lines: Vec<usize> = input.errors.iter().map(|&(line, _)| line).collect();
+    assert_eq!(vec![2, 4], lines);
+
+    let input: Lenient<u8, _> = FileReader::new()
+        .split_lines()
+        .trim()
+        .collect_errors()
+        .read_from_reader(" 1 \n300\n".as_bytes())
+        .unwrap();
+    assert_eq!(vec![1], input.values);
+    assert_eq!(1, input.errors.len());
+    assert_eq!(2, input.errors[0].0);
+}
+
+#[test]
+fn iterate_lines_lazily() {
+    let mut iter = FileReader::new()
+        .split_lines()
+        .iter_from_file::<i32, _>("tests/inputs/partially_malformed.txt")
+        .unwrap();
+    assert_eq!(Some(12), iter.next().map(Result::unwrap));
+    assert!(matches!(iter.next(), Some(Err(Error::ParseError(_)))));
+    assert_eq!(Some(7), iter.next().map(Result::unwrap));
+
+    let sum: Result<i32, _> = FileReader::new()
+        .split_lines()
+        .trim()
+        .iter_from_reader::<i32, _>(" 1\n2 \n 3 ".as_bytes())
+        .sum();
+    assert_eq!(6, sum.unwrap());
+
+    assert!(FileReader::new()
+        .split_lines()
+        .iter_from_file::<i32, _>("tests/inputs/does_not_exist.txt")
+        .is_err());
+}
+
+#[test]
+fn string_delimited_numbers() {
+    let input: Vec<u32> = FileReader::new()
+        .trim()
+        .split_str(" -> ")
+        .read_from_file("tests/inputs/arrow_separated.txt")
+        .unwrap();
+    assert_eq!(vec![3, 14, 15, 92], input);
+
+    let input: Vec<String> = FileReader::new()
+        .split_str(", ")
+        .read_from_reader("a, b,c, d".as_bytes())
+        .unwrap();
+    assert_eq!(vec!["a", "b,c", "d"], input);
+}
+
+#[cfg(feature = "regex")]
+#[test]
+fn regex_delimited_numbers() {
+    let input: Vec<u32> = FileReader::new()
+        .split_regex(r"\s*(->|,)\s*")
+        .unwrap()
+        .trim()
+        .read_from_reader("1 -> 2,3 ,4->5\n".as_bytes())
+        .unwrap();
+    assert_eq!(vec![1, 2, 3, 4, 5], input);
+
+    assert!(FileReader::new().split_regex("(").is_err());
+}
+
+#[test]
+fn detect_input_format() {
+    let detect = |path: &str| -> Format {
+        FileReader::new()
+            .detect_format()
+            .read_from_file(path)
+            .unwrap()
+    };
+    assert_eq!(
+        Format::Delimited {
+            delimiter: ',',
+            count: 6
+        },
+        detect("tests/inputs/comma_separated.txt")
+    );
+    assert_eq!(
+        Format::Groups { count: 5 },
+        detect("tests/inputs/groups.txt")
+    );
+    assert_eq!(
+        Format::Grid { rows: 3, cols: 3 },
+        detect("tests/inputs/virus.txt")
+    );
+    assert_eq!(
+        Format::Lines { count: 6 },
+        detect("tests/inputs/newline_delimited.txt")
+    );
+
+    assert_eq!(Format::Empty, Format::detect("\n"));
+    assert_eq!(Format::Single, Format::detect("abc\n"));
+    assert_eq!(Format::Whitespace { count: 3 }, Format::detect("1 2\t3\n"));
+    assert_eq!(
+        Format::Lines { count: 4 },
+        Format::detect("199\n200\n208\n210\n")
+    );
+    assert_eq!(
+        "FileReader::new().trim().split_char(',')",
+        Format::detect("1,2").suggestion()
+    );
+}
+
+#[test]
+fn lines_into_tuples() {
+    let input: Vec<(String, u32, char)> = FileReader::new()
+        .split_lines()
+        .then_split_char(' ')
+        .read_from_file("tests/inputs/fields.txt")
+        .unwrap();
+    assert_eq!(
+        vec![
+            (String::from("forward"), 5, 'x'),
+            (String::from("down"), 8, 'y'),
+            (String::from("up"), 3, 'z'),
+        ],
+        input
+    );
+
+    let input: Vec<(i32, i32)> = FileReader::new()
+        .split_lines()
+        .trim()
+        .then_split_char(',')
+        .read_from_reader(" 1, 2\n-3 ,4 \n".as_bytes())
+        .unwrap();
+    assert_eq!(vec![(1, 2), (-3, 4)], input);
+
+    let result: Result<Vec<(i32, i32)>, _> = FileReader::new()
+        .split_lines()
+        .then_split_char(',')
+        .read_from_reader("1,2,3".as_bytes());
+    assert!(matches!(result, Err(Error::ParseError(_))));
+}
+
+#[test]
+fn lines_into_nested_vectors() {
+    let input: Vec<Vec<u32>> = FileReader::new()
+        .split_lines()
+        .then_split_char(',')
+        .read_from_file("tests/inputs/nested.txt")
+        .unwrap();
+    assert_eq!(vec![vec![1, 2, 3], vec![4, 5], vec![], vec![6]], input);
+
+    let input: Vec<Vec<i32>> = FileReader::new()
+        .split_lines()
+        .then_split_whitespace()
+        .read_from_reader("  1  -2 3\n4\t5 \n".as_bytes())
+        .unwrap();
+    assert_eq!(vec![vec![1, -2, 3], vec![4, 5]], input);
+}
+
+#[test]
+fn from_input_provider() {
+    let input: Vec<u32> = FileReader::new()
+        .split_char(',')
+        .read_from_provider("3,1,4")
+        .unwrap();
+    assert_eq!(vec![3, 1, 4], input);
+
+    let mut requests = 0;
+    let input: Vec<Vec<u32>> = FileReader::new()
+        .split_groups()
+        .read_from_provider(from_callback(|| {
+            requests += 1;
+            Ok(String::from("1\n2\n\n3\n"))
+        }))
+        .unwrap();
+    assert_eq!(vec![vec![1, 2], vec![3]], input);
+    assert_eq!(1, requests);
+
+    let failed: Result<String, _> =
+        FileReader::new().read_from_provider(from_callback(|| Err(String::from("fetch failed"))));
+    assert_eq!("fetch failed", failed.unwrap_err().to_string());
+}
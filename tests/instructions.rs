@@ -0,0 +1,117 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::instructions::conditional::{run, Instruction};
+use aoc_util::instructions::opcodes::{self, Opcode, Sample};
+use aoc_util::instructions::{Comparison, Registers};
+use aoc_util::optimize::Rng;
+
+#[test]
+fn comparisons() {
+    assert_eq!(Ok(Comparison::GreaterOrEqual), ">=".parse());
+    assert!("!=".parse::<Comparison>().unwrap().eval(1, 2));
+    assert!(!"<".parse::<Comparison>().unwrap().eval(2, 2));
+    assert!("<>".parse::<Comparison>().is_err());
+}
+
+#[test]
+fn registers_default_to_zero() {
+    let mut registers = Registers::new();
+    assert_eq!(0, registers.get("x"));
+    assert_eq!(None, registers.max());
+    assert_eq!(-3, registers.add("x", -3));
+    assert_eq!(Some(-3), registers.max());
+}
+
+#[test]
+fn conditional_program() {
+    let program: Vec<Instruction> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/conditional_registers.txt")
+        .unwrap();
+    assert_eq!(10, program[2].delta);
+
+    let outcome = run(&program);
+    assert_eq!(1, outcome.registers.get("a"));
+    assert_eq!(-10, outcome.registers.get("c"));
+    assert_eq!(Some(1), outcome.final_max);
+    assert_eq!(Some(10), outcome.all_time_max);
+}
+
+#[test]
+fn invalid_instruction() {
+    assert!("b mul 5 if a > 1".parse::<Instruction>().is_err());
+    assert!("b inc 5 when a > 1".parse::<Instruction>().is_err());
+}
+
+fn generate_samples(mapping: &[Opcode], count: usize) -> Vec<Sample> {
+    let mut rng = Rng::new(16);
+    (0..count)
+        .map(|_| {
+            let before: Vec<u64> = (0..4).map(|_| rng.below(4)).collect();
+            let number = rng.below(16);
+            let instruction = [number, rng.below(4), rng.below(4), rng.below(4)];
+            let mut after = before.clone();
+            let [_, a, b, c] = instruction;
+            mapping[number as usize]
+                .execute(&mut after, a, b, c)
+                .unwrap();
+            Sample {
+                before,
+                instruction,
+                after,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn opcode_sample_candidates() {
+    let input = "Before: [3, 2, 1, 1]\n9 2 1 2\nAfter:  [3, 2, 2, 1]\n\n\n\n9 0 0 1\n";
+    let (samples, program) = opcodes::parse(input).unwrap();
+    assert_eq!(1, samples.len());
+    assert_eq!(vec![[9, 0, 0, 1]], program);
+    assert_eq!(1, opcodes::count_ambiguous(&samples, &Opcode::ALL, 3));
+    assert_eq!(0, opcodes::count_ambiguous(&samples, &Opcode::ALL, 4));
+
+    assert!("Before: [3, 2, 1, 1]\n9 2 1\nAfter:  [3, 2, 2, 1]"
+        .parse::<Sample>()
+        .is_err());
+    assert!(opcodes::parse("Before: [3, 2, 1, 1]\n9 2 1 2\n").is_err());
+}
+
+#[test]
+fn opcode_execution() {
+    let mut registers = vec![1, 2, 3, 4];
+    assert_eq!(Some(()), Opcode::Mulr.execute(&mut registers, 1, 2, 0));
+    assert_eq!(vec![6, 2, 3, 4], registers);
+    assert_eq!(Some(()), Opcode::Gtir.execute(&mut registers, 5, 3, 1));
+    assert_eq!(vec![6, 1, 3, 4], registers);
+    assert_eq!(None, Opcode::Addr.execute(&mut registers, 4, 0, 0));
+    assert_eq!(None, Opcode::Seti.execute(&mut registers, 4, 0, 9));
+    assert_eq!(vec![6, 1, 3, 4], registers);
+}
+
+#[test]
+fn deduce_opcode_mapping() {
+    let mut mapping = Opcode::ALL.to_vec();
+    Rng::new(7).shuffle(&mut mapping);
+    let samples = generate_samples(&mapping, 800);
+    assert_eq!(
+        Some(mapping.clone()),
+        opcodes::deduce(&samples, &Opcode::ALL)
+    );
+
+    // Not enough information with only a handful of samples.
+    assert_eq!(None, opcodes::deduce(&samples[..3], &Opcode::ALL));
+
+    let number = |op| mapping.iter().position(|&m| m == op).unwrap() as u64;
+    let program = [
+        [number(Opcode::Seti), 7, 0, 0],
+        [number(Opcode::Muli), 0, 6, 1],
+        [number(Opcode::Eqri), 1, 42, 2],
+    ];
+    let mut registers = vec![0; 4];
+    assert_eq!(Some(()), opcodes::run(&program, &mapping, &mut registers));
+    assert_eq!(vec![7, 42, 1, 0], registers);
+}
@@ -0,0 +1,199 @@
+extern crate aoc_util;
+
+use std::collections::VecDeque;
+use std::sync::mpsc;
+use std::thread;
+
+use aoc_util::intcode::{self, parse_program, Machine, Network, Packet, Status};
+
+fn machine(program: &str) -> Machine {
+    program.parse().unwrap()
+}
+
+#[test]
+fn add_and_multiply() {
+    let mut m = machine("1,9,10,3,2,3,11,0,99,30,40,50");
+    assert_eq!(Ok(Status::Halted), m.run((), Vec::new()));
+    assert_eq!(3500, m.get(0));
+    assert_eq!(&[3500, 9, 10, 70], &m.memory()[..4]);
+
+    let mut m = machine("1002,4,3,4,33");
+    m.run((), Vec::new()).unwrap();
+    assert_eq!(99, m.get(4));
+    assert!(m.is_halted());
+}
+
+#[test]
+fn comparisons_and_jumps() {
+    let program = "3,21,1008,21,8,20,1005,20,22,107,8,21,20,1006,20,31,1106,0,36,98,0,0,1002,21,\
+                   125,20,4,20,1105,1,46,104,999,1105,1,46,1101,1000,1,20,4,20,1105,1,46,98,99";
+    assert_eq!(Ok(vec![999]), machine(program).run_with(&[7]));
+    assert_eq!(Ok(vec![1000]), machine(program).run_with(&[8]));
+    assert_eq!(Ok(vec![1001]), machine(program).run_with(&[9]));
+
+    assert_eq!(
+        Ok(vec![0]),
+        machine("3,3,1107,-1,8,3,4,3,99").run_with(&[8])
+    );
+    assert_eq!(
+        Ok(vec![1]),
+        machine("3,3,1105,-1,9,1101,0,0,12,4,12,99,1").run_with(&[5])
+    );
+}
+
+#[test]
+fn relative_base_and_large_memory() {
+    let quine = "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99";
+    assert_eq!(
+        Ok(parse_program(quine).unwrap()),
+        machine(quine).run_with(&[])
+    );
+    assert_eq!(
+        Ok(vec![1219070632396864]),
+        machine("1102,34915192,34915192,7,4,7,99,0").run_with(&[])
+    );
+    assert_eq!(
+        Ok(vec![1125899906842624]),
+        machine("104,1125899906842624,99").run_with(&[])
+    );
+}
+
+#[test]
+fn pause_for_input() {
+    // 2019 day 7: amplifiers in a feedback loop.
+    let program = parse_program(
+        "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+    )
+    .unwrap();
+    let phases = [9, 8, 7, 6, 5];
+    let mut amps: Vec<Machine> = phases
+        .iter()
+        .map(|_| Machine::new(program.clone()))
+        .collect();
+    let mut queues: Vec<VecDeque<i64>> = phases.iter().map(|&p| VecDeque::from(vec![p])).collect();
+    queues[0].push_back(0);
+
+    let mut last = None;
+    while !amps[4].is_halted() {
+        for i in 0..5 {
+            let mut output = Vec::new();
+            let status = amps[i].run(&mut queues[i], &mut output).unwrap();
+            assert_ne!(Status::Running, status);
+            if i == 4 {
+                last = output.last().copied().or(last);
+            }
+            queues[(i + 1) % 5].extend(output);
+        }
+    }
+    assert_eq!(Some(139629729), last);
+}
+
+#[test]
+fn closures_and_channels() {
+    let mut inputs = vec![3, 4].into_iter();
+    let mut sum = 0;
+    let mut m = machine("3,0,3,1,1,0,1,0,4,0,99");
+    let status = m.run(
+        intcode::from_fn(|| inputs.next()),
+        intcode::to_fn(|v| sum += v),
+    );
+    assert_eq!(Ok(Status::Halted), status);
+    assert_eq!(7, sum);
+
+    let (in_tx, in_rx) = mpsc::channel();
+    let (out_tx, out_rx) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        let mut m = machine("3,0,102,2,0,0,4,0,99");
+        m.run(in_rx, out_tx)
+    });
+    in_tx.send(21).unwrap();
+    assert_eq!(Ok(42), out_rx.recv());
+    assert_eq!(Ok(Status::Halted), handle.join().unwrap());
+}
+
+#[test]
+fn invalid_programs() {
+    assert!("1,2,x".parse::<Machine>().is_err());
+    assert!(machine("42").run((), Vec::new()).is_err());
+    assert!(machine("1101,1,1,-1,99").run((), Vec::new()).is_err());
+    assert!(machine("11101,1,1,3,99").run((), Vec::new()).is_err());
+    assert!(machine("301,1,1,3,99").run((), Vec::new()).is_err());
+    assert_eq!(
+        Ok(Status::NeedsInput),
+        machine("3,0,99").run((), Vec::new())
+    );
+}
+
+#[test]
+fn feedback_loop_on_threads() {
+    let program = parse_program(
+        "3,26,1001,26,-4,26,3,27,1002,27,2,27,1,27,26,27,4,27,1001,28,-1,28,1005,28,6,99,0,0,5",
+    )
+    .unwrap();
+
+    // Amplifier `i` reads from channel `i` and writes to channel `i + 1`; the last channel is
+    // forwarded back to the first one.
+    let (senders, receivers): (Vec<_>, Vec<_>) = (0..6).map(|_| mpsc::channel()).unzip();
+    for (i, &phase) in [9, 8, 7, 6, 5].iter().enumerate() {
+        senders[i].send(phase).unwrap();
+    }
+    senders[0].send(0).unwrap();
+
+    let mut receivers = receivers.into_iter();
+    let handles: Vec<_> = (0..5)
+        .map(|i| {
+            let machine = Machine::new(program.clone());
+            intcode::spawn(machine, receivers.next().unwrap(), senders[i + 1].clone())
+        })
+        .collect();
+    let last_receiver = receivers.next().unwrap();
+    let first_sender = senders[0].clone();
+    drop(senders);
+
+    let mut last = None;
+    for value in last_receiver {
+        last = Some(value);
+        let _ = first_sender.send(value);
+    }
+    assert_eq!(Some(139629729), last);
+    for handle in handles {
+        assert!(handle.join().unwrap().unwrap().is_halted());
+    }
+}
+
+/// Reads its address, then forwards every packet `(x, y)` as `(x + 1, y)` to the next address.
+const FORWARD: &str = "3,100,3,101,1008,101,-1,103,1005,103,2,3,102,1001,100,1,104,4,104,\
+                       1001,101,1,101,4,101,4,102,1105,1,2";
+
+#[test]
+fn network_rounds() {
+    let mut network = Network::new(&parse_program(FORWARD).unwrap(), 3);
+    assert_eq!(3, network.len());
+
+    let round = network.step().unwrap();
+    assert!(round.external.is_empty());
+    assert!(!round.idle);
+    assert!(network.step().unwrap().idle);
+
+    network.send(0, 10, 5);
+    let round = network.step().unwrap();
+    assert_eq!(
+        vec![Packet {
+            destination: 3,
+            x: 13,
+            y: 5
+        }],
+        round.external
+    );
+    assert!(!round.idle);
+    assert!(network.step().unwrap().idle);
+}
+
+#[test]
+fn network_with_nat() {
+    let mut network = Network::new(&parse_program(FORWARD).unwrap(), 3);
+    assert_eq!(Ok(None), network.run_nat(3));
+
+    network.send(0, 0, 42);
+    assert_eq!(Ok(Some((42, 42))), network.run_nat(3));
+}
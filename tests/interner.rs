@@ -0,0 +1,28 @@
+use aoc_util::collections::Interner;
+
+#[test]
+fn intern_and_resolve() {
+    let mut names = Interner::new();
+    assert!(names.is_empty());
+    assert_eq!(0, names.intern("AA"));
+    assert_eq!(1, names.intern("DD"));
+    assert_eq!(0, names.intern("AA"));
+    assert_eq!(2, names.len());
+    assert_eq!(None, names.get("BB"));
+    assert_eq!(Some("DD"), names.resolve(1));
+    assert_eq!(None, names.resolve(2));
+}
+
+#[test]
+fn collect_names() {
+    let names: Interner = "AA BB AA CC".split(' ').collect();
+    assert_eq!(
+        vec![(0, "AA"), (1, "BB"), (2, "CC")],
+        names.iter().collect::<Vec<_>>()
+    );
+
+    // Per-node data lives in a vector indexed by ID.
+    let mut rates = vec![0; names.len()];
+    rates[names.get("BB").unwrap()] = 13;
+    assert_eq!(vec![0, 13, 0], rates);
+}
@@ -0,0 +1,65 @@
+use aoc_util::collections::CircularList;
+use aoc_util::marbles;
+
+#[test]
+fn circular_list() {
+    let mut list = CircularList::new();
+    assert_eq!(None, list.current());
+    assert_eq!(None, list.remove());
+    list.move_forward(3);
+
+    for i in 0..5 {
+        list.insert(i);
+    }
+    assert_eq!(5, list.len());
+    list.move_forward(7);
+    assert_eq!(Some(&1), list.current());
+    list.move_backward(3);
+    assert_eq!(Some(&3), list.current());
+
+    assert_eq!(Some(3), list.remove());
+    assert_eq!(vec![4, 0, 1, 2], list.iter().copied().collect::<Vec<_>>());
+
+    // Removed slots are reused.
+    list.insert(9);
+    assert_eq!(
+        vec![9, 0, 1, 2, 4],
+        list.iter().copied().collect::<Vec<_>>()
+    );
+
+    while list.remove().is_some() {}
+    assert!(list.is_empty());
+    list.insert(1);
+    assert_eq!(vec![1], list.iter().copied().collect::<Vec<_>>());
+}
+
+#[test]
+fn marble_high_scores() {
+    let games = [
+        (9, 25, 32),
+        (10, 1618, 8317),
+        (13, 7999, 146_373),
+        (17, 1104, 2764),
+        (21, 6111, 54_718),
+        (30, 5807, 37_305),
+    ];
+    for &(players, last_marble, score) in games.iter() {
+        assert_eq!(score, marbles::high_score(players, last_marble));
+    }
+}
+
+#[test]
+fn marble_scores_per_player() {
+    let scores = marbles::scores(9, 25);
+    assert_eq!(32, scores[4]);
+    assert_eq!(32, scores.iter().sum::<u64>());
+}
+
+#[test]
+fn parse_game() {
+    assert_eq!(
+        Ok((13, 7999)),
+        marbles::parse("13 players; last marble is worth 7999 points\n")
+    );
+    assert!(marbles::parse("13 players; last marble is worth points").is_err());
+}
@@ -0,0 +1,148 @@
+extern crate aoc_util;
+
+use aoc_util::math::{
+    checked_gcd, checked_lcm, checked_lcm_of, crt, discrete_log_bruteforce, egcd, gcd, lcm, lcm_of,
+    mod_inverse, mod_pow, next_multiple, transform,
+};
+
+#[test]
+fn modular_exponentiation() {
+    assert_eq!(445, mod_pow(4, 13, 497));
+    assert_eq!(1, mod_pow(7, 0, 13));
+    assert_eq!(0, mod_pow(7, 0, 1));
+    assert_eq!(
+        (u64::MAX - 1) % 1_000_000_007,
+        mod_pow(u64::MAX - 1, 1, 1_000_000_007)
+    );
+}
+
+#[test]
+fn handshake() {
+    const MODULUS: u64 = 20201227;
+    let card_loop = discrete_log_bruteforce(7, MODULUS, 5764801).unwrap();
+    let door_loop = discrete_log_bruteforce(7, MODULUS, 17807724).unwrap();
+    assert_eq!(8, card_loop);
+    assert_eq!(11, door_loop);
+    assert_eq!(14897079, transform(17807724, card_loop, MODULUS));
+    assert_eq!(14897079, transform(5764801, door_loop, MODULUS));
+}
+
+#[test]
+fn no_discrete_log() {
+    // Powers of 2 modulo 7 are 1, 2, 4
+    assert_eq!(None, discrete_log_bruteforce(2, 7, 3));
+    assert_eq!(Some(0), discrete_log_bruteforce(2, 7, 1));
+}
+
+#[test]
+fn next_multiples() {
+    assert_eq!(944, next_multiple(939, 59));
+    assert_eq!(14, next_multiple(14, 7));
+    assert_eq!(0, next_multiple(0, 7));
+}
+
+#[test]
+fn extended_gcd() {
+    assert_eq!((2, -9, 47), egcd(240, 46));
+    assert_eq!((5, 1, 0), egcd(5, 0));
+    assert_eq!((5, 0, -1), egcd(0, -5));
+    let (g, x, y) = egcd(-12, 18);
+    assert_eq!(6, g);
+    assert_eq!(6, -12 * x + 18 * y);
+}
+
+#[test]
+fn modular_inverse() {
+    assert_eq!(Some(4), mod_inverse(3, 11));
+    assert_eq!(Some(7), mod_inverse(-3, 11));
+    assert_eq!(None, mod_inverse(6, 9));
+    assert_eq!(Some(0), mod_inverse(5, 1));
+    let m = 1_000_000_007;
+    assert_eq!(1, mod_inverse(123_456_789, m).unwrap() * 123_456_789 % m);
+}
+
+#[test]
+fn chinese_remainder() {
+    assert_eq!(Some((23, 105)), crt(&[2, 3, 2], &[3, 5, 7]));
+    assert_eq!(Some((0, 1)), crt(&[], &[]));
+    assert_eq!(Some((4, 12)), crt(&[0, 4], &[4, 6]));
+    assert_eq!(None, crt(&[0, 1], &[4, 6]));
+    assert_eq!(Some((1, 5)), crt(&[-4], &[5]));
+
+    // 2020 day 13: bus `m` at offset `i` means `t ≡ -i (mod m)`.
+    assert_eq!(
+        Some((1202161486, 1789 * 37 * 47 * 1889)),
+        crt(&[0, -1, -2, -3], &[1789, 37, 47, 1889])
+    );
+    let large = [999_999_937, 999_999_929];
+    assert_eq!(Some(large[0] * large[1]), crt(&[1, 2], &large).map(|s| s.1));
+    assert_eq!(
+        None,
+        crt(&[1, 2, 3], &[999_999_937, 999_999_929, 999_999_893])
+    );
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(6, gcd(12u8, 18));
+    assert_eq!(6, gcd(-12i64, -18));
+    assert_eq!(7, gcd(0usize, 7));
+    assert_eq!(0, gcd(0i32, 0));
+    assert_eq!(36, lcm(-12i16, 18));
+    assert_eq!(0, lcm(0u32, 5));
+    assert_eq!(232_792_560u64, lcm_of(1..=20));
+    assert_eq!(1, lcm_of(Vec::<i32>::new()));
+}
+
+#[test]
+fn gcd_of_min() {
+    assert_eq!(1, gcd(i32::MIN, -1));
+    assert_eq!(1, gcd(-1, i64::MIN));
+    assert_eq!(2, gcd(i8::MIN, 6));
+    assert_eq!(i16::MIN, gcd(i16::MIN, 0));
+    assert_eq!(i128::MIN, gcd(i128::MIN, i128::MIN));
+
+    assert_eq!(Some(1), checked_gcd(i32::MIN, -1));
+    assert_eq!(None, checked_gcd(i16::MIN, 0));
+    assert_eq!(None, checked_gcd(isize::MIN, isize::MIN));
+    assert_eq!(Some(u8::MAX), checked_gcd(u8::MAX, 0));
+}
+
+#[test]
+fn lcm_of_min() {
+    assert_eq!(0, lcm(i32::MIN, 0));
+    assert_eq!(None, checked_lcm(i32::MIN, -1));
+    assert_eq!(None, checked_lcm(i64::MIN, i64::MIN));
+    assert_eq!(Some(i8::MAX), checked_lcm(i8::MIN + 1, -1));
+    assert_eq!(None, checked_lcm_of(vec![3, i16::MIN]));
+}
+
+#[test]
+fn signed_lcm_beyond_max() {
+    // The product fits into `u32`, but not into `i32`.
+    assert_eq!(2_499_950_000u32, lcm(50000, 49999));
+    assert_eq!(None, checked_lcm(50000i32, 49999));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "lcm overflows")]
+fn signed_lcm_overflow_panics() {
+    lcm(50000i32, 49999);
+}
+
+#[test]
+fn checked_lcms() {
+    assert_eq!(Some(36), checked_lcm(12u8, 18));
+    assert_eq!(None, checked_lcm(250u8, 3));
+    assert_eq!(Some(0), checked_lcm(i8::MIN, 0));
+    assert_eq!(None, checked_lcm(i8::MIN, 1));
+    assert_eq!(Some(120), checked_lcm(i8::MIN + 8, -8));
+    // Cycle lengths like those of 2023 day 8 have an lcm beyond `u32`.
+    let cycles = [20777u64, 19199, 18673, 16043, 12361, 15517];
+    assert_eq!(None, checked_lcm_of(cycles.iter().map(|&c| c as u32)));
+    assert_eq!(
+        Some(18_215_611_419_223),
+        checked_lcm_of(cycles.iter().copied())
+    );
+}
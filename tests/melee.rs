@@ -0,0 +1,89 @@
+use aoc_util::game::melee::{min_winning_elf_attack, Battle, Race};
+
+const EXAMPLE: &str = "\
+#######
+#.G...#
+#...EG#
+#.#.#G#
+#..G#E#
+#.....#
+#######";
+
+fn run(map: &str) -> (Race, usize, u32) {
+    let mut battle: Battle = map.parse().unwrap();
+    let outcome = battle.run();
+    (outcome.winner, outcome.full_rounds, outcome.remaining_hp)
+}
+
+#[test]
+fn goblins_win() {
+    let mut battle: Battle = EXAMPLE.parse().unwrap();
+    assert!(battle.round());
+    assert!(battle.round());
+    let goblin_hp: Vec<u32> = battle
+        .units()
+        .iter()
+        .filter(|u| u.race == Race::Goblin)
+        .map(|u| u.hp)
+        .collect();
+    assert_eq!(vec![200, 194, 194, 200], goblin_hp);
+
+    let outcome = battle.run();
+    assert_eq!(
+        (Race::Goblin, 47, 590),
+        (outcome.winner, outcome.full_rounds, outcome.remaining_hp)
+    );
+    assert_eq!(27730, outcome.score());
+    assert_eq!(2, outcome.elf_deaths);
+}
+
+#[test]
+fn more_battles() {
+    let maps = [
+        (
+            "#######\n#G..#E#\n#E#E.E#\n#G.##.#\n#...#E#\n#...E.#\n#######",
+            (Race::Elf, 37, 982),
+        ),
+        (
+            "#######\n#E..EG#\n#.#G.E#\n#E.##E#\n#G..#.#\n#..E#.#\n#######",
+            (Race::Elf, 46, 859),
+        ),
+        (
+            "#######\n#E.G#.#\n#.#G..#\n#G.#.G#\n#G..#.#\n#...E.#\n#######",
+            (Race::Goblin, 35, 793),
+        ),
+        (
+            "#########\n#G......#\n#.E.#...#\n#..##..G#\n#...##..#\n#...#...#\n#.G...G.#\n#.....G.#\n#########",
+            (Race::Goblin, 20, 937),
+        ),
+    ];
+    for (map, expected) in maps.iter() {
+        assert_eq!(*expected, run(map));
+    }
+}
+
+#[test]
+fn movement_tie_breaking() {
+    // The elf has two squares in range at distance 3 and picks the first in reading order,
+    // stepping right rather than down.
+    let mut battle: Battle = "#######\n#.E...#\n#.....#\n#...G.#\n#######"
+        .parse()
+        .unwrap();
+    battle.round();
+    assert_eq!((1, 3), battle.units()[0].pos);
+}
+
+#[test]
+fn elves_without_losses() {
+    let battle: Battle = EXAMPLE.parse().unwrap();
+    let (attack, outcome) = min_winning_elf_attack(&battle).unwrap();
+    assert_eq!(15, attack);
+    assert_eq!(4988, outcome.score());
+    assert_eq!(0, outcome.elf_deaths);
+}
+
+#[test]
+fn invalid_map() {
+    assert!("###\n#X#\n###".parse::<Battle>().is_err());
+    assert!("###\n#E\n###".parse::<Battle>().is_err());
+}
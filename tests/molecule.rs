@@ -0,0 +1,47 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::molecule::{self, Rules};
+
+#[test]
+fn parse_input() {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/molecule.txt")
+        .unwrap();
+    let (rules, molecule) = molecule::parse(&input).unwrap();
+    assert_eq!(5, rules.rules().len());
+    assert_eq!(("H".to_string(), "HO".to_string()), rules.rules()[2]);
+    assert_eq!("HOH", molecule);
+}
+
+#[test]
+fn distinct_single_step_productions() {
+    let rules: Rules = "H => HO\nH => OH\nO => HH".parse().unwrap();
+    assert_eq!(4, rules.single_step("HOH").len());
+    assert_eq!(7, rules.single_step("HOHOHO").len());
+}
+
+#[test]
+fn reduce_to_electron() {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/molecule.txt")
+        .unwrap();
+    let (rules, molecule) = molecule::parse(&input).unwrap();
+    assert_eq!(Some(3), rules.reduce(&molecule, "e", 42, 100));
+    assert_eq!(Some(6), rules.reduce("HOHOHO", "e", 42, 100));
+    assert_eq!(None, rules.reduce("X", "e", 42, 10));
+}
+
+#[test]
+fn non_shrinking_rules_terminate() {
+    let rules: Rules = "H => H\nHO => OH\nOH => HO\ne => HO\nO => OO"
+        .parse()
+        .unwrap();
+    assert_eq!(Some(2), rules.reduce("HOO", "e", 42, 10));
+    assert_eq!(None, rules.reduce("OH", "e", 42, 10));
+}
+
+#[test]
+fn invalid_rule() {
+    assert!("H -> HO".parse::<Rules>().is_err());
+}
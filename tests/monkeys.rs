@@ -0,0 +1,60 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::monkeys::{ModulusPool, Monkey, Operation, Troop};
+
+fn example() -> Troop {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/monkeys.txt")
+        .unwrap();
+    input.parse().unwrap()
+}
+
+#[test]
+fn parse_operations() {
+    assert_eq!(Ok(Operation::Mul(19)), "new = old * 19".parse());
+    assert_eq!(Ok(Operation::Add(6)), "old + 6".parse());
+    assert_eq!(Ok(Operation::Square), "new = old * old".parse());
+    assert!("new = old - 3".parse::<Operation>().is_err());
+}
+
+#[test]
+fn modulus_pool() {
+    let pool = ModulusPool::new(vec![4, 6, 10]);
+    assert_eq!(60, pool.modulus());
+    assert_eq!(1, pool.reduce(61));
+    assert_eq!(180, ModulusPool::with_relief(vec![4, 6, 10], 3).modulus());
+}
+
+#[test]
+#[should_panic(expected = "divisor must not be 0")]
+fn modulus_pool_of_zero() {
+    ModulusPool::new(vec![4, 0]);
+}
+
+#[test]
+fn zero_divisor_is_rejected() {
+    let input = "Monkey 0:
+  Starting items: 79, 98
+  Operation: new = old * 19
+  Test: divisible by 0
+    If true: throw to monkey 2
+    If false: throw to monkey 3";
+    assert!(input.parse::<Monkey>().is_err());
+    assert!(input.replace("by 0", "by 23").parse::<Monkey>().is_ok());
+}
+
+#[test]
+fn twenty_rounds_with_relief() {
+    let mut troop = example();
+    troop.run(20, 3);
+    assert_eq!(&[101, 95, 7, 105], troop.inspections());
+    assert_eq!(10605, troop.monkey_business());
+}
+
+#[test]
+fn ten_thousand_rounds_without_relief() {
+    let mut troop = example();
+    troop.run(10_000, 1);
+    assert_eq!(2_713_310_158, troop.monkey_business());
+}
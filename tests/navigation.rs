@@ -0,0 +1,46 @@
+extern crate aoc_util;
+
+use aoc_util::geometry::Point2;
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::navigation::{Action, Ferry, Mode};
+
+fn actions() -> Vec<Action> {
+    FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/navigation.txt")
+        .unwrap()
+}
+
+#[test]
+fn parse_actions() {
+    assert_eq!(Ok(Action::Forward(10)), "F10".parse());
+    assert_eq!(Ok(Action::Left(270)), "L270".parse());
+    assert!("R45".parse::<Action>().is_err());
+    assert!("X1".parse::<Action>().is_err());
+    assert!("".parse::<Action>().is_err());
+}
+
+#[test]
+fn move_ship() {
+    let mut ferry = Ferry::new(Mode::Ship);
+    ferry.run(&actions());
+    assert_eq!(Point2::new(17, 8), ferry.position());
+    assert_eq!(Point2::new(0, 1), ferry.heading());
+    assert_eq!(25, ferry.distance_from_start());
+}
+
+#[test]
+fn move_waypoint() {
+    let mut ferry = Ferry::new(Mode::Waypoint);
+    ferry.run(&actions());
+    assert_eq!(Point2::new(214, 72), ferry.position());
+    assert_eq!(Point2::new(4, 10), ferry.heading());
+    assert_eq!(286, ferry.distance_from_start());
+}
+
+#[test]
+fn left_and_right_cancel() {
+    let mut ferry = Ferry::with_heading(Mode::Ship, Point2::new(0, -1));
+    ferry.run(&[Action::Left(270), Action::Right(270), Action::Forward(2)]);
+    assert_eq!(Point2::new(0, -2), ferry.position());
+}
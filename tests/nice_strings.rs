@@ -0,0 +1,59 @@
+extern crate aoc_util;
+
+use aoc_util::strings::{
+    contains_forbidden, find_abas, has_aba_pattern, has_abba, has_double_letter, has_n_vowels,
+    has_pair_appearing_twice, split_brackets, supports_ssl, supports_tls,
+};
+
+fn nice_v1(s: &str) -> bool {
+    has_n_vowels(s, 3) && has_double_letter(s) && !contains_forbidden(s, &["ab", "cd", "pq", "xy"])
+}
+
+fn nice_v2(s: &str) -> bool {
+    has_pair_appearing_twice(s) && has_aba_pattern(s)
+}
+
+#[test]
+fn first_ruleset() {
+    assert!(nice_v1("ugknbfddgicrmopn"));
+    assert!(nice_v1("aaa"));
+    assert!(!nice_v1("jchzalrnumimnmhp"));
+    assert!(!nice_v1("haegwjzuvuyypxyu"));
+    assert!(!nice_v1("dvszwmarrgswjxmb"));
+}
+
+#[test]
+fn second_ruleset() {
+    assert!(nice_v2("qjhvhtzxzqqjkmpb"));
+    assert!(nice_v2("xxyxx"));
+    assert!(!nice_v2("uurcxstgmygtbstg"));
+    assert!(!nice_v2("ieodomkazucvgmuy"));
+    assert!(!has_pair_appearing_twice("aaa"));
+}
+
+#[test]
+fn bracket_sections() {
+    assert_eq!(
+        (vec!["abba", "qrst"], vec!["mnop"]),
+        split_brackets("abba[mnop]qrst")
+    );
+    assert!(has_abba("ioxxoj"));
+    assert!(!has_abba("aaaa"));
+    assert_eq!(vec![('z', 'a'), ('z', 'b')], find_abas("zazbz"));
+}
+
+#[test]
+fn ipv7_tls() {
+    assert!(supports_tls("abba[mnop]qrst"));
+    assert!(!supports_tls("abcd[bddb]xyyx"));
+    assert!(!supports_tls("aaaa[qwer]tyui"));
+    assert!(supports_tls("ioxxoj[asdfgh]zxcvbn"));
+}
+
+#[test]
+fn ipv7_ssl() {
+    assert!(supports_ssl("aba[bab]xyz"));
+    assert!(!supports_ssl("xyx[xyx]xyx"));
+    assert!(supports_ssl("aaa[kek]eke"));
+    assert!(supports_ssl("zazbz[bzb]cdb"));
+}
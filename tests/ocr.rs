@@ -0,0 +1,410 @@
+use aoc_util::grid::Grid;
+use aoc_util::ocr::{self, FONT_4X6, FONT_6X10};
+
+#[test]
+fn render_and_decode_large_font() {
+    let letters = "ABCEFGHJKLNPRXZ";
+    for letter in letters.chars() {
+        let rendered = FONT_6X10.render(&letter.to_string()).unwrap();
+        assert_eq!(Some(letter.to_string()), ocr::decode(&rendered));
+    }
+    let rendered = FONT_6X10.render(letters).unwrap();
+    assert_eq!(Some(String::from(letters)), ocr::decode(&rendered));
+}
+
+#[test]
+fn render_and_decode_small_font() {
+    let letters = "ABCEFGHIJKLOPRSUYZ";
+    for letter in letters.chars() {
+        let rendered = FONT_4X6.render(&letter.to_string()).unwrap();
+        assert_eq!(Some(letter.to_string()), ocr::decode(&rendered));
+    }
+    let rendered = FONT_4X6.render(letters).unwrap();
+    assert_eq!(Some(String::from(letters)), ocr::decode(&rendered));
+}
+
+#[test]
+fn decode_small_font() {
+    let rendered = "\
+####..##..#..#.###..
+#....#..#.#..#.#..#.
+###..#....#..#.#..#.
+#....#....#..#.###..
+#....#..#.#..#.#.#..
+####..##...##..#..#.";
+    assert_eq!(Some(String::from("ECUR")), ocr::decode(rendered));
+
+    let rendered = ".###.\n..#..\n..#..\n..#..\n..#..\n.###.";
+    assert_eq!(Some(String::from("I")), ocr::decode(rendered));
+}
+
+/// All letters of the small font.
+const SMALL_LETTERS: &str = "ABCEFGHIJKLOPRSUYZ";
+
+/// The letters of the small font as they appear in puzzle outputs, 4 pixels wide (5 for `Y`),
+/// in the order of `SMALL_LETTERS`.
+const SMALL_GLYPHS: [&str; 18] = [
+    "\
+.##.
+#..#
+#..#
+####
+#..#
+#..#",
+    "\
+###.
+#..#
+###.
+#..#
+#..#
+###.",
+    "\
+.##.
+#..#
+#...
+#...
+#..#
+.##.",
+    "\
+####
+#...
+###.
+#...
+#...
+####",
+    "\
+####
+#...
+###.
+#...
+#...
+#...",
+    "\
+.##.
+#..#
+#...
+#.##
+#..#
+.###",
+    "\
+#..#
+#..#
+####
+#..#
+#..#
+#..#",
+    "\
+.###
+..#.
+..#.
+..#.
+..#.
+.###",
+    "\
+..##
+...#
+...#
+...#
+#..#
+.##.",
+    "\
+#..#
+#.#.
+##..
+#.#.
+#.#.
+#..#",
+    "\
+#...
+#...
+#...
+#...
+#...
+####",
+    "\
+.##.
+#..#
+#..#
+#..#
+#..#
+.##.",
+    "\
+###.
+#..#
+#..#
+###.
+#...
+#...",
+    "\
+###.
+#..#
+#..#
+###.
+#.#.
+#..#",
+    "\
+.###
+#...
+#...
+.##.
+...#
+###.",
+    "\
+#..#
+#..#
+#..#
+#..#
+#..#
+.##.",
+    "\
+#...#
+#...#
+.#.#.
+..#..
+..#..
+..#..",
+    "\
+####
+...#
+..#.
+.#..
+#...
+####",
+];
+
+/// All letters of the large font.
+const LARGE_LETTERS: &str = "ABCEFGHJKLNPRXZ";
+
+/// The letters of the large font as they appear in puzzle outputs, 6 pixels wide, in the order
+/// of `LARGE_LETTERS`.
+const LARGE_GLYPHS: [&str; 15] = [
+    "\
+..##..
+.#..#.
+#....#
+#....#
+#....#
+######
+#....#
+#....#
+#....#
+#....#",
+    "\
+#####.
+#....#
+#....#
+#....#
+#####.
+#....#
+#....#
+#....#
+#....#
+#####.",
+    "\
+.####.
+#....#
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#....#
+.####.",
+    "\
+######
+#.....
+#.....
+#.....
+#####.
+#.....
+#.....
+#.....
+#.....
+######",
+    "\
+######
+#.....
+#.....
+#.....
+#####.
+#.....
+#.....
+#.....
+#.....
+#.....",
+    "\
+.####.
+#....#
+#.....
+#.....
+#.....
+#..###
+#....#
+#....#
+#...##
+.###.#",
+    "\
+#....#
+#....#
+#....#
+#....#
+######
+#....#
+#....#
+#....#
+#....#
+#....#",
+    "\
+...###
+....#.
+....#.
+....#.
+....#.
+....#.
+....#.
+#...#.
+#...#.
+.###..",
+    "\
+#....#
+#...#.
+#..#..
+#.#...
+##....
+##....
+#.#...
+#..#..
+#...#.
+#....#",
+    "\
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+#.....
+######",
+    "\
+#....#
+##...#
+##...#
+#.#..#
+#.#..#
+#..#.#
+#..#.#
+#...##
+#...##
+#....#",
+    "\
+#####.
+#....#
+#....#
+#....#
+#####.
+#.....
+#.....
+#.....
+#.....
+#.....",
+    "\
+#####.
+#....#
+#....#
+#....#
+#####.
+#..#..
+#...#.
+#...#.
+#....#
+#....#",
+    "\
+#....#
+#....#
+.#..#.
+.#..#.
+..##..
+..##..
+.#..#.
+.#..#.
+#....#
+#....#",
+    "\
+######
+.....#
+.....#
+....#.
+...#..
+..#...
+.#....
+#.....
+#.....
+######",
+];
+
+/// Lay out glyphs next to each other like a puzzle output: every glyph is padded to `cell`
+/// columns and followed by `spacing` blank columns.
+fn layout(glyphs: &[&str], cell: usize, spacing: usize) -> String {
+    let rows: Vec<Vec<&str>> = glyphs.iter().map(|g| g.lines().collect()).collect();
+    (0..rows[0].len())
+        .map(|row| {
+            rows.iter()
+                .map(|glyph| format!("{:.<width$}", glyph[row], width = cell + spacing))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn decode_small_alphabet() {
+    for (letter, glyph) in SMALL_LETTERS.chars().zip(&SMALL_GLYPHS) {
+        assert_eq!(
+            Some(letter.to_string()),
+            ocr::decode(&layout(&[glyph], 5, 0)),
+            "letter {}",
+            letter
+        );
+    }
+    let rendered = layout(&SMALL_GLYPHS, 5, 0);
+    assert_eq!(Some(String::from(SMALL_LETTERS)), ocr::decode(&rendered));
+}
+
+#[test]
+fn decode_large_alphabet() {
+    for (letter, glyph) in LARGE_LETTERS.chars().zip(&LARGE_GLYPHS) {
+        assert_eq!(
+            Some(letter.to_string()),
+            ocr::decode(&layout(&[glyph], 6, 2)),
+            "letter {}",
+            letter
+        );
+    }
+    let rendered = layout(&LARGE_GLYPHS, 6, 2);
+    assert_eq!(Some(String::from(LARGE_LETTERS)), ocr::decode(&rendered));
+}
+
+#[test]
+fn decode_grid() {
+    let rendered = FONT_4X6.render("HI").unwrap();
+    let grid = Grid::parse(&rendered, |c| c == '#').unwrap();
+    assert_eq!(Some(String::from("HI")), ocr::decode_grid(&grid));
+}
+
+#[test]
+fn decode_ignores_surrounding_blanks() {
+    let rendered = FONT_6X10.render("LX").unwrap();
+    let padded: Vec<String> = rendered.lines().map(|l| format!("....{}...", l)).collect();
+    let padded = format!("\n{}\n........\n", padded.join("\n"));
+    assert_eq!(Some(String::from("LX")), ocr::decode(&padded));
+}
+
+#[test]
+fn unknown_glyphs() {
+    assert_eq!(None, FONT_6X10.render("ABQ"));
+    assert_eq!(None, ocr::decode("#.#\n.#.\n#.#"));
+    assert_eq!(None, ocr::decode(""));
+}
@@ -0,0 +1,64 @@
+extern crate aoc_util;
+
+use std::cmp::Reverse;
+
+use aoc_util::optimize::{first_success, random_restart, Rng};
+
+#[test]
+fn deterministic_rng() {
+    let mut a = Rng::new(1234);
+    let mut b = Rng::new(1234);
+    let xs: Vec<u64> = (0..10).map(|_| a.below(100)).collect();
+    let ys: Vec<u64> = (0..10).map(|_| b.below(100)).collect();
+    assert_eq!(xs, ys);
+    assert!(xs.iter().all(|&x| x < 100));
+    assert_ne!(Rng::new(0).next_u64(), Rng::new(1).next_u64());
+}
+
+#[test]
+fn shuffle_is_permutation() {
+    let mut rng = Rng::new(5);
+    let mut items: Vec<u32> = (0..50).collect();
+    rng.shuffle(&mut items);
+    assert_ne!((0..50).collect::<Vec<u32>>(), items);
+    items.sort_unstable();
+    assert_eq!((0..50).collect::<Vec<u32>>(), items);
+}
+
+#[test]
+fn tracks_best_result() {
+    let mut attempts = 0;
+    let results = [Some(5), None, Some(3), Some(4)];
+    let best = random_restart(
+        |_| {
+            attempts += 1;
+            results[attempts - 1]
+        },
+        0,
+        3,
+    );
+    assert_eq!(4, attempts);
+    assert_eq!(Some(3), best);
+
+    let worst = random_restart(|rng| Some(Reverse(rng.below(10))), 0, 100);
+    assert_eq!(Some(Reverse(9)), worst);
+}
+
+#[test]
+fn stops_at_first_success() {
+    let mut attempts = 0;
+    let result = first_success(
+        |_| {
+            attempts += 1;
+            if attempts == 3 {
+                Some(attempts)
+            } else {
+                None
+            }
+        },
+        0,
+        10,
+    );
+    assert_eq!(Some(3), result);
+    assert_eq!(None, first_success(|_| None::<u32>, 0, 10));
+}
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use aoc_util::output::{format_answer, format_duration, timed};
+
+#[test]
+fn durations() {
+    assert_eq!("850ns", format_duration(Duration::from_nanos(850)));
+    assert_eq!("12.3µs", format_duration(Duration::from_nanos(12_345)));
+    assert_eq!("1.50ms", format_duration(Duration::from_micros(1500)));
+    assert_eq!("2.01s", format_duration(Duration::from_millis(2010)));
+    assert_eq!("125.00s", format_duration(Duration::from_secs(125)));
+}
+
+#[test]
+fn answers() {
+    assert_eq!(
+        "Part 1: 42 (3ns)",
+        format_answer(1, 42, Duration::from_nanos(3))
+    );
+    assert_eq!(
+        "Part 2: ABCDEF (1.0µs)",
+        format_answer(2, "ABCDEF", Duration::from_micros(1))
+    );
+}
+
+#[test]
+fn time_closure() {
+    let (result, duration) = timed(|| {
+        std::thread::sleep(Duration::from_millis(5));
+        7
+    });
+    assert_eq!(7, result);
+    assert!(duration >= Duration::from_millis(5));
+}
@@ -0,0 +1,47 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::particles::{closest_long_term, resolve_collisions, tick_with_collisions, Particle};
+
+#[test]
+fn parse_particle() {
+    let particle: Particle = "p=<-317,1413,1507>, v=<19,-102,-125>, a=<1,-3,-3>"
+        .parse()
+        .unwrap();
+    assert_eq!([-317, 1413, 1507], particle.p);
+    assert_eq!([19, -102, -125], particle.v);
+    assert_eq!([1, -3, -3], particle.a);
+    assert!("p=<1,2>, v=<0,0,0>, a=<0,0,0>".parse::<Particle>().is_err());
+    assert!("x=<1,2,3>, v=<0,0,0>, a=<0,0,0>"
+        .parse::<Particle>()
+        .is_err());
+}
+
+#[test]
+fn long_term_closest() {
+    let particles: Vec<Particle> = vec![
+        "p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>".parse().unwrap(),
+        "p=<4,0,0>, v=<0,0,0>, a=<-2,0,0>".parse().unwrap(),
+    ];
+    assert_eq!(Some(0), closest_long_term(&particles));
+
+    // Same acceleration, but the first particle moves against it for a while
+    let particles: Vec<Particle> = vec![
+        "p=<0,0,0>, v=<-10,0,0>, a=<1,0,0>".parse().unwrap(),
+        "p=<5,0,0>, v=<0,0,0>, a=<1,0,0>".parse().unwrap(),
+    ];
+    assert_eq!(Some(0), closest_long_term(&particles));
+    assert_eq!(None, closest_long_term(&[]));
+}
+
+#[test]
+fn collisions() {
+    let mut particles: Vec<Particle> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/particles.txt")
+        .unwrap();
+    assert_eq!(0, tick_with_collisions(&mut particles));
+    assert_eq!(3, tick_with_collisions(&mut particles));
+    assert_eq!(1, particles.len());
+    assert_eq!(1, resolve_collisions(particles, 10).len());
+}
@@ -0,0 +1,44 @@
+extern crate aoc_util;
+
+use aoc_util::strings::{Match, MultiPattern};
+
+#[test]
+fn overlapping_matches() {
+    let scanner = MultiPattern::new(["he", "she", "his", "hers"]);
+    assert_eq!(
+        vec![
+            Match {
+                pattern: 1,
+                start: 1,
+                end: 4
+            },
+            Match {
+                pattern: 0,
+                start: 2,
+                end: 4
+            },
+            Match {
+                pattern: 3,
+                start: 2,
+                end: 6
+            },
+        ],
+        scanner.find_all("ushers")
+    );
+}
+
+#[test]
+fn count_matches() {
+    let scanner = MultiPattern::new(vec![String::from("H"), String::from("HO")]);
+    assert_eq!(2, scanner.pattern_count());
+    assert_eq!(6, scanner.count("HOHOHO"));
+    assert_eq!(vec![3, 2], scanner.count_per_pattern("HOHOH"));
+}
+
+#[test]
+fn forbidden_substrings() {
+    let scanner = MultiPattern::new(["ab", "cd", "pq", "xy"]);
+    assert!(!scanner.is_match("ugknbfddgicrmopn"));
+    assert!(scanner.is_match("haegwjzuvuyypxyu"));
+    assert!(!scanner.is_match(""));
+}
@@ -0,0 +1,69 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc_util::project::Project;
+
+/// Create a workspace with one member under the temporary directory.
+fn workspace(name: &str) -> PathBuf {
+    let root = env::temp_dir().join(format!("aoc-util-{}-{}", name, std::process::id()));
+    fs::create_dir_all(root.join("year2020/src")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"year2020\"]\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("year2020/Cargo.toml"),
+        "[package]\nname = \"year2020\"\n",
+    )
+    .unwrap();
+    root.canonicalize().unwrap()
+}
+
+#[test]
+fn package_root() {
+    let project = Project::from_dir("tests/inputs").unwrap();
+    assert_eq!(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .canonicalize()
+            .unwrap(),
+        project.root()
+    );
+}
+
+#[test]
+fn workspace_root() {
+    let root = workspace("root");
+    let project = Project::from_dir(root.join("year2020/src")).unwrap();
+    assert_eq!(root, project.root());
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn input_paths() {
+    let root = workspace("paths");
+    let project = Project::from_dir(root.join("year2020")).unwrap();
+    assert_eq!(
+        root.join("inputs/2020/day05.txt"),
+        project.clone().inputs_dir("inputs").input_path(2020, 5)
+    );
+    assert_eq!(
+        root.join("data/2021/day12.txt"),
+        project.clone().inputs_dir("data").input_path(2021, 12)
+    );
+    assert_eq!(
+        root.join("data/2021/day12.answers.txt"),
+        project.clone().inputs_dir("data").answers_path(2021, 12)
+    );
+    assert_eq!(
+        Path::new("/srv/aoc/2020/day01.txt"),
+        project.inputs_dir("/srv/aoc").input_path(2020, 1)
+    );
+    fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn missing_project() {
+    assert!(Project::from_dir("/").is_err());
+}
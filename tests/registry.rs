@@ -0,0 +1,111 @@
+use aoc_util::answer::Answer;
+use aoc_util::registry::{Registry, Solver};
+use aoc_util::runner;
+
+struct Sum;
+
+impl Solver for Sum {
+    fn year(&self) -> u32 {
+        2020
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &str {
+        "Sum"
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        input
+            .lines()
+            .map(|line| line.parse::<i128>().unwrap())
+            .sum::<i128>()
+            .into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        format!("{} lines", input.lines().count()).into()
+    }
+}
+
+struct Day(u32, u32);
+
+impl Solver for Day {
+    fn year(&self) -> u32 {
+        self.0
+    }
+
+    fn day(&self) -> u32 {
+        self.1
+    }
+
+    fn name(&self) -> &str {
+        "Day"
+    }
+
+    fn part1(&self, _: &str) -> Answer {
+        self.1.into()
+    }
+
+    fn part2(&self, _: &str) -> Answer {
+        self.0.into()
+    }
+}
+
+aoc_util::register_solvers!(Sum, Day(2019, 25), Day(2020, 3));
+
+#[test]
+fn registered_solvers() {
+    let registry = registry();
+    assert_eq!(3, registry.len());
+    assert_eq!(vec![2019, 2020], registry.years());
+
+    let days: Vec<(u32, u32)> = registry.iter().map(|s| (s.year(), s.day())).collect();
+    assert_eq!(vec![(2019, 25), (2020, 1), (2020, 3)], days);
+    assert_eq!(
+        vec![1, 3],
+        registry.year(2020).map(|s| s.day()).collect::<Vec<_>>()
+    );
+
+    let solver = registry.get(2020, 1).unwrap();
+    assert_eq!("Sum", solver.name());
+    assert_eq!(Answer::Integer(6), solver.part1("1\n2\n3\n"));
+    assert!(registry.get(2020, 2).is_none());
+}
+
+#[test]
+fn duplicate_days() {
+    static FIRST: Day = Day(2020, 3);
+    static SECOND: Day = Day(2020, 3);
+
+    assert!(Registry::from_solvers(vec![&FIRST as &dyn Solver, &SECOND]).is_err());
+
+    let mut merged = Registry::new();
+    merged.register(&FIRST).unwrap();
+    assert!(merged.merge(registry()).is_err());
+
+    let mut merged = Registry::new();
+    merged.register(&Day(2021, 1)).unwrap();
+    merged.merge(registry()).unwrap();
+    assert_eq!(4, merged.len());
+    assert_eq!(vec![2019, 2020, 2021], merged.years());
+}
+
+#[test]
+fn run_registered_solver() {
+    let input =
+        runner::read_input(vec![String::from("tests/inputs/newline_delimited.txt")]).unwrap();
+    let results = runner::run_solver(&input, registry().get(2020, 1).unwrap());
+    assert_eq!(
+        vec!["108", "6 lines"],
+        results
+            .iter()
+            .map(|r| r.answer.as_str())
+            .collect::<Vec<_>>()
+    );
+
+    let bench = runner::bench_solver(&input, &Sum, 3);
+    assert_eq!(vec![1, 2], bench.iter().map(|r| r.part).collect::<Vec<_>>());
+}
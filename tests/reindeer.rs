@@ -0,0 +1,51 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::reindeer::{leader_points, winning_distance, winning_points, Reindeer};
+
+fn reindeer() -> Vec<Reindeer> {
+    FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/reindeer.txt")
+        .unwrap()
+}
+
+#[test]
+fn parse_reindeer() {
+    let reindeer = reindeer();
+    assert_eq!(
+        Reindeer {
+            name: String::from("Dancer"),
+            speed: 16,
+            fly: 11,
+            rest: 162,
+        },
+        reindeer[1]
+    );
+    assert!("Comet can fly fast".parse::<Reindeer>().is_err());
+    assert!(
+        "Comet can fly x km/s for 10 seconds, but then must rest for 127 seconds."
+            .parse::<Reindeer>()
+            .is_err()
+    );
+}
+
+#[test]
+fn distances() {
+    let reindeer = reindeer();
+    assert_eq!(14, reindeer[0].distance(1));
+    assert_eq!(140, reindeer[0].distance(10));
+    assert_eq!(140, reindeer[0].distance(137));
+    assert_eq!(154, reindeer[0].distance(138));
+    assert_eq!(1056, reindeer[1].distance(1000));
+    assert_eq!(Some(1120), winning_distance(&reindeer, 1000));
+}
+
+#[test]
+fn points() {
+    let reindeer = reindeer();
+    assert_eq!(vec![0, 1], leader_points(&reindeer, 1));
+    assert_eq!(vec![312, 689], leader_points(&reindeer, 1000));
+    assert_eq!(Some(689), winning_points(&reindeer, 1000));
+    assert_eq!(None, winning_points(&[], 1000));
+}
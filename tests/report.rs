@@ -0,0 +1,133 @@
+use aoc_util::answer::Answer;
+use aoc_util::project::Project;
+use aoc_util::registry::Solver;
+use aoc_util::runner::{Report, Verdict};
+
+/// Sums the lines, and counts them (off by one) in part 2.
+struct Sum;
+
+impl Solver for Sum {
+    fn year(&self) -> u32 {
+        2020
+    }
+
+    fn day(&self) -> u32 {
+        1
+    }
+
+    fn name(&self) -> &str {
+        "Sum \"quoted\""
+    }
+
+    fn part1(&self, input: &str) -> Answer {
+        input
+            .lines()
+            .map(|line| line.parse::<i128>().unwrap())
+            .sum::<i128>()
+            .into()
+    }
+
+    fn part2(&self, input: &str) -> Answer {
+        (input.lines().count() + 2).into()
+    }
+}
+
+struct Missing;
+
+impl Solver for Missing {
+    fn year(&self) -> u32 {
+        2020
+    }
+
+    fn day(&self) -> u32 {
+        2
+    }
+
+    fn name(&self) -> &str {
+        "Missing"
+    }
+
+    fn part1(&self, _: &str) -> Answer {
+        unreachable!()
+    }
+
+    fn part2(&self, _: &str) -> Answer {
+        unreachable!()
+    }
+}
+
+aoc_util::register_solvers!(Sum, Missing);
+
+fn report() -> Report {
+    let project = Project::from_dir("tests/inputs")
+        .unwrap()
+        .inputs_dir("tests/inputs/report");
+    Report::for_project(registry().iter(), &project)
+}
+
+#[test]
+fn verdicts_and_stars() {
+    let report = report();
+    assert_eq!(2, report.days.len());
+
+    let day1 = &report.days[0];
+    let verdicts: Vec<Verdict> = day1.parts.iter().map(|p| p.verdict).collect();
+    assert_eq!(vec![Verdict::Correct, Verdict::Wrong], verdicts);
+    assert_eq!(Answer::Integer(6), day1.parts[0].answer);
+    assert_eq!(1, day1.stars());
+    assert!(report.days[1].parts.is_empty());
+
+    assert_eq!(1, report.stars());
+    assert!(report.has_wrong_answers());
+}
+
+#[test]
+fn unknown_answers() {
+    let report = Report::generate(
+        registry().iter(),
+        |_, day| (day == 1).then(|| String::from("4\n5\n")),
+        |_, _| vec![String::from("9")],
+    );
+    let verdicts: Vec<Verdict> = report.days[0].parts.iter().map(|p| p.verdict).collect();
+    assert_eq!(vec![Verdict::Correct, Verdict::Unknown], verdicts);
+    assert!(!report.has_wrong_answers());
+}
+
+#[test]
+fn render_table() {
+    let table = report().render();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(6, lines.len());
+    assert_eq!(
+        vec!["Year", "Day", "Name", "Part", "1", "Part", "2", "Stars"],
+        lines[0].split_whitespace().collect::<Vec<_>>()
+    );
+    assert!(lines[1].chars().all(|c| c == '-'));
+    assert!(lines[2].starts_with("2020") && lines[2].contains("Sum \"quoted\""));
+    assert!(lines[2].contains('✓') && lines[2].contains('✗'));
+    assert!(lines[2].ends_with("  *"));
+    assert!(lines[3].contains("no input"));
+    assert!(lines[5].starts_with("Total") && lines[5].ends_with("  1"));
+}
+
+#[test]
+fn export_markdown() {
+    let markdown = report().to_markdown();
+    let lines: Vec<&str> = markdown.lines().collect();
+    assert_eq!(5, lines.len());
+    assert_eq!("| Year | Day | Name | Part 1 | Part 2 | Stars |", lines[0]);
+    assert_eq!("| --- | --- | --- | --- | --- | --- |", lines[1]);
+    assert!(lines[3].starts_with("| 2020 | 2 | Missing | no input | no input |"));
+}
+
+#[test]
+fn export_json() {
+    let json = report().to_json();
+    assert!(json.starts_with("{\"stars\":1,\"duration_ns\":"));
+    assert!(json.contains("\"name\":\"Sum \\\"quoted\\\"\",\"stars\":1,\"parts\":[{\"part\":1,"));
+    assert!(json.contains("\"answer\":\"6\",\"duration_ns\":"));
+    assert!(json.contains("\"answer\":\"5\""));
+    assert!(json.contains("\"correct\":true}"));
+    assert!(json.contains("\"correct\":false}"));
+    assert!(json.ends_with("\"name\":\"Missing\",\"stars\":0,\"parts\":[]}]}"));
+}
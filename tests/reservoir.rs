@@ -0,0 +1,57 @@
+use aoc_util::geometry::Point2;
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::reservoir::{Reservoir, Tile};
+
+const SPRING: Point2<i64> = Point2::new(500, 0);
+
+fn fill(input: &str) -> Reservoir {
+    let mut reservoir: Reservoir = input.parse().unwrap();
+    reservoir.fill(SPRING);
+    reservoir
+}
+
+#[test]
+fn example_reservoir() {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/reservoir.txt")
+        .unwrap();
+    let reservoir = fill(&input);
+    assert_eq!(57, reservoir.reached());
+    assert_eq!(29, reservoir.retained());
+    assert_eq!(
+        Some(&Tile::Settled),
+        reservoir.tiles().get(Point2::new(500, 6))
+    );
+    assert_eq!(
+        Some(&Tile::Flowing),
+        reservoir.tiles().get(Point2::new(500, 2))
+    );
+}
+
+#[test]
+fn tiles_above_clay_not_counted() {
+    // Single bucket, spring far above.
+    let reservoir = fill("x=498, y=10..12\nx=502, y=10..12\ny=12, x=499..501");
+    assert_eq!(6, reservoir.retained());
+    // The overflowing row above the bucket is above the topmost clay, only the two columns
+    // falling down both sides count.
+    assert_eq!(6 + 2 * 3, reservoir.reached());
+}
+
+#[test]
+fn streams_merge_in_wide_basin() {
+    // A small bucket inside a wide basin: water overflows the bucket on both sides and fills
+    // the basin around and above it.
+    let input = "x=490, y=5..12\nx=510, y=5..12\ny=12, x=490..510\n\
+                 x=499, y=2..3\nx=501, y=2..3\ny=3, x=499..501";
+    let reservoir = fill(input);
+    // The basin fills up to its brim (rows 5..=11, 19 tiles wide), the bucket holds one tile.
+    assert_eq!(7 * 19 + 1, reservoir.retained());
+}
+
+#[test]
+fn invalid_vein() {
+    assert!("x=1, z=2..3".parse::<Reservoir>().is_err());
+    assert!("x=1 y=2..3".parse::<Reservoir>().is_err());
+    assert!("x=1, y=2-3".parse::<Reservoir>().is_err());
+}
@@ -0,0 +1,70 @@
+use aoc_util::runner::{self, bench, bench_json, read_input, solver, Options, DEFAULT_BENCH_RUNS};
+
+fn part1(input: &str) -> u32 {
+    input.lines().map(|line| line.parse::<u32>().unwrap()).sum()
+}
+
+fn part2(input: &str) -> String {
+    format!("{} lines", input.lines().count())
+}
+
+#[test]
+fn run_parts() {
+    let input = read_input(vec![String::from("tests/inputs/newline_delimited.txt")]).unwrap();
+    let results = runner::run(&input, &[&solver(part1), &solver(part2)]);
+
+    assert_eq!(2, results.len());
+    assert_eq!((1, "108"), (results[0].part, results[0].answer.as_str()));
+    assert_eq!(
+        (2, "6 lines"),
+        (results[1].part, results[1].answer.as_str())
+    );
+}
+
+#[test]
+fn missing_input_file() {
+    assert!(read_input(vec![String::from("tests/inputs/does_not_exist.txt")]).is_err());
+}
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn parse_options() {
+    assert_eq!(Options::default(), Options::parse(args(&[])).unwrap());
+    assert_eq!(
+        Options {
+            input: Some(String::from("day01.txt")),
+            bench: Some(DEFAULT_BENCH_RUNS),
+            json: false,
+        },
+        Options::parse(args(&["--bench", "day01.txt"])).unwrap()
+    );
+    assert_eq!(
+        Options {
+            input: Some(String::from("-")),
+            bench: Some(20),
+            json: true,
+        },
+        Options::parse(args(&["--json", "--bench", "20", "-"])).unwrap()
+    );
+    assert!(Options::parse(args(&["--fast"])).is_err());
+    assert!(Options::parse(args(&["--bench", "0"])).is_err());
+    assert!(Options::parse(args(&["a.txt", "b.txt"])).is_err());
+}
+
+#[test]
+fn bench_parts() {
+    let results = bench("1\n2\n3", &[&solver(part1), &solver(part2)], 11);
+    assert_eq!(2, results.len());
+    for (r, part) in results.iter().zip(1..) {
+        assert_eq!(part, r.part);
+        assert_eq!(11, r.runs);
+        assert!(r.min <= r.median);
+    }
+
+    let json = bench_json(&results[..1]);
+    assert!(json.starts_with("[{\"part\":1,\"runs\":11,\"min_ns\":"));
+    assert!(json.ends_with("}]"));
+}
@@ -0,0 +1,177 @@
+use aoc_util::grid::Grid;
+use aoc_util::search::{astar, bfs, dijkstra, explore, topological_sort};
+
+#[test]
+fn shortest_path_on_grid() {
+    let grid = Grid::parse("S.#.\n.##.\n...E", |c| c != '#').unwrap();
+    let (path, cost) = bfs(
+        (0, 0),
+        |&(r, c)| {
+            grid.neighbors4(r, c)
+                .filter(|&(nr, nc)| grid[(nr, nc)])
+                .collect::<Vec<_>>()
+        },
+        |&pos| pos == (2, 3),
+    )
+    .unwrap();
+    assert_eq!(5, cost);
+    assert_eq!((0, 0), path[0]);
+    assert_eq!((2, 3), *path.last().unwrap());
+    assert_eq!(6, path.len());
+}
+
+#[test]
+fn start_is_goal() {
+    assert_eq!(Some((vec![7], 0)), bfs(7, |&n| vec![n + 1], |&n| n == 7));
+}
+
+#[test]
+fn unreachable_goal() {
+    assert_eq!(None, bfs(0u8, |&n| (n < 5).then(|| n + 1), |&n| n == 10));
+}
+
+fn weighted_grid() -> Grid<u32> {
+    Grid::parse("1163751\n1381373\n2136511\n3694931\n7463417", |c| {
+        c.to_digit(10).unwrap()
+    })
+    .unwrap()
+}
+
+#[test]
+fn cheapest_path_on_grid() {
+    let grid = weighted_grid();
+    let target = (grid.height() - 1, grid.width() - 1);
+    let successors = |&(r, c): &(usize, usize)| {
+        grid.neighbors4(r, c)
+            .map(|pos| (pos, grid[pos]))
+            .collect::<Vec<_>>()
+    };
+
+    let (path, cost) = dijkstra((0, 0), successors, |&pos| pos == target).unwrap();
+    let (astar_path, astar_cost) = astar(
+        (0, 0),
+        successors,
+        |&(r, c)| ((target.0 - r) + (target.1 - c)) as u32,
+        |&pos| pos == target,
+    )
+    .unwrap();
+
+    assert_eq!(cost, astar_cost);
+    assert_eq!(cost, path.iter().skip(1).map(|&pos| grid[pos]).sum::<u32>());
+    assert_eq!(
+        astar_cost,
+        astar_path.iter().skip(1).map(|&pos| grid[pos]).sum::<u32>()
+    );
+    assert_eq!(Some(&target), path.last());
+}
+
+#[test]
+fn cheaper_detour() {
+    // Direct edge 0 -> 3 costs 10, the detour over 1 and 2 only 3.
+    let edges = |&n: &u8| match n {
+        0 => vec![(3, 10), (1, 1)],
+        1 => vec![(2, 1)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+    assert_eq!(Some((vec![0, 1, 2, 3], 3)), dijkstra(0, edges, |&n| n == 3));
+    assert_eq!(None, dijkstra(3, edges, |&n| n == 0));
+}
+
+#[test]
+fn order_by_dependencies() {
+    let rules = [
+        ('C', 'A'),
+        ('C', 'F'),
+        ('A', 'B'),
+        ('A', 'D'),
+        ('B', 'E'),
+        ('D', 'E'),
+        ('F', 'E'),
+    ];
+    let order = topological_sort(Some('E'), |&step| {
+        rules
+            .iter()
+            .filter(|&&(_, after)| after == step)
+            .map(|&(before, _)| before)
+            .collect::<Vec<_>>()
+    })
+    .unwrap();
+    assert_eq!("CABDFE", order.into_iter().collect::<String>());
+}
+
+#[test]
+fn detect_cycle() {
+    // 1 -> 2 -> 3 -> 4 -> 2, where `a -> b` means a depends on b.
+    let deps = |&n: &u32| match n {
+        1 => vec![2],
+        2 => vec![3],
+        3 => vec![4],
+        4 => vec![2],
+        _ => vec![],
+    };
+    let cycle = topological_sort(vec![1, 5], deps).unwrap_err();
+    assert_eq!(vec![2, 3, 4], cycle.nodes);
+
+    assert_eq!(
+        Ok(vec![7, 6]),
+        topological_sort(vec![6], |&n: &u32| if n == 6 { vec![7, 7] } else { vec![] })
+    );
+}
+
+/// States `(minute, robots, ore)` of robots collecting one ore each per minute, where 2 ore
+/// buy another robot at the start of a minute.
+fn ore_after<D>(minutes: u32, dominates: D) -> Vec<(u32, u32, u32)>
+where
+    D: Fn(&(u32, u32, u32), &(u32, u32, u32)) -> bool,
+{
+    explore(
+        (0, 1, 0),
+        |&(minute, _, _)| minute,
+        |&(minute, robots, ore)| {
+            let mut next = Vec::new();
+            if minute < minutes {
+                next.push((minute + 1, robots, ore + robots));
+                if ore >= 2 {
+                    next.push((minute + 1, robots + 1, ore - 2 + robots));
+                }
+            }
+            next
+        },
+        dominates,
+    )
+}
+
+fn most_ore(states: &[(u32, u32, u32)], minute: u32) -> Option<u32> {
+    states
+        .iter()
+        .filter(|state| state.0 == minute)
+        .map(|state| state.2)
+        .max()
+}
+
+#[test]
+fn explore_with_dominance() {
+    let dominates = |a: &(u32, u32, u32), b: &(u32, u32, u32)| a.1 >= b.1 && a.2 >= b.2;
+    let states = ore_after(8, dominates);
+    let all_states = ore_after(8, |a, b| a == b);
+    assert!(states.len() < all_states.len());
+    assert_eq!(most_ore(&all_states, 8), most_ore(&states, 8));
+    assert_eq!(Some(7), most_ore(&states, 6));
+
+    // No surviving state is dominated by another one of the same minute.
+    for a in &states {
+        for b in &states {
+            if a != b && a.0 == b.0 {
+                assert!(!dominates(a, b), "{:?} dominates {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn explore_with_equality_visits_all() {
+    let mut states = explore(0u8, |&n| n, |&n| vec![n / 2, (n + 3) % 10], |a, b| a == b);
+    states.sort_unstable();
+    assert_eq!((0..10).collect::<Vec<_>>(), states);
+}
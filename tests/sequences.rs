@@ -0,0 +1,136 @@
+extern crate aoc_util;
+
+use aoc_util::assert_solves;
+use aoc_util::optimize::Rng;
+use aoc_util::sequences::{
+    first_repeated_cumulative_sum, first_repeated_cumulative_sum_modular, iterate,
+    iterate_checkpoints, iterate_until, look_and_say, van_eck, Scoreboard,
+};
+
+#[test]
+fn look_and_say_terms() {
+    assert_solves!(look_and_say, "1", "11");
+    assert_solves!(look_and_say, "11", "21");
+    assert_solves!(look_and_say, "21", "1211");
+    assert_solves!(look_and_say, "1211", "111221");
+    assert_solves!(look_and_say, "111221", "312211");
+}
+
+#[test]
+fn iterate_n_times() {
+    assert_eq!(1024, iterate(1u64, |x| x * 2, 10));
+    assert_eq!(7, iterate(7, |x| x + 1, 0));
+}
+
+#[test]
+fn iterate_until_predicate() {
+    assert_eq!((4, 16), iterate_until(1, |x| x * 2, |&x| x > 10));
+    assert_eq!((0, 20), iterate_until(20, |x| x * 2, |&x| x > 10));
+}
+
+#[test]
+fn checkpoints_in_any_order() {
+    let lengths = iterate_checkpoints(
+        String::from("1"),
+        |s| look_and_say(s),
+        &[5, 0, 3],
+        |s| s.len(),
+    );
+    assert_eq!(vec![6, 1, 4], lengths);
+}
+
+#[test]
+fn memory_game() {
+    assert_eq!(0, van_eck(&[0, 3, 6], 1));
+    assert_eq!(6, van_eck(&[0, 3, 6], 3));
+    assert_eq!(0, van_eck(&[0, 3, 6], 4));
+    assert_eq!(3, van_eck(&[0, 3, 6], 5));
+    assert_eq!(3, van_eck(&[0, 3, 6], 6));
+    assert_eq!(0, van_eck(&[0, 3, 6], 10));
+    assert_eq!(436, van_eck(&[0, 3, 6], 2020));
+    assert_eq!(1836, van_eck(&[3, 1, 2], 2020));
+}
+
+#[test]
+fn memory_game_thirty_million() {
+    assert_eq!(175_594, van_eck(&[0, 3, 6], 30_000_000));
+}
+
+#[test]
+fn scoreboard_scores_after() {
+    let mut board = Scoreboard::new();
+    for _ in 0..15 {
+        board.step();
+    }
+    assert_eq!(&[3, 7, 1, 0, 1, 0, 1, 2, 4, 5][..], &board.scores()[..10]);
+
+    assert_eq!(
+        &[5, 1, 5, 8, 9, 1, 6, 7, 7, 9][..],
+        Scoreboard::new().scores_after(9, 10)
+    );
+    assert_eq!(
+        &[0, 1, 2, 4, 5, 1, 5, 8, 9, 1][..],
+        Scoreboard::new().scores_after(5, 10)
+    );
+    assert_eq!(
+        &[9, 2, 5, 1, 0, 7, 1, 0, 8, 5][..],
+        Scoreboard::new().scores_after(18, 10)
+    );
+    assert_eq!(
+        &[5, 9, 4, 1, 4, 2, 9, 8, 8, 2][..],
+        Scoreboard::new().scores_after(2018, 10)
+    );
+}
+
+#[test]
+fn scoreboard_find_pattern() {
+    assert_eq!(9, Scoreboard::new().find(&[5, 1, 5, 8, 9]));
+    assert_eq!(5, Scoreboard::new().find(&[0, 1, 2, 4, 5]));
+    assert_eq!(18, Scoreboard::new().find(&[9, 2, 5, 1, 0]));
+    assert_eq!(2018, Scoreboard::new().find(&[5, 9, 4, 1, 4]));
+    assert_eq!(0, Scoreboard::new().find(&[3, 7]));
+    assert_eq!(1, Scoreboard::new().find(&[7, 1]));
+    assert_eq!(0, Scoreboard::new().find(&[]));
+}
+
+#[test]
+fn repeated_cumulative_sums() {
+    let examples: [(&[i64], i64); 5] = [
+        (&[1, -2, 3, 1], 2),
+        (&[1, -1], 0),
+        (&[3, 3, 4, -2, -4], 10),
+        (&[-6, 3, 8, 5, -6], 5),
+        (&[7, 7, -2, -7, -4], 14),
+    ];
+    for &(deltas, expected) in examples.iter() {
+        assert_eq!(Some(expected), first_repeated_cumulative_sum(deltas));
+        assert_eq!(
+            Some(expected),
+            first_repeated_cumulative_sum_modular(deltas)
+        );
+    }
+
+    assert_eq!(None, first_repeated_cumulative_sum(&[]));
+    assert_eq!(None, first_repeated_cumulative_sum_modular(&[]));
+    assert_eq!(None, first_repeated_cumulative_sum_modular(&[1, 1, 1]));
+    assert_eq!(Some(0), first_repeated_cumulative_sum_modular(&[3, -3, 5]));
+}
+
+#[test]
+fn repeated_cumulative_sums_agree() {
+    let mut rng = Rng::new(3);
+    for _ in 0..200 {
+        let len = 1 + rng.below(12) as usize;
+        let mut deltas: Vec<i64> = (0..len).map(|_| rng.below(41) as i64 - 20).collect();
+        // Keep the drift small but non-zero, so that a repeat exists and is found quickly.
+        let drift: i64 = deltas.iter().sum();
+        deltas.push(-drift + if rng.chance(0.5) { 1 } else { -1 });
+
+        assert_eq!(
+            first_repeated_cumulative_sum(&deltas),
+            first_repeated_cumulative_sum_modular(&deltas),
+            "{:?}",
+            deltas
+        );
+    }
+}
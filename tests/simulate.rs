@@ -0,0 +1,95 @@
+use aoc_util::simulate::EventQueue;
+
+struct Reindeer {
+    speed: u64,
+    fly: u64,
+    rest: u64,
+}
+
+enum Event {
+    StartFlying(usize),
+    StartResting(usize),
+}
+
+/// Distance traveled by every reindeer after `seconds`, processing only the changes between
+/// flying and resting.
+fn race(reindeer: &[Reindeer], seconds: u64) -> Vec<u64> {
+    let mut distances = vec![0; reindeer.len()];
+    let mut flying_since = vec![None; reindeer.len()];
+    let mut queue = EventQueue::new();
+    for i in 0..reindeer.len() {
+        queue.schedule(0, Event::StartFlying(i));
+    }
+
+    while let Some((time, event)) = queue.pop_until(seconds) {
+        match event {
+            Event::StartFlying(i) => {
+                flying_since[i] = Some(time);
+                queue.schedule_in(reindeer[i].fly, Event::StartResting(i));
+            }
+            Event::StartResting(i) => {
+                distances[i] += reindeer[i].speed * (time - flying_since[i].take().unwrap());
+                queue.schedule_in(reindeer[i].rest, Event::StartFlying(i));
+            }
+        }
+    }
+    for (i, since) in flying_since.iter().enumerate() {
+        if let Some(since) = since {
+            distances[i] += reindeer[i].speed * (queue.now() - since);
+        }
+    }
+
+    distances
+}
+
+#[test]
+fn reindeer_race() {
+    let reindeer = [
+        Reindeer {
+            speed: 14,
+            fly: 10,
+            rest: 127,
+        },
+        Reindeer {
+            speed: 16,
+            fly: 11,
+            rest: 162,
+        },
+    ];
+    assert_eq!(vec![1120, 1056], race(&reindeer, 1000));
+    assert_eq!(vec![140, 160], race(&reindeer, 10));
+}
+
+#[test]
+fn order_and_cancellation() {
+    let mut queue = EventQueue::new();
+    let a = queue.schedule(5, 'a');
+    queue.schedule(5, 'b');
+    queue.schedule(2, 'c');
+    let d = queue.schedule(1, 'd');
+    assert_eq!(4, queue.len());
+
+    assert_eq!(Some('d'), queue.cancel(d));
+    assert_eq!(None, queue.cancel(d));
+    assert_eq!(Some(2), queue.peek_time());
+    assert_eq!(Some((2, 'c')), queue.pop());
+
+    queue.schedule_in(3, 'e');
+    assert_eq!(None, queue.pop_until(4));
+    assert_eq!(4, queue.now());
+    assert_eq!(Some((5, 'a')), queue.pop());
+    assert_eq!(None, queue.cancel(a));
+    assert_eq!(Some((5, 'b')), queue.pop());
+    assert_eq!(Some((5, 'e')), queue.pop());
+    assert!(queue.is_empty());
+    assert_eq!(None, queue.pop());
+}
+
+#[test]
+#[should_panic(expected = "in the past")]
+fn schedule_in_the_past() {
+    let mut queue = EventQueue::new();
+    queue.schedule(3, ());
+    queue.pop();
+    queue.schedule(2, ());
+}
@@ -0,0 +1,96 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::slices::{
+    contiguous_range_with_sum, count_chains, find_k_with_sum, find_pair_with_sum,
+    first_not_sum_of_window, gap_counts,
+};
+
+fn expense_report() -> Vec<u32> {
+    FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/expense_report.txt")
+        .unwrap()
+}
+
+#[test]
+fn pair_with_sum() {
+    let entries = expense_report();
+    assert_eq!(Some((299, 1721)), find_pair_with_sum(&entries, 2020));
+    assert_eq!(None, find_pair_with_sum(&entries, 1));
+    // An entry may not be used twice
+    assert_eq!(None, find_pair_with_sum(&[1010], 2020));
+    assert_eq!(
+        Some((1010, 1010)),
+        find_pair_with_sum(&[1010, 5, 1010], 2020)
+    );
+}
+
+#[test]
+fn k_with_sum() {
+    let entries = expense_report();
+    assert_eq!(
+        Some(vec![366, 675, 979]),
+        find_k_with_sum(&entries, 3, 2020)
+    );
+    assert_eq!(Some(vec![299, 1721]), find_k_with_sum(&entries, 2, 2020));
+    assert_eq!(Some(vec![979]), find_k_with_sum(&entries, 1, 979));
+    assert_eq!(None, find_k_with_sum(&entries, 0, 0));
+    assert_eq!(None, find_k_with_sum(&entries, 7, 2020));
+}
+
+#[test]
+fn signed_values() {
+    assert_eq!(
+        Some(vec![-5, -1, 2, 4]),
+        find_k_with_sum(&[4, -1, 9, -5, 2], 4, 0)
+    );
+}
+
+#[test]
+fn not_sum_of_window() {
+    let numbers = [
+        35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576,
+    ];
+    assert_eq!(Some(127), first_not_sum_of_window(&numbers, 5));
+    assert_eq!(None, first_not_sum_of_window(&[1, 2, 3, 5, 8], 2));
+    // The two summands must have different values
+    assert_eq!(Some(4), first_not_sum_of_window(&[2, 2, 4], 2));
+}
+
+#[test]
+fn range_with_sum() {
+    let numbers = [
+        35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576,
+    ];
+    assert_eq!(
+        Some(&[15, 25, 47, 40][..]),
+        contiguous_range_with_sum(&numbers, 127)
+    );
+    assert_eq!(None, contiguous_range_with_sum(&numbers, 36));
+    assert_eq!(None, contiguous_range_with_sum(&[127], 127));
+}
+
+#[test]
+fn adapter_chains() {
+    let mut adapters: Vec<u64> = FileReader::new()
+        .split_lines()
+        .read_from_file("tests/inputs/adapters.txt")
+        .unwrap();
+    let device = adapters.iter().max().unwrap() + 3;
+    adapters.push(0);
+    adapters.push(device);
+
+    let gaps = gap_counts(&adapters);
+    assert_eq!(Some(&22), gaps.get(&1));
+    assert_eq!(Some(&10), gaps.get(&3));
+    assert_eq!(19208, count_chains(&adapters, 3));
+}
+
+#[test]
+fn chains_with_other_gaps() {
+    assert_eq!(1, count_chains(&[0, 1, 2, 3], 1));
+    assert_eq!(3, count_chains(&[0, 1, 2, 3], 2));
+    assert_eq!(0, count_chains(&[0, 5], 3));
+    assert_eq!(0, count_chains::<u32>(&[], 3));
+}
@@ -0,0 +1,70 @@
+use aoc_util::geometry::Point2;
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::ocr::FONT_6X10;
+use aoc_util::stars::{self, render, Star};
+
+const HI: &str = "\
+#...#..###
+#...#...#.
+#...#...#.
+#####...#.
+#...#...#.
+#...#...#.
+#...#...#.
+#...#..###";
+
+fn example() -> Vec<Star> {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/stars.txt")
+        .unwrap();
+    input.lines().map(|l| l.parse().unwrap()).collect()
+}
+
+#[test]
+fn parse_star() {
+    assert_eq!(
+        Ok(Star {
+            position: Point2::new(-6, 10),
+            velocity: Point2::new(2, -2)
+        }),
+        "position=<-6, 10> velocity=< 2, -2>".parse()
+    );
+    assert!("position=<-6, 10>".parse::<Star>().is_err());
+}
+
+#[test]
+fn stars_align() {
+    let stars = example();
+    let (time, points) = stars::converge(&stars);
+    assert_eq!(3, time);
+    assert_eq!(HI, render(&points));
+    assert_eq!(render(&points), render(&stars::positions_at(&stars, 3)));
+    // The example uses a smaller font than the puzzle inputs.
+    assert_eq!(None, stars::message(&stars));
+}
+
+#[test]
+fn stars_spell_message() {
+    let rendered = FONT_6X10.render("HAX").unwrap();
+    let stars: Vec<Star> = rendered
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| Point2::new(x as i64, y as i64))
+        })
+        .enumerate()
+        .map(|(i, p)| {
+            let i = i as i64;
+            let velocity = Point2::new(i % 5 - 2, i % 3 - 1);
+            Star {
+                position: p + velocity * -50,
+                velocity,
+            }
+        })
+        .collect();
+
+    assert_eq!(Some((50, String::from("HAX"))), stars::message(&stars));
+}
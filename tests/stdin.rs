@@ -0,0 +1,115 @@
+//! Reading from stdin is tested by running this test binary again as a child process, with the
+//! input piped into it. The child tests only do something if `CHILD_ENV` is set.
+
+extern crate aoc_util;
+
+use std::env;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use aoc_util::input::{FileReader, FromFileOrStdin, FromStdin};
+
+const CHILD_ENV: &str = "AOC_UTIL_STDIN_CHILD";
+
+fn is_child() -> bool {
+    env::var_os(CHILD_ENV).is_some()
+}
+
+/// Run the test `name` in a child process with `input` on stdin and check that it passed.
+fn run_child(name: &str, input: &str) {
+    let mut child = Command::new(env::current_exe().unwrap())
+        .args(["--exact", name, "--test-threads=1"])
+        .env(CHILD_ENV, "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "child failed:\n{}", stdout);
+    assert!(stdout.contains("1 passed"), "child didn't run:\n{}", stdout);
+}
+
+#[test]
+fn child_string() {
+    if is_child() {
+        let input: String = FileReader::new().trim().read_from_stdin().unwrap();
+        assert_eq!("This is a string.", input);
+    }
+}
+
+#[test]
+fn child_lines() {
+    if is_child() {
+        let input: Vec<u32> = FileReader::new().split_lines().read_from_stdin().unwrap();
+        assert_eq!(vec![4, 8, 15, 16, 23, 42], input);
+    }
+}
+
+#[test]
+fn child_whitespace() {
+    if is_child() {
+        let input: Vec<u32> = FileReader::new()
+            .split_whitespace()
+            .read_from_stdin()
+            .unwrap();
+        assert_eq!(vec![4, 8, 15, 16, 23, 42], input);
+    }
+}
+
+#[test]
+fn child_char() {
+    if is_child() {
+        let input: Vec<u32> = FileReader::new()
+            .split_char(',')
+            .trim()
+            .read_from_stdin()
+            .unwrap();
+        assert_eq!(vec![4, 8, 15, 16, 23, 42], input);
+    }
+}
+
+#[test]
+fn child_parse_error() {
+    if is_child() {
+        let input: Result<Vec<u32>, _> = FileReader::new().split_lines().read_from_stdin();
+        assert!(input.is_err());
+    }
+}
+
+#[test]
+fn child_file_or_stdin() {
+    if is_child() {
+        let input: Vec<u32> = FileReader::new()
+            .split_lines()
+            .read_from_file_or_stdin(None::<&str>)
+            .unwrap();
+        assert_eq!(vec![1, 2, 3], input);
+
+        let input: Vec<u32> = FileReader::new()
+            .split_lines()
+            .read_from_file_or_stdin(Some("tests/inputs/newline_delimited.txt"))
+            .unwrap();
+        assert_eq!(vec![4, 8, 15, 16, 23, 42], input);
+    }
+}
+
+#[test]
+fn read_from_stdin() {
+    if is_child() {
+        return;
+    }
+    run_child("child_string", "  This is a string.\n");
+    run_child("child_lines", "4\n8\n15\n16\n23\n42\n");
+    run_child("child_whitespace", "4 8\t15\n16  23 42");
+    run_child("child_char", "4, 8, 15, 16, 23, 42\n");
+    run_child("child_parse_error", "4\neight\n");
+    run_child("child_file_or_stdin", "1\n2\n3\n");
+}
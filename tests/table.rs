@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use aoc_util::collections::HashTable;
+use aoc_util::testing::Rng;
+
+#[test]
+fn insert_get_remove() {
+    let mut table = HashTable::new();
+    assert!(table.is_empty());
+    assert_eq!(None, table.insert("a", 1));
+    assert_eq!(Some(1), table.insert("a", 2));
+    assert_eq!(None, table.insert("b", 3));
+
+    assert_eq!(2, table.len());
+    assert_eq!(Some(&2), table.get("a"));
+    assert_eq!(3, table["b"]);
+    assert!(!table.contains_key("c"));
+
+    *table.get_mut("b").unwrap() += 1;
+    *table.get_or_insert_with("c", || 10) += 1;
+    assert_eq!(Some(4), table.remove("b"));
+    assert_eq!(None, table.remove("b"));
+
+    let mut entries: Vec<_> = table.into_iter().collect();
+    entries.sort_unstable();
+    assert_eq!(vec![("a", 2), ("c", 11)], entries);
+}
+
+#[test]
+fn behaves_like_hash_map() {
+    let mut rng = Rng::new(7);
+    let mut table = HashTable::new();
+    let mut map = HashMap::new();
+
+    for _ in 0..20_000 {
+        // Few distinct keys, so that removals often hit existing entries in long probe runs
+        let key = rng.int_in(0..=500);
+        if rng.chance(0.4) {
+            assert_eq!(map.remove(&key), table.remove(&key), "remove {}", key);
+        } else {
+            let value = rng.next_u64();
+            assert_eq!(
+                map.insert(key, value),
+                table.insert(key, value),
+                "insert {}",
+                key
+            );
+        }
+        assert_eq!(map.len(), table.len());
+    }
+
+    for (key, value) in &map {
+        assert_eq!(Some(value), table.get(key));
+    }
+    assert_eq!(map.len(), table.iter().count());
+    assert_eq!(table, map.into_iter().collect::<HashTable<_, _>>());
+}
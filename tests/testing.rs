@@ -0,0 +1,160 @@
+use aoc_util::grid::Grid;
+use aoc_util::input::{FileReader, FromReader};
+use aoc_util::testing::{
+    assert_roundtrip, assert_roundtrip_with, fuzz, random_brackets, random_grid, random_ints,
+    random_nested_list, Rng,
+};
+use aoc_util::{aoc_test, assert_solves, assert_solves_file};
+
+fn sum_lines(input: &str) -> i32 {
+    input.lines().map(|line| line.parse::<i32>().unwrap()).sum()
+}
+
+#[test]
+fn solves_inline_input() {
+    assert_solves!(sum_lines, "1\n2\n3", 6);
+    assert_solves!(|input: &str| input.trim().len(), " abc \n", 3);
+}
+
+#[test]
+fn solves_file_input() {
+    assert_solves_file!(sum_lines, "inputs/newline_delimited.txt", 108);
+}
+
+#[test]
+#[should_panic(expected = "wrong answer")]
+fn wrong_answer() {
+    assert_solves!(sum_lines, "1\n2", 4);
+}
+
+aoc_test!(sum_lines, "tests/inputs/newline_delimited.txt", 108);
+aoc_test!(
+    count_words: |input: &str| input.split_whitespace().count(),
+    "tests/inputs/whitespace_delimited.txt",
+    6
+);
+
+#[test]
+fn reproducible_rng() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+    let values: Vec<u64> = (0..5).map(|_| a.next_u64()).collect();
+    assert_eq!(values, (0..5).map(|_| b.next_u64()).collect::<Vec<_>>());
+
+    for _ in 0..1000 {
+        assert!(a.below(10) < 10);
+        assert!((-3..=3).contains(&a.int_in(-3..=3)));
+    }
+    assert_eq!(5, a.int_in(5..=5));
+    let full: Vec<i64> = (0..3).map(|_| a.int_in(i64::MIN..=i64::MAX)).collect();
+    assert_ne!(full[0], full[1]);
+    assert!(!a.chance(0.0));
+    assert!(a.chance(1.0));
+    assert_eq!(&'x', a.choose(&['x']));
+
+    // The same generator as for randomized searches, so a seed means the same everywhere.
+    let mut search = aoc_util::optimize::Rng::new(7);
+    let mut fuzz = Rng::new(7);
+    assert_eq!(search.next_u64(), fuzz.next_u64());
+}
+
+#[test]
+fn grid_roundtrip() {
+    fuzz(
+        1,
+        50,
+        |rng| {
+            let (height, width) = (rng.below(8) as usize + 1, rng.below(8) as usize + 1);
+            random_grid(rng, height, width, |rng| rng.chance(0.4))
+        },
+        |grid| {
+            assert_roundtrip_with(
+                grid,
+                |grid| {
+                    grid.rows()
+                        .map(|row| row.iter().map(|&b| if b { '#' } else { '.' }).collect())
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                },
+                |s| Grid::parse(s, |c| c == '#'),
+            )
+        },
+    );
+}
+
+#[test]
+fn int_list_roundtrip() {
+    fuzz(
+        2,
+        50,
+        |rng| random_ints(rng, 10, -1000..=1000),
+        |ints| {
+            assert_roundtrip_with(
+                ints,
+                |ints| {
+                    let strings: Vec<String> = ints.iter().map(i64::to_string).collect();
+                    strings.join(",")
+                },
+                |s| -> Result<Vec<i64>, _> {
+                    FileReader::new()
+                        .split_char(',')
+                        .read_from_reader(s.as_bytes())
+                },
+            )
+        },
+    );
+    assert_roundtrip(&-42i64);
+}
+
+#[test]
+fn balanced_brackets() {
+    fuzz(
+        3,
+        100,
+        |rng| random_brackets(rng, 12),
+        |s| {
+            assert_eq!(24, s.len());
+            let mut stack = Vec::new();
+            for c in s.chars() {
+                match c {
+                    '(' | '[' | '{' | '<' => stack.push(c),
+                    _ => {
+                        let open = stack.pop().unwrap();
+                        assert!(
+                            ["()", "[]", "{}", "<>"].contains(&format!("{}{}", open, c).as_str())
+                        );
+                    }
+                }
+            }
+            assert!(stack.is_empty());
+        },
+    );
+}
+
+#[test]
+fn nested_lists() {
+    fuzz(
+        4,
+        100,
+        |rng| random_nested_list(rng, 4, 4),
+        |s| {
+            let (mut depth, mut max_depth) = (0, 0);
+            for c in s.chars() {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => assert!(c == ',' || c.is_ascii_digit()),
+                }
+                max_depth = max_depth.max(depth);
+            }
+            assert_eq!(0, depth);
+            assert!((1..=4).contains(&max_depth));
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "fuzz case 0 (seed 5) failed")]
+fn failing_fuzz_case() {
+    fuzz(5, 10, |rng| rng.below(10), |&n| assert!(n > 100));
+}
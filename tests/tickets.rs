@@ -0,0 +1,52 @@
+extern crate aoc_util;
+
+use std::collections::BTreeSet;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::matching::eliminate;
+use aoc_util::tickets::{Field, Notes};
+
+#[test]
+fn parse_field() {
+    let field: Field = "departure location: 1-3 or 5-7".parse().unwrap();
+    assert_eq!("departure location", field.name);
+    assert_eq!(vec![1..=3, 5..=7], field.ranges);
+    assert!(field.accepts(3));
+    assert!(!field.accepts(4));
+}
+
+#[test]
+fn error_rate() {
+    let notes: Notes = "class: 1-3 or 5-7\nrow: 6-11 or 33-44\nseat: 13-40 or 45-50\n\n\
+                        your ticket:\n7,1,14\n\n\
+                        nearby tickets:\n7,3,47\n40,4,50\n55,2,20\n38,6,12"
+        .parse()
+        .unwrap();
+    assert_eq!(71, notes.error_rate());
+    assert_eq!(vec![&[7, 3, 47][..]], notes.valid_tickets());
+}
+
+#[test]
+fn deduce_field_columns() {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/tickets.txt")
+        .unwrap();
+    let notes: Notes = input.parse().unwrap();
+    let columns = notes.field_columns().unwrap();
+    assert_eq!(Some(&1), columns.get("class"));
+    assert_eq!(Some(&0), columns.get("row"));
+    assert_eq!(Some(&2), columns.get("seat"));
+}
+
+#[test]
+fn ambiguous_elimination() {
+    let candidates: Vec<BTreeSet<u8>> = vec![
+        vec![1, 2].into_iter().collect(),
+        vec![1, 2].into_iter().collect(),
+    ];
+    assert_eq!(None, eliminate(candidates));
+
+    let contradiction: Vec<BTreeSet<u8>> =
+        vec![vec![1].into_iter().collect(), vec![1].into_iter().collect()];
+    assert_eq!(None, eliminate(contradiction));
+}
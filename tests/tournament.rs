@@ -0,0 +1,68 @@
+extern crate aoc_util;
+
+use std::collections::VecDeque;
+
+use aoc_util::tournament::{knockout, last_remaining, last_remaining_across, play, reduce};
+
+#[test]
+fn play_rounds() {
+    let bracket = play(vec![1, 2, 3, 4], |a, b| a + b);
+    assert_eq!(&[vec![1, 2, 3, 4], vec![3, 7], vec![10]], bracket.rounds());
+    assert_eq!(Some(10), bracket.into_winner());
+
+    let bracket = play(vec!["solo"], |a, _| a);
+    assert_eq!(1, bracket.rounds().len());
+    assert_eq!(Some(&"solo"), bracket.winner());
+
+    let bracket = play(Vec::<u8>::new(), |a, _| *a);
+    assert!(bracket.rounds().is_empty());
+    assert_eq!(None, bracket.winner());
+}
+
+#[test]
+fn knockout_with_byes() {
+    let bracket = knockout(vec!["d", "a", "c", "b", "e", "f"], |a, b| a < b);
+    assert_eq!(
+        &[vec!["a", "b", "e"], vec!["a", "e"], vec!["a"]],
+        &bracket.rounds()[1..]
+    );
+}
+
+#[test]
+fn reduce_without_history() {
+    let words: Vec<String> = ["a", "b", "c", "d", "e"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    assert_eq!(Some(String::from("abcde")), reduce(words, |a, b| a + &b));
+    assert_eq!(None, reduce(Vec::<u8>::new(), |a, _| a));
+}
+
+/// Simulate the circle of `n`, where the one at `victim(len)` positions ahead of the current
+/// one is eliminated.
+fn simulate(n: u64, victim: fn(usize) -> usize) -> u64 {
+    let mut circle: VecDeque<u64> = (1..=n).collect();
+    while circle.len() > 1 {
+        let offset = victim(circle.len());
+        circle.remove(offset);
+        if offset > 0 {
+            circle.rotate_left(1);
+        }
+    }
+    circle[0]
+}
+
+#[test]
+fn josephus_circles() {
+    assert_eq!(3, last_remaining(5));
+    assert_eq!(2, last_remaining_across(5));
+    for n in 1..200 {
+        assert_eq!(simulate(n, |_| 1), last_remaining(n), "n = {}", n);
+        assert_eq!(
+            simulate(n, |len| len / 2),
+            last_remaining_across(n),
+            "n = {}",
+            n
+        );
+    }
+}
@@ -0,0 +1,63 @@
+extern crate aoc_util;
+
+use aoc_util::input::{FileReader, FromFile};
+use aoc_util::tree::tower::Tower;
+use aoc_util::tree::{license_value, metadata_sum, parse_license, Tree};
+
+#[test]
+fn build_tree() {
+    let mut tree = Tree::new();
+    let a = tree.add_node("a");
+    let b = tree.add_node("b");
+    let c = tree.add_node("c");
+    assert_eq!(None, tree.root());
+    tree.add_child(a, b);
+    tree.add_child(b, c);
+    assert_eq!(Some(a), tree.root());
+    assert_eq!(Some(b), tree.parent(c));
+    assert_eq!(&[c], tree.children(b));
+    assert_eq!(2, tree.depth(c));
+    assert_eq!(vec![c, b, a], tree.post_order(a));
+}
+
+#[test]
+fn tower_root_and_weights() {
+    let input: String = FileReader::new()
+        .read_from_file("tests/inputs/tower.txt")
+        .unwrap();
+    let tower: Tower = input.parse().unwrap();
+    assert_eq!(Some("tknk"), tower.root_name());
+
+    let root = tower.tree().root().unwrap();
+    assert_eq!(778, tower.total_weight(root));
+    assert_eq!(Some(("ugml", 60)), tower.correct_weight());
+}
+
+#[test]
+fn balanced_tower() {
+    let tower: Tower = "a (1) -> b, c, d\nb (2)\nc (2)\nd (2)".parse().unwrap();
+    assert_eq!(None, tower.correct_weight());
+    assert!("a (1) -> x".parse::<Tower>().is_err());
+}
+
+#[test]
+fn license_tree() {
+    let tree = parse_license("2 3 0 3 10 11 12 1 1 0 1 99 2 1 1 2\n").unwrap();
+    let root = tree.root().unwrap();
+    assert_eq!(4, tree.len());
+    assert_eq!(&vec![1, 1, 2], tree.value(root));
+    assert_eq!(2, tree.children(root).len());
+
+    assert_eq!(138, metadata_sum(&tree, root));
+    assert_eq!(66, license_value(&tree, root));
+    assert_eq!(33, license_value(&tree, tree.children(root)[0]));
+    assert_eq!(0, license_value(&tree, tree.children(root)[1]));
+}
+
+#[test]
+fn invalid_license() {
+    assert!(parse_license("").is_err());
+    assert!(parse_license("1 1 0 1 5").is_err());
+    assert!(parse_license("0 1 5 7").is_err());
+    assert!(parse_license("0 x").is_err());
+}
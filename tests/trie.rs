@@ -0,0 +1,49 @@
+extern crate aoc_util;
+
+use aoc_util::collections::Trie;
+
+#[test]
+fn insert_and_contains() {
+    let mut trie = Trie::new();
+    assert!(trie.is_empty());
+    assert!(trie.insert("bwu"));
+    assert!(trie.insert("b"));
+    assert!(!trie.insert("b"));
+    assert_eq!(2, trie.len());
+    assert!(trie.contains("b"));
+    assert!(!trie.contains("bw"));
+    assert!(trie.starts_with("bw"));
+    assert!(!trie.starts_with("g"));
+}
+
+#[test]
+fn prefix_queries() {
+    let trie: Trie = vec!["gb", "g", "br", "bwu", "b"].into_iter().collect();
+    assert_eq!(5, trie.count_with_prefix(""));
+    assert_eq!(3, trie.count_with_prefix("b"));
+    assert_eq!(vec!["b", "br", "bwu"], trie.words_with_prefix("b"));
+    assert!(trie.words_with_prefix("x").is_empty());
+}
+
+#[test]
+fn count_towel_arrangements() {
+    let trie: Trie = vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+        .into_iter()
+        .collect();
+    let ways = |design: &str| {
+        let mut ways = vec![0u64; design.len() + 1];
+        ways[0] = 1;
+        for start in 0..design.len() {
+            for len in trie.prefix_lengths(&design[start..]) {
+                ways[start + len] += ways[start];
+            }
+        }
+        ways[design.len()]
+    };
+
+    let designs = [
+        "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb",
+    ];
+    let counts: Vec<u64> = designs.iter().map(|d| ways(d)).collect();
+    assert_eq!(vec![2, 1, 4, 6, 0, 1, 2, 0], counts);
+}
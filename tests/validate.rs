@@ -0,0 +1,36 @@
+extern crate aoc_util;
+
+use aoc_util::validate::{Check, Distinct, StreamChecker, SumOfTwo};
+
+#[test]
+fn xmas_preamble() {
+    let mut checker = StreamChecker::new(2, SumOfTwo);
+    assert_eq!(Check::Preamble, checker.push(1));
+    assert_eq!(Check::Preamble, checker.push(2));
+    assert_eq!(Check::Valid, checker.push(3));
+    assert_eq!(Check::Invalid, checker.push(7));
+    assert_eq!(&[3, 7], checker.window());
+    assert_eq!(Check::Valid, checker.push(10));
+
+    // The two summands must have different values.
+    let mut checker = StreamChecker::new(2, SumOfTwo);
+    assert_eq!(Some(4), checker.first_invalid(vec![2, 2, 4]));
+}
+
+#[test]
+fn distinct_from_recent() {
+    let mut checker = StreamChecker::new(3, Distinct);
+    // Repeats within the last 3 characters: `j` (position 3) and `q` (5).
+    let repeats = checker.invalid_positions("mjqjpqmgbljsph".chars());
+    assert_eq!(vec![3, 5], repeats);
+}
+
+#[test]
+fn closure_rules() {
+    let increasing = |window: &[i32], value: &i32| window.iter().all(|w| w < value);
+    let mut checker = StreamChecker::new(2, increasing);
+    assert_eq!(
+        vec![3, 5],
+        checker.invalid_positions(vec![1, 4, 5, 2, 6, 6, 9])
+    );
+}
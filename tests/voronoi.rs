@@ -0,0 +1,40 @@
+use aoc_util::geometry::Point2;
+use aoc_util::voronoi::{areas, count_within_total_distance, largest_finite_area};
+
+fn example() -> Vec<Point2<i64>> {
+    vec![(1, 1), (1, 6), (8, 3), (3, 4), (5, 5), (8, 9)]
+        .into_iter()
+        .map(Point2::from)
+        .collect()
+}
+
+#[test]
+fn finite_areas() {
+    assert_eq!(
+        vec![None, None, None, Some(9), Some(17), None],
+        areas(&example())
+    );
+    assert_eq!(Some(17), largest_finite_area(&example()));
+    assert_eq!(None, largest_finite_area(&[Point2::new(0, 0)]));
+    assert_eq!(None, largest_finite_area(&[]));
+}
+
+#[test]
+fn total_distance_region() {
+    assert_eq!(16, count_within_total_distance(&example(), 32));
+    assert_eq!(0, count_within_total_distance(&example(), 0));
+
+    // A single point: all cells with distance below 3 form a diamond of 13 cells.
+    assert_eq!(13, count_within_total_distance(&[Point2::new(5, -5)], 3));
+}
+
+#[test]
+fn total_distance_matches_brute_force() {
+    let points = example();
+    let limit = 60;
+    let brute = (-40..60)
+        .flat_map(|y| (-40..60).map(move |x| Point2::new(x, y)))
+        .filter(|&c| points.iter().map(|p| p.manhattan(c) as i64).sum::<i64>() < limit)
+        .count();
+    assert_eq!(brute, count_within_total_distance(&points, limit));
+}